@@ -0,0 +1,107 @@
+use syntax::{ast, ast::Radix, AstToken};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_digit_separators
+//
+// Groups the digits of a long integer literal with `_` separators.
+//
+// ```
+// const _: i32 = 1000000<|>;
+// ```
+// ->
+// ```
+// const _: i32 = 1_000_000;
+// ```
+pub(crate) fn add_digit_separators(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let literal = ctx.find_node_at_offset::<ast::Literal>()?;
+    let literal = match literal.kind() {
+        ast::LiteralKind::IntNumber(it) => it,
+        _ => return None,
+    };
+    let radix = literal.radix();
+    let suffix = literal.suffix().unwrap_or_default();
+    let text = literal.text().as_str();
+    let (prefix, digits) = match radix {
+        Radix::Decimal => ("", &text[..text.len() - suffix.len()]),
+        _ => text[..text.len() - suffix.len()].split_at(2),
+    };
+
+    let digits: Vec<char> = digits.chars().filter(|&c| c != '_').collect();
+    let group_size = match radix {
+        Radix::Decimal | Radix::Octal => 3,
+        Radix::Hexadecimal | Radix::Binary => 4,
+    };
+    let mut grouped = String::new();
+    for (i, digit) in digits.iter().enumerate() {
+        if i > 0 && (digits.len() - i) % group_size == 0 {
+            grouped.push('_');
+        }
+        grouped.push(*digit);
+    }
+    let converted = format!("{}{}{}", prefix, grouped, suffix);
+    if converted == text {
+        return None;
+    }
+
+    let range = literal.syntax().text_range();
+    acc.add(
+        AssistId("add_digit_separators", AssistKind::RefactorRewrite),
+        "Add digit separators",
+        range,
+        |builder| builder.replace(range, converted),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn decimal_literal() {
+        check_assist(
+            add_digit_separators,
+            "const _: u64 = 1000000<|>;",
+            "const _: u64 = 1_000_000;",
+        );
+    }
+
+    #[test]
+    fn decimal_literal_with_suffix() {
+        check_assist(
+            add_digit_separators,
+            "const _: u64 = 12345678<|>u64;",
+            "const _: u64 = 12_345_678u64;",
+        );
+    }
+
+    #[test]
+    fn hexadecimal_literal() {
+        check_assist(
+            add_digit_separators,
+            "const _: u32 = 0xDEADBEEF<|>;",
+            "const _: u32 = 0xDEAD_BEEF;",
+        );
+    }
+
+    #[test]
+    fn regroups_existing_separators() {
+        check_assist(
+            add_digit_separators,
+            "const _: u8 = 0b1_0101010<|>u8;",
+            "const _: u8 = 0b1010_1010u8;",
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_short_literal() {
+        check_assist_not_applicable(add_digit_separators, "const _: u64 = 100<|>;");
+    }
+
+    #[test]
+    fn not_applicable_to_grouped_literal() {
+        check_assist_not_applicable(add_digit_separators, "const _: u64 = 1_000<|>;");
+    }
+}
@@ -0,0 +1,163 @@
+use syntax::ast::{self, AstNode, GenericParamsOwner, NameOwner};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_lifetime_to_type
+//
+// Adds a new lifetime to a struct, enum or union and uses it for the references it holds.
+//
+// ```
+// struct Point {
+//     x: &<|>u32,
+//     y: u32,
+// }
+// ```
+// ->
+// ```
+// struct Point<'a> {
+//     x: &'a u32,
+//     y: u32,
+// }
+// ```
+pub(crate) fn add_lifetime_to_type(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let ref_type_focused = ctx.find_node_at_offset::<ast::RefType>()?;
+    if ref_type_focused.lifetime().is_some() {
+        return None;
+    }
+
+    let node = ctx.find_node_at_offset::<ast::AdtDef>()?;
+    let has_lifetime = node
+        .generic_param_list()
+        .map_or(false, |gen_list| gen_list.lifetime_params().next().is_some());
+    if has_lifetime {
+        return None;
+    }
+
+    let ref_types = fetch_borrowed_types(&node)?;
+    let target = node.syntax().text_range();
+
+    acc.add(
+        AssistId("add_lifetime_to_type", AssistKind::Generate),
+        "Add lifetime",
+        target,
+        |builder| {
+            match node.generic_param_list() {
+                Some(gen_param) => {
+                    if let Some(left_angle) = gen_param.l_angle_token() {
+                        builder.insert(left_angle.text_range().end(), "'a, ");
+                    }
+                }
+                None => {
+                    if let Some(name) = node.name() {
+                        builder.insert(name.syntax().text_range().end(), "<'a>");
+                    }
+                }
+            }
+
+            for ref_type in ref_types {
+                if let Some(amp_token) = ref_type.amp_token() {
+                    builder.insert(amp_token.text_range().end(), "'a ");
+                }
+            }
+        },
+    )
+}
+
+/// Collects the references without a lifetime in the fields of `node`.
+fn fetch_borrowed_types(node: &ast::AdtDef) -> Option<Vec<ast::RefType>> {
+    let field_types: Vec<ast::Type> = match node {
+        ast::AdtDef::Enum(enum_) => enum_
+            .variant_list()?
+            .variants()
+            .filter_map(|variant| variant.field_list())
+            .flat_map(|field_list| field_list_types(&field_list))
+            .collect(),
+        ast::AdtDef::Struct(strukt) => field_list_types(&strukt.field_list()?),
+        ast::AdtDef::Union(un) => {
+            un.record_field_list()?.fields().filter_map(|field| field.ty()).collect()
+        }
+    };
+
+    let ref_types: Vec<ast::RefType> = field_types
+        .iter()
+        .flat_map(|ty| ty.syntax().descendants())
+        .filter_map(ast::RefType::cast)
+        .filter(|ref_type| ref_type.lifetime().is_none())
+        // References in function pointer types get their own late-bound lifetimes.
+        .filter(|ref_type| {
+            !ref_type.syntax().ancestors().any(|it| ast::FnPtrType::can_cast(it.kind()))
+        })
+        .collect();
+
+    if ref_types.is_empty() {
+        None
+    } else {
+        Some(ref_types)
+    }
+}
+
+fn field_list_types(field_list: &ast::FieldList) -> Vec<ast::Type> {
+    match field_list {
+        ast::FieldList::RecordFieldList(it) => it.fields().filter_map(|field| field.ty()).collect(),
+        ast::FieldList::TupleFieldList(it) => it.fields().filter_map(|field| field.ty()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn add_lifetime_to_struct() {
+        check_assist(
+            add_lifetime_to_type,
+            "struct Foo { a: &<|>i32 }",
+            "struct Foo<'a> { a: &'a i32 }",
+        );
+    }
+
+    #[test]
+    fn add_lifetime_to_struct_with_several_references() {
+        check_assist(
+            add_lifetime_to_type,
+            "struct Foo { a: &<|>i32, b: &usize, c: u32, d: Vec<&str> }",
+            "struct Foo<'a> { a: &'a i32, b: &'a usize, c: u32, d: Vec<&'a str> }",
+        );
+    }
+
+    #[test]
+    fn add_lifetime_to_struct_with_type_params() {
+        check_assist(
+            add_lifetime_to_type,
+            "struct Foo<T> { a: &<|>T, b: T }",
+            "struct Foo<'a, T> { a: &'a T, b: T }",
+        );
+    }
+
+    #[test]
+    fn add_lifetime_to_tuple_struct() {
+        check_assist(
+            add_lifetime_to_type,
+            "struct Foo(&<|>i32, &str);",
+            "struct Foo<'a>(&'a i32, &'a str);",
+        );
+    }
+
+    #[test]
+    fn add_lifetime_to_enum() {
+        check_assist(
+            add_lifetime_to_type,
+            "enum Foo { Bar { a: &<|>i32 }, Baz(&str) }",
+            "enum Foo<'a> { Bar { a: &'a i32 }, Baz(&'a str) }",
+        );
+    }
+
+    #[test]
+    fn add_lifetime_not_applicable() {
+        check_assist_not_applicable(add_lifetime_to_type, "struct Foo<'a> { a: &<|>i32 }");
+        check_assist_not_applicable(add_lifetime_to_type, "struct Foo { a: &'a<|> i32 }");
+        check_assist_not_applicable(add_lifetime_to_type, "struct Foo { a: <|>i32 }");
+    }
+}
@@ -0,0 +1,121 @@
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode, AttrsOwner},
+    SyntaxKind::{COMMENT, WHITESPACE},
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: add_must_use
+//
+// Adds `#[must_use]` to a function returning a value.
+//
+// ```
+// fn is_<|>empty(s: &str) -> bool {
+//     s.len() == 0
+// }
+// ```
+// ->
+// ```
+// #[must_use]
+// fn is_empty(s: &str) -> bool {
+//     s.len() == 0
+// }
+// ```
+pub(crate) fn add_must_use(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    if let Some(body) = fn_.body() {
+        if body.syntax().text_range().contains(ctx.offset()) {
+            return None;
+        }
+    }
+    match fn_.ret_type()?.ty()? {
+        ast::Type::NeverType(_) => return None,
+        ast::Type::TupleType(it) if it.fields().next().is_none() => return None,
+        _ => {}
+    }
+    if fn_.attrs().any(|attr| attr.simple_name().map_or(false, |name| name == "must_use")) {
+        return None;
+    }
+    // The attribute has no effect on trait impl methods.
+    let in_trait_impl = fn_
+        .syntax()
+        .parent()
+        .and_then(ast::AssocItemList::cast)
+        .and_then(|it| it.syntax().parent())
+        .and_then(ast::Impl::cast)
+        .map_or(false, |it| it.trait_().is_some());
+    if in_trait_impl {
+        return None;
+    }
+
+    // Insert after doc comments.
+    let offset = fn_
+        .syntax()
+        .children_with_tokens()
+        .find(|it| it.kind() != COMMENT && it.kind() != WHITESPACE)?
+        .text_range()
+        .start();
+    let indent = IndentLevel::from_node(fn_.syntax());
+    acc.add(
+        AssistId("add_must_use", AssistKind::RefactorRewrite),
+        "Add `#[must_use]`",
+        fn_.syntax().text_range(),
+        |builder| builder.insert(offset, format!("#[must_use]\n{}", indent)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn adds_must_use_to_bool_fn() {
+        check_assist(
+            add_must_use,
+            r#"
+struct S;
+impl S {
+    /// Checks something.
+    pub fn <|>check(&self) -> bool {
+        true
+    }
+}
+"#,
+            r#"
+struct S;
+impl S {
+    /// Checks something.
+    #[must_use]
+    pub fn check(&self) -> bool {
+        true
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_unit_fns() {
+        check_assist_not_applicable(add_must_use, r#"fn <|>foo() {}"#);
+        check_assist_not_applicable(add_must_use, r#"fn <|>foo() -> () {}"#);
+        check_assist_not_applicable(add_must_use, r#"fn <|>foo() -> ! { loop {} }"#);
+    }
+
+    #[test]
+    fn not_applicable_when_already_must_use() {
+        check_assist_not_applicable(
+            add_must_use,
+            r#"
+#[must_use]
+fn <|>foo() -> bool { true }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_body() {
+        check_assist_not_applicable(add_must_use, r#"fn foo() -> bool { <|>true }"#);
+    }
+}
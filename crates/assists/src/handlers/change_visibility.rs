@@ -1,7 +1,9 @@
 use syntax::{
     ast::{self, NameOwner, VisibilityOwner},
     AstNode,
-    SyntaxKind::{CONST, ENUM, FN, MODULE, STATIC, STRUCT, TRAIT, TYPE_ALIAS, VISIBILITY},
+    SyntaxKind::{
+        CONST, ENUM, FN, MODULE, STATIC, STRUCT, TRAIT, TYPE_ALIAS, VISIBILITY, WHITESPACE,
+    },
     T,
 };
 use test_utils::mark;
@@ -17,7 +19,7 @@ use crate::{utils::vis_offset, AssistContext, AssistId, AssistKind, Assists};
 // ```
 // ->
 // ```
-// pub(crate) fn frobnicate() {}
+// pub fn frobnicate() {}
 // ```
 pub(crate) fn change_visibility(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
     if let Some(vis) = ctx.find_node_at_offset::<ast::Visibility>() {
@@ -72,47 +74,61 @@ fn add_vis(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
         return None;
     };
 
-    acc.add(
-        AssistId("change_visibility", AssistKind::RefactorRewrite),
-        "Change visibility to pub(crate)",
-        target,
-        |edit| {
-            edit.insert(offset, "pub(crate) ");
-        },
-    )
-}
-
-fn change_vis(acc: &mut Assists, vis: ast::Visibility) -> Option<()> {
-    if vis.syntax().text() == "pub" {
-        let target = vis.syntax().text_range();
-        return acc.add(
+    for vis in &["pub", "pub(crate)"] {
+        acc.add(
             AssistId("change_visibility", AssistKind::RefactorRewrite),
-            "Change Visibility to pub(crate)",
+            format!("Change visibility to {}", vis),
             target,
             |edit| {
-                edit.replace(vis.syntax().text_range(), "pub(crate)");
+                edit.insert(offset, format!("{} ", vis));
             },
         );
     }
-    if vis.syntax().text() == "pub(crate)" {
-        let target = vis.syntax().text_range();
-        return acc.add(
+    Some(())
+}
+
+fn change_vis(acc: &mut Assists, vis: ast::Visibility) -> Option<()> {
+    let target = vis.syntax().text_range();
+    let mut private_range = target;
+    if let Some(ws) = vis.syntax().next_sibling_or_token().filter(|it| it.kind() == WHITESPACE) {
+        private_range = private_range.cover(ws.text_range());
+    }
+
+    acc.add(
+        AssistId("change_visibility", AssistKind::RefactorRewrite),
+        "Make private",
+        target,
+        |edit| {
+            edit.delete(private_range);
+        },
+    );
+
+    // `pub` and `pub(crate)` flip into each other by default.
+    let new_visibilities: &[&str] = match vis.syntax().text().to_string().as_str() {
+        "pub" => &["pub(crate)"],
+        "pub(crate)" => &["pub"],
+        _ => &["pub", "pub(crate)"],
+    };
+    for &new_vis in new_visibilities {
+        acc.add(
             AssistId("change_visibility", AssistKind::RefactorRewrite),
-            "Change visibility to pub",
+            format!("Change visibility to {}", new_vis),
             target,
             |edit| {
-                edit.replace(vis.syntax().text_range(), "pub");
+                edit.replace(target, new_vis);
             },
         );
     }
-    None
+    Some(())
 }
 
 #[cfg(test)]
 mod tests {
     use test_utils::mark;
 
-    use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
+    use crate::tests::{
+        check_assist, check_assist_by_label, check_assist_not_applicable, check_assist_target,
+    };
 
     use super::*;
 
@@ -156,6 +172,53 @@ mod tests {
         check_assist(change_visibility, "<|>pub(crate) fn foo() {}", "pub fn foo() {}")
     }
 
+    #[test]
+    fn change_visibility_adds_pub_to_items() {
+        check_assist_by_label(
+            change_visibility,
+            "<|>fn foo() {}",
+            "pub fn foo() {}",
+            "Change visibility to pub",
+        );
+    }
+
+    #[test]
+    fn change_visibility_pub_to_private() {
+        check_assist_by_label(
+            change_visibility,
+            "<|>pub fn foo() {}",
+            "fn foo() {}",
+            "Make private",
+        );
+    }
+
+    #[test]
+    fn change_visibility_pub_crate_to_private() {
+        check_assist_by_label(
+            change_visibility,
+            "<|>pub(crate) fn foo() {}",
+            "fn foo() {}",
+            "Make private",
+        );
+        check_assist_by_label(
+            change_visibility,
+            "struct S { <|>pub(crate) field: u32 }",
+            "struct S { field: u32 }",
+            "Make private",
+        );
+    }
+
+    #[test]
+    fn change_visibility_pub_super() {
+        check_assist_by_label(
+            change_visibility,
+            "<|>pub(super) fn foo() {}",
+            "pub fn foo() {}",
+            "Change visibility to pub",
+        );
+        check_assist(change_visibility, "<|>pub(super) fn foo() {}", "pub(crate) fn foo() {}");
+    }
+
     #[test]
     fn change_visibility_const() {
         check_assist(change_visibility, "<|>const FOO = 3u8;", "pub(crate) const FOO = 3u8;");
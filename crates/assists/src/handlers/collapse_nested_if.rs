@@ -0,0 +1,169 @@
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        AstNode, BinOp,
+    },
+    SyntaxKind, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: collapse_nested_if
+//
+// Merges an `if` whose only content is another `if` into a single `if` with
+// both conditions. Neither `if` may have an `else` branch.
+//
+// ```
+// fn main() {
+//     <|>if x > 0 {
+//         if x < 10 {
+//             println!("small");
+//         }
+//     }
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     if x > 0 && x < 10 {
+//         println!("small");
+//     }
+// }
+// ```
+pub(crate) fn collapse_nested_if(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let if_keyword = ctx.find_token_syntax_at_offset(T![if])?;
+    let outer = ast::IfExpr::cast(if_keyword.parent())?;
+    let outer_cond = plain_condition(&outer)?;
+    let outer_block = outer.then_branch()?;
+
+    // The inner `if` has to be the only thing in the block, including comments.
+    let inner = match (outer_block.statements().next(), outer_block.expr()) {
+        (None, Some(ast::Expr::IfExpr(it))) => it,
+        _ => return None,
+    };
+    if outer_block.syntax().children_with_tokens().any(|it| it.kind() == SyntaxKind::COMMENT) {
+        return None;
+    }
+    let inner_cond = plain_condition(&inner)?;
+    let inner_block = inner.then_branch()?;
+
+    let target = if_keyword.text_range();
+    acc.add(
+        AssistId("collapse_nested_if", AssistKind::RefactorRewrite),
+        "Collapse nested if",
+        target,
+        |edit| {
+            let block = inner_block.dedent(IndentLevel(1));
+            edit.replace(
+                outer.syntax().text_range(),
+                format!("if {} && {} {}", operand(outer_cond), operand(inner_cond), block),
+            );
+        },
+    )
+}
+
+/// Returns the condition of an `if` that has no `else` branch and isn't an `if let`.
+fn plain_condition(if_expr: &ast::IfExpr) -> Option<ast::Expr> {
+    if if_expr.else_branch().is_some() {
+        return None;
+    }
+    let cond = if_expr.condition()?;
+    if cond.pat().is_some() {
+        return None;
+    }
+    cond.expr()
+}
+
+fn operand(cond: ast::Expr) -> String {
+    match &cond {
+        ast::Expr::BinExpr(bin) if bin.op_kind() == Some(BinOp::BooleanOr) => {
+            format!("({})", cond)
+        }
+        _ => cond.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn collapses_nested_if() {
+        check_assist(
+            collapse_nested_if,
+            r#"
+fn f(a: bool, b: bool) {
+    i<|>f a {
+        if b {
+            foo();
+        }
+    }
+}
+"#,
+            r#"
+fn f(a: bool, b: bool) {
+    if a && b {
+        foo();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn parenthesizes_or_conditions() {
+        check_assist(
+            collapse_nested_if,
+            r#"
+fn f(a: bool, b: bool, c: bool) {
+    <|>if a || b {
+        if c { foo(); }
+    }
+}
+"#,
+            r#"
+fn f(a: bool, b: bool, c: bool) {
+    if (a || b) && c { foo(); }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_else() {
+        check_assist_not_applicable(
+            collapse_nested_if,
+            r#"
+fn f(a: bool, b: bool) {
+    <|>if a {
+        if b {
+            foo();
+        } else {
+            bar();
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_with_other_statements() {
+        check_assist_not_applicable(
+            collapse_nested_if,
+            r#"
+fn f(a: bool, b: bool) {
+    <|>if a {
+        foo();
+        if b {
+            bar();
+        }
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,218 @@
+use hir::Adt;
+use ide_db::helpers::FamousDefs;
+use syntax::{
+    ast::{self, BinOp},
+    AstNode, AstToken,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_concat_to_format
+//
+// Converts a `String` concatenation into a single `format!` call.
+//
+// ```
+// # //- /main.rs crate:main deps:alloc
+// # use alloc::string::String;
+// fn greet(greeting: String, name: &str) {
+//     let s = greeting <|>+ ", " + name;
+// }
+// # //- /liballoc.rs crate:alloc
+// # pub mod string { pub struct String; }
+// ```
+// ->
+// ```
+// # use alloc::string::String;
+// fn greet(greeting: String, name: &str) {
+//     let s = format!("{}, {}", greeting, name);
+// }
+// ```
+pub(crate) fn convert_concat_to_format(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let mut concat = ctx.find_node_at_offset::<ast::BinExpr>()?;
+    if concat.op_kind()? != BinOp::Addition {
+        return None;
+    }
+    // Find the whole `a + b + c` chain, which is nested as `(a + b) + c`.
+    while let Some(parent) = concat.syntax().parent().and_then(ast::BinExpr::cast) {
+        if parent.op_kind() != Some(BinOp::Addition)
+            || parent.lhs().as_ref() != Some(&concat.clone().into())
+        {
+            break;
+        }
+        concat = parent;
+    }
+
+    let mut operands = Vec::new();
+    let mut rest = ast::Expr::from(concat.clone());
+    loop {
+        match &rest {
+            ast::Expr::BinExpr(bin_expr) if bin_expr.op_kind() == Some(BinOp::Addition) => {
+                operands.push(bin_expr.rhs()?);
+                rest = bin_expr.lhs()?;
+            }
+            _ => {
+                operands.push(rest);
+                break;
+            }
+        }
+    }
+    operands.reverse();
+
+    // Only `String + &str` is a concatenation, the other operands can't be `String`s.
+    let string =
+        FamousDefs(&ctx.sema, ctx.sema.scope(concat.syntax()).krate()).alloc_string_String()?;
+    let ty = ctx.sema.type_of_expr(operands.first()?)?;
+    if ty.as_adt() != Some(Adt::Struct(string)) {
+        return None;
+    }
+
+    let mut format_string = String::new();
+    let mut args = Vec::new();
+    for operand in operands {
+        if let Some(text) = string_literal_text(&operand) {
+            format_string.push_str(&text.replace('{', "{{").replace('}', "}}"));
+            continue;
+        }
+        // `&b` only borrows for the concatenation, `format!` does that on its own.
+        let arg = match &operand {
+            ast::Expr::RefExpr(it) if it.mut_token().is_none() => it.expr()?,
+            _ => operand.clone(),
+        };
+        format_string.push_str("{}");
+        args.push(arg.to_string());
+    }
+
+    let target = concat.syntax().text_range();
+    acc.add(
+        AssistId("convert_concat_to_format", AssistKind::RefactorRewrite),
+        "Convert to format!",
+        target,
+        |builder| {
+            let mut format_call = format!("format!(\"{}\"", format_string);
+            for arg in args {
+                format_call.push_str(", ");
+                format_call.push_str(&arg);
+            }
+            format_call.push(')');
+            builder.replace(target, format_call);
+        },
+    )
+}
+
+/// Returns the contents of a plain (non-raw) string literal, without quotes.
+fn string_literal_text(expr: &ast::Expr) -> Option<String> {
+    let literal = match expr {
+        ast::Expr::Literal(it) => it,
+        _ => return None,
+    };
+    let string = match literal.kind() {
+        ast::LiteralKind::String(it) => it,
+        _ => return None,
+    };
+    let text = string.text().as_str();
+    if !text.starts_with('"') {
+        return None;
+    }
+    Some(text[1..text.len() - 1].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    const ALLOC: &str = r#"//- /liballoc.rs crate:alloc
+pub mod string { pub struct String; }
+"#;
+
+    fn check(ra_fixture_before: &str, ra_fixture_after: &str) {
+        let before = format!("//- /main.rs crate:main deps:alloc{}{}", ra_fixture_before, ALLOC);
+        check_assist(convert_concat_to_format, &before, ra_fixture_after);
+    }
+
+    #[test]
+    fn converts_two_operands() {
+        check(
+            r#"
+use alloc::string::String;
+fn foo(a: String, b: String) {
+    let s = a <|>+ &b;
+}
+"#,
+            r#"
+use alloc::string::String;
+fn foo(a: String, b: String) {
+    let s = format!("{}{}", a, b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inlines_string_literals() {
+        check(
+            r#"
+use alloc::string::String;
+fn foo(a: String, b: &str) {
+    let s = a + " {" + b <|>+ "}";
+}
+"#,
+            r#"
+use alloc::string::String;
+fn foo(a: String, b: &str) {
+    let s = format!("{} {{{}}}", a, b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_numbers() {
+        check_assist_not_applicable(
+            convert_concat_to_format,
+            r#"
+fn foo(a: i32, b: i32) {
+    let s = a <|>+ b;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn finds_string_through_std() {
+        check_assist(
+            convert_concat_to_format,
+            r#"
+//- /main.rs crate:main deps:std
+use std::string::String;
+fn foo(a: String, b: &str) {
+    let s = a <|>+ b;
+}
+//- /libstd.rs crate:std deps:alloc
+pub use alloc::string;
+//- /liballoc.rs crate:alloc
+pub mod string { pub struct String; }
+"#,
+            r#"
+use std::string::String;
+fn foo(a: String, b: &str) {
+    let s = format!("{}{}", a, b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_string_types() {
+        check_assist_not_applicable(
+            convert_concat_to_format,
+            r#"
+struct String;
+fn foo(a: String, b: &str) {
+    let s = a <|>+ b;
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use itertools::Itertools;
+use syntax::{
+    ast::{self, AstNode, GenericParamsOwner, NameOwner, VisibilityOwner},
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_enum_to_struct
+//
+// Converts an enum with a single tuple variant into a tuple struct. Usages of
+// the variant are not updated.
+//
+// ```
+// enum <|>Meters { Meters(u32) }
+// ```
+// ->
+// ```
+// struct Meters(u32);
+// ```
+pub(crate) fn convert_enum_to_struct(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let enum_ = ctx.find_node_at_offset::<ast::Enum>()?;
+    let variant_list = enum_.variant_list()?;
+    if ctx.offset() > variant_list.syntax().text_range().start() {
+        return None;
+    }
+    let mut variants = variant_list.variants();
+    let variant = variants.next()?;
+    if variants.next().is_some() {
+        return None;
+    }
+    let fields = match variant.field_list()? {
+        ast::FieldList::TupleFieldList(it) => it,
+        ast::FieldList::RecordFieldList(_) => return None,
+    };
+    if fields.fields().next().is_none() {
+        return None;
+    }
+
+    let name = enum_.name()?;
+    let visibility = enum_.visibility();
+    let start = match &visibility {
+        Some(vis) => vis.syntax().text_range().start(),
+        None => enum_.enum_token()?.text_range().start(),
+    };
+    let range = TextRange::new(start, enum_.syntax().text_range().end());
+    acc.add(
+        AssistId("convert_enum_to_struct", AssistKind::RefactorRewrite),
+        "Convert to tuple struct",
+        enum_.syntax().text_range(),
+        |builder| {
+            // Fields of a variant are as visible as the enum itself.
+            let vis = visibility.as_ref().map(|it| format!("{} ", it)).unwrap_or_default();
+            let fields = fields.fields().map(|field| format!("{}{}", vis, field)).join(", ");
+            let generics = enum_.generic_param_list().map(|it| it.to_string()).unwrap_or_default();
+            let where_clause =
+                enum_.where_clause().map(|it| format!(" {}", it)).unwrap_or_default();
+            builder.replace(
+                range,
+                format!("{}struct {}{}({}){};", vis, name, generics, fields, where_clause),
+            );
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn converts_newtype_enum() {
+        check_assist(
+            convert_enum_to_struct,
+            r#"enum <|>Wrapper { V(u32) }"#,
+            r#"struct Wrapper(u32);"#,
+        );
+    }
+
+    #[test]
+    fn keeps_attributes_visibility_and_generics() {
+        check_assist(
+            convert_enum_to_struct,
+            r#"
+#[derive(Debug)]
+pub enum <|>Pair<T> where T: Copy {
+    Pair(T, T),
+}
+"#,
+            r#"
+#[derive(Debug)]
+pub struct Pair<T>(pub T, pub T) where T: Copy;
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_enums() {
+        check_assist_not_applicable(convert_enum_to_struct, r#"enum <|>E { A(u32), B(u32) }"#);
+        check_assist_not_applicable(convert_enum_to_struct, r#"enum <|>E { A { x: u32 } }"#);
+        check_assist_not_applicable(convert_enum_to_struct, r#"enum <|>E { A }"#);
+    }
+}
@@ -0,0 +1,303 @@
+use syntax::{
+    ast::{self, ArgListOwner, AstNode, LoopBodyOwner, NameOwner},
+    TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_for_to_find
+//
+// Converts a `for` loop which returns the first matching item into a call to `Iterator::find`.
+//
+// ```
+// # //- /main.rs crate:main deps:core
+// fn first_long<'a>(words: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+//     <|>for word in words {
+//         if word.len() > 5 {
+//             return Some(word);
+//         }
+//     }
+//     None
+// }
+// # //- /libcore.rs crate:core
+// # pub mod iter {
+// #     pub use self::traits::{collect::IntoIterator, iterator::Iterator};
+// #     mod traits {
+// #         pub(crate) mod iterator { pub trait Iterator { type Item; } }
+// #         pub(crate) mod collect {
+// #             pub trait IntoIterator { type Item; }
+// #             impl<I: super::iterator::Iterator> IntoIterator for I { type Item = I::Item; }
+// #         }
+// #     }
+// # }
+// # pub mod option { pub enum Option<T> { None, Some(T) } }
+// # pub mod prelude { pub use crate::{iter::Iterator, option::Option::{self, *}}; }
+// # #[prelude_import] pub use prelude::*;
+// ```
+// ->
+// ```
+// fn first_long<'a>(words: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+//     words.into_iter().find(|&word| word.len() > 5)
+// }
+// ```
+pub(crate) fn convert_for_to_find(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let for_loop = ctx.find_node_at_offset::<ast::ForExpr>()?;
+    let body = for_loop.loop_body()?;
+    if ctx.offset() >= body.syntax().text_range().start() {
+        return None;
+    }
+    let pat = match for_loop.pat()? {
+        ast::Pat::IdentPat(it)
+            if it.mut_token().is_none() && it.ref_token().is_none() && it.pat().is_none() =>
+        {
+            it
+        }
+        _ => return None,
+    };
+    let name = pat.name()?;
+    let iterable = for_loop.iterable()?;
+
+    let cond = returned_condition(&body, name.text())?;
+    if cond.syntax().descendants().any(|it| {
+        ast::ReturnExpr::can_cast(it.kind())
+            || ast::BreakExpr::can_cast(it.kind())
+            || ast::ContinueExpr::can_cast(it.kind())
+            || ast::TryExpr::can_cast(it.kind())
+            || ast::AwaitExpr::can_cast(it.kind())
+    }) {
+        return None;
+    }
+
+    // The loop has to be the last statement, followed by `None`.
+    let stmt = for_loop.syntax().parent().and_then(ast::ExprStmt::cast)?;
+    let block = stmt.syntax().parent().and_then(ast::BlockExpr::cast)?;
+    if block.statements().last()?.syntax() != stmt.syntax() {
+        return None;
+    }
+    let tail = block.expr()?;
+    if tail.syntax().text() != "None" {
+        return None;
+    }
+
+    // `find` hands out references to the items, so only items we can copy out of those are
+    // handled, which covers the common case of iterating over references.
+    let item_ty = ctx.sema.type_of_pat(&ast::Pat::IdentPat(pat.clone()))?;
+    if item_ty.remove_ref().is_none() && !item_ty.is_copy(ctx.db()) {
+        return None;
+    }
+    let receiver = match iterable {
+        ast::Expr::PathExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::IndexExpr(_)
+        | ast::Expr::ParenExpr(_) => iterable.to_string(),
+        _ => format!("({})", iterable),
+    };
+    // `find` takes `&mut self`, while the loop consumes the iterable, so go through
+    // `into_iter` rather than requiring a `mut` binding.
+    let receiver = format!("{}.into_iter()", receiver);
+
+    let range =
+        TextRange::new(for_loop.syntax().text_range().start(), tail.syntax().text_range().end());
+    acc.add(
+        AssistId("convert_for_to_find", AssistKind::RefactorRewrite),
+        "Convert to find",
+        for_loop.syntax().text_range(),
+        |builder| {
+            builder.replace(range, format!("{}.find(|&{}| {})", receiver, name, cond));
+        },
+    )
+}
+
+/// Matches a loop body consisting only of `if cond { return Some(name); }`, returning `cond`.
+fn returned_condition(body: &ast::BlockExpr, name: &str) -> Option<ast::Expr> {
+    let if_expr = single_expr(body)?;
+    let if_expr = match if_expr {
+        ast::Expr::IfExpr(it) if it.else_branch().is_none() => it,
+        _ => return None,
+    };
+    let condition = if_expr.condition()?;
+    if condition.pat().is_some() {
+        return None;
+    }
+
+    let ret = match single_expr(&if_expr.then_branch()?)? {
+        ast::Expr::ReturnExpr(it) => it,
+        _ => return None,
+    };
+    let call = match ret.expr()? {
+        ast::Expr::CallExpr(it) => it,
+        _ => return None,
+    };
+    if call.expr()?.syntax().text() != "Some" {
+        return None;
+    }
+    let mut args = call.arg_list()?.args();
+    match (args.next()?, args.next()) {
+        (ast::Expr::PathExpr(arg), None) if arg.syntax().text() == name => {}
+        _ => return None,
+    }
+
+    condition.expr()
+}
+
+/// Returns the only expression in `block`, either as its tail or as its sole statement.
+fn single_expr(block: &ast::BlockExpr) -> Option<ast::Expr> {
+    let mut statements = block.statements();
+    match (statements.next(), block.expr()) {
+        (None, Some(expr)) => Some(expr),
+        (Some(ast::Stmt::ExprStmt(stmt)), None) if statements.next().is_none() => stmt.expr(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ide_db::helpers::FamousDefs;
+
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    /// Appended to `libcore.rs`, so that iterators can be used in `for` loops.
+    const INTO_ITERATOR_IMPL: &str = r#"
+impl<I: iter::Iterator> iter::IntoIterator for I {
+    type Item = I::Item;
+    type IntoIter = I;
+    fn into_iter(self) -> I {
+        self
+    }
+}
+"#;
+
+    fn with_core(before: &str) -> String {
+        format!(
+            "//- /main.rs crate:main deps:core{}{}{}",
+            before,
+            FamousDefs::FIXTURE,
+            INTO_ITERATOR_IMPL
+        )
+    }
+
+    fn check(before: &str, after: &str) {
+        check_assist(convert_for_to_find, &with_core(before), after);
+    }
+
+    fn check_not_applicable(before: &str) {
+        check_assist_not_applicable(convert_for_to_find, &with_core(before));
+    }
+
+    #[test]
+    fn converts_searching_loop() {
+        check(
+            r#"
+fn first_even<'a>(xs: impl Iterator<Item = &'a u32>) -> Option<&'a u32> {
+    <|>for x in xs {
+        if *x % 2 == 0 {
+            return Some(x);
+        }
+    }
+    None
+}
+"#,
+            r#"
+fn first_even<'a>(xs: impl Iterator<Item = &'a u32>) -> Option<&'a u32> {
+    xs.into_iter().find(|&x| *x % 2 == 0)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn calls_into_iter_on_non_iterators() {
+        check(
+            r#"
+struct Names;
+struct Iter;
+impl Iterator for Iter {
+    type Item = &'static str;
+    fn next(&mut self) -> Option<&'static str> { None }
+}
+impl IntoIterator for &Names {
+    type Item = &'static str;
+    type IntoIter = Iter;
+    fn into_iter(self) -> Iter { Iter }
+}
+
+fn find_empty(names: Names) -> Option<&'static str> {
+    <|>for name in &names {
+        if name.is_empty() {
+            return Some(name);
+        }
+    }
+    None
+}
+"#,
+            r#"
+struct Names;
+struct Iter;
+impl Iterator for Iter {
+    type Item = &'static str;
+    fn next(&mut self) -> Option<&'static str> { None }
+}
+impl IntoIterator for &Names {
+    type Item = &'static str;
+    type IntoIter = Iter;
+    fn into_iter(self) -> Iter { Iter }
+}
+
+fn find_empty(names: Names) -> Option<&'static str> {
+    (&names).into_iter().find(|&name| name.is_empty())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_complex_bodies() {
+        check_not_applicable(
+            r#"
+fn first_even<'a>(xs: impl Iterator<Item = &'a u32>) -> Option<&'a u32> {
+    <|>for x in xs {
+        println!("{}", x);
+        if *x % 2 == 0 {
+            return Some(x);
+        }
+    }
+    None
+}
+"#,
+        );
+        check_not_applicable(
+            r#"
+fn first_even<'a>(xs: impl Iterator<Item = &'a u32>) -> Option<&'a u32> {
+    <|>for x in xs {
+        if *x % 2 == 0 {
+            return Some(x);
+        } else {
+            return None;
+        }
+    }
+    None
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_trailing_none() {
+        check_not_applicable(
+            r#"
+fn first_even<'a>(xs: impl Iterator<Item = &'a u32>, default: &'a u32) -> Option<&'a u32> {
+    <|>for x in xs {
+        if *x % 2 == 0 {
+            return Some(x);
+        }
+    }
+    Some(default)
+}
+"#,
+        );
+    }
+}
@@ -259,6 +259,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn convert_between_decimal_and_other_bases() {
+        check_assist_by_label(
+            convert_integer_literal,
+            "fn f() { let x = 255<|>u8; }",
+            "fn f() { let x = 0xFFu8; }",
+            "Convert 255u8 to 0xFFu8",
+        );
+        check_assist_by_label(
+            convert_integer_literal,
+            "fn f() { let x = 0b1010<|>; }",
+            "fn f() { let x = 10; }",
+            "Convert 0b1010 to 10",
+        );
+    }
+
     #[test]
     fn convert_overflowing_literal() {
         let before = "const _: i32 =
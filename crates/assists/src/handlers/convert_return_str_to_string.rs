@@ -0,0 +1,345 @@
+use hir::{Adt, ModuleDef, PathResolution};
+use ide_db::helpers::FamousDefs;
+use syntax::{
+    ast::{self, ArgListOwner, LoopBodyOwner},
+    AstNode, SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_return_str_to_string
+//
+// Changes a function returning `&str` to return an owned `String`.
+//
+// ```
+// fn name() -> &'static str<|> {
+//     "Ferris"
+// }
+// ```
+// ->
+// ```
+// fn name() -> String {
+//     "Ferris".to_string()
+// }
+// ```
+pub(crate) fn convert_return_str_to_string(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let (func, ty, body) = fn_ret_type_at_cursor(ctx)?;
+    let ret_type = ctx.sema.to_def(&func)?.ret_type(ctx.db());
+    if ret_type.is_mutable_reference() || !ret_type.remove_ref()?.is_str() {
+        return None;
+    }
+
+    let returned = returned_exprs(&body);
+    acc.add(
+        AssistId("convert_return_str_to_string", AssistKind::RefactorRewrite),
+        "Return `String` instead of `&str`",
+        ty.syntax().text_range(),
+        |builder| {
+            builder.replace(ty.syntax().text_range(), "String");
+            for expr in returned {
+                let range = expr.syntax().text_range();
+                // `&s` and `s` convert the same way, but the former needs parentheses.
+                let inner = match &expr {
+                    ast::Expr::RefExpr(ref_expr) if ref_expr.mut_token().is_none() => {
+                        ref_expr.expr()
+                    }
+                    _ => None,
+                };
+                let expr = inner.unwrap_or(expr);
+                let receiver = match expr {
+                    ast::Expr::Literal(_)
+                    | ast::Expr::PathExpr(_)
+                    | ast::Expr::MethodCallExpr(_)
+                    | ast::Expr::CallExpr(_)
+                    | ast::Expr::FieldExpr(_)
+                    | ast::Expr::IndexExpr(_)
+                    | ast::Expr::ParenExpr(_) => expr.to_string(),
+                    _ => format!("({})", expr),
+                };
+                builder.replace(range, format!("{}.to_string()", receiver));
+            }
+        },
+    )
+}
+
+// Assist: convert_return_string_to_str
+//
+// Changes a function which only ever returns owned copies of string literals
+// to return `&'static str`.
+//
+// ```
+// # //- /main.rs crate:main deps:alloc
+// # use alloc::string::String;
+// fn name() -> String<|> {
+//     "Ferris".to_string()
+// }
+// # //- /liballoc.rs crate:alloc
+// # pub mod string { pub struct String; }
+// ```
+// ->
+// ```
+// # use alloc::string::String;
+// fn name() -> &'static str {
+//     "Ferris"
+// }
+// ```
+pub(crate) fn convert_return_string_to_str(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let (func, ty, body) = fn_ret_type_at_cursor(ctx)?;
+    let string =
+        FamousDefs(&ctx.sema, ctx.sema.scope(func.syntax()).krate()).alloc_string_String()?;
+    if ctx.sema.to_def(&func)?.ret_type(ctx.db()).as_adt() != Some(Adt::Struct(string)) {
+        return None;
+    }
+
+    let literals = returned_exprs(&body)
+        .into_iter()
+        .map(|expr| Some((expr.syntax().text_range(), owned_string_literal(ctx, string, &expr)?)))
+        .collect::<Option<Vec<_>>>()?;
+    if literals.is_empty() {
+        return None;
+    }
+
+    acc.add(
+        AssistId("convert_return_string_to_str", AssistKind::RefactorRewrite),
+        "Return `&'static str` instead of `String`",
+        ty.syntax().text_range(),
+        |builder| {
+            builder.replace(ty.syntax().text_range(), "&'static str");
+            for (range, literal) in literals {
+                builder.replace(range, literal.to_string());
+            }
+        },
+    )
+}
+
+fn fn_ret_type_at_cursor(ctx: &AssistContext) -> Option<(ast::Fn, ast::Type, ast::BlockExpr)> {
+    let ret_type = ctx.find_node_at_offset::<ast::RetType>()?;
+    let func = ast::Fn::cast(ret_type.syntax().parent()?)?;
+    let body = func.body()?;
+    Some((func, ret_type.ty()?, body))
+}
+
+/// Collects the tail expression of `body`, looking into `if`s, `match`es and
+/// blocks, together with the arguments of all `return`s.
+fn returned_exprs(body: &ast::BlockExpr) -> Vec<ast::Expr> {
+    let mut res = Vec::new();
+    for node in body.syntax().descendants() {
+        if let Some(return_expr) = ast::ReturnExpr::cast(node.clone()) {
+            let in_nested_fn = return_expr
+                .syntax()
+                .ancestors()
+                .take_while(|it| it != body.syntax())
+                .any(|it| matches!(it.kind(), SyntaxKind::CLOSURE_EXPR | SyntaxKind::FN));
+            if let (Some(expr), false) = (return_expr.expr(), in_nested_fn) {
+                tail_exprs(expr, &mut res);
+            }
+        }
+    }
+    if let Some(expr) = body.expr() {
+        tail_exprs(expr, &mut res);
+    }
+    res
+}
+
+fn tail_exprs(expr: ast::Expr, acc: &mut Vec<ast::Expr>) {
+    match expr {
+        ast::Expr::BlockExpr(block) => {
+            if let Some(expr) = block.expr() {
+                tail_exprs(expr, acc);
+            }
+        }
+        ast::Expr::IfExpr(if_expr) => {
+            if let Some(block) = if_expr.then_branch() {
+                tail_exprs(block.into(), acc);
+            }
+            match if_expr.else_branch() {
+                Some(ast::ElseBranch::Block(block)) => tail_exprs(block.into(), acc),
+                Some(ast::ElseBranch::IfExpr(if_expr)) => tail_exprs(if_expr.into(), acc),
+                None => {}
+            }
+        }
+        ast::Expr::MatchExpr(match_expr) => {
+            let arms = match_expr.match_arm_list().into_iter().flat_map(|it| it.arms());
+            for expr in arms.filter_map(|arm| arm.expr()) {
+                tail_exprs(expr, acc);
+            }
+        }
+        ast::Expr::LoopExpr(loop_expr) => {
+            // `break` values of the loop are its result.
+            let breaks = loop_expr
+                .loop_body()
+                .into_iter()
+                .flat_map(|it| it.syntax().descendants())
+                .filter_map(ast::BreakExpr::cast);
+            for expr in breaks.filter_map(|it| it.expr()) {
+                acc.push(expr);
+            }
+        }
+        ast::Expr::ReturnExpr(_) => {}
+        _ => acc.push(expr),
+    }
+}
+
+/// Returns the literal of `"lit".to_string()`, `"lit".to_owned()`, `"lit".into()`
+/// and `String::from("lit")`.
+fn owned_string_literal(
+    ctx: &AssistContext,
+    string: hir::Struct,
+    expr: &ast::Expr,
+) -> Option<ast::Literal> {
+    let arg = match expr {
+        ast::Expr::MethodCallExpr(call) => {
+            let name = call.name_ref()?;
+            if !matches!(name.text().as_str(), "to_string" | "to_owned" | "into")
+                || call.arg_list()?.args().next().is_some()
+            {
+                return None;
+            }
+            call.receiver()?
+        }
+        ast::Expr::CallExpr(call) => {
+            let path = match call.expr()? {
+                ast::Expr::PathExpr(it) => it.path()?,
+                _ => return None,
+            };
+            if path.segment()?.name_ref()?.text() != "from" {
+                return None;
+            }
+            match ctx.sema.resolve_path(&path.qualifier()?)? {
+                PathResolution::Def(ModuleDef::Adt(Adt::Struct(it))) if it == string => {}
+                _ => return None,
+            }
+            let mut args = call.arg_list()?.args();
+            let arg = args.next()?;
+            if args.next().is_some() {
+                return None;
+            }
+            arg
+        }
+        _ => return None,
+    };
+    match arg {
+        ast::Expr::Literal(literal) if matches!(literal.kind(), ast::LiteralKind::String(_)) => {
+            Some(literal)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    const ALLOC: &str = r#"//- /liballoc.rs crate:alloc
+pub mod string { pub struct String; }
+"#;
+
+    fn with_alloc(ra_fixture: &str) -> String {
+        format!("//- /main.rs crate:main deps:alloc{}{}", ra_fixture, ALLOC)
+    }
+
+    #[test]
+    fn converts_tail_expression() {
+        check_assist(
+            convert_return_str_to_string,
+            r#"
+struct Person { name: String }
+fn name(p: &Person) -> &<|>str {
+    &p.name
+}
+"#,
+            r#"
+struct Person { name: String }
+fn name(p: &Person) -> String {
+    p.name.to_string()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn converts_returns_and_branches() {
+        check_assist(
+            convert_return_str_to_string,
+            r#"
+fn describe(n: i32) -> &'static str<|> {
+    if n < 0 {
+        return "negative";
+    }
+    match n {
+        0 => "zero",
+        _ => "positive",
+    }
+}
+"#,
+            r#"
+fn describe(n: i32) -> String {
+    if n < 0 {
+        return "negative".to_string();
+    }
+    match n {
+        0 => "zero".to_string(),
+        _ => "positive".to_string(),
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_types() {
+        check_assist_not_applicable(convert_return_str_to_string, r#"fn f() -> &<|>mut str {}"#);
+        check_assist_not_applicable(convert_return_str_to_string, r#"fn f() -> &[u8]<|> {}"#);
+    }
+
+    #[test]
+    fn converts_string_literals_back() {
+        check_assist(
+            convert_return_string_to_str,
+            &with_alloc(
+                r#"
+use alloc::string::String;
+fn greeting(formal: bool) -> String<|> {
+    if formal { String::from("Good day") } else { "Hi".to_owned() }
+}
+"#,
+            ),
+            r#"
+use alloc::string::String;
+fn greeting(formal: bool) -> &'static str {
+    if formal { "Good day" } else { "Hi" }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_computed_strings() {
+        check_assist_not_applicable(
+            convert_return_string_to_str,
+            &with_alloc(
+                r#"
+use alloc::string::String;
+fn greeting(name: &str) -> String<|> {
+    format!("Hi {}", name)
+}
+"#,
+            ),
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_string_types() {
+        check_assist_not_applicable(
+            convert_return_string_to_str,
+            r#"
+struct String;
+fn greeting() -> String<|> {
+    String::from("Hi")
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,340 @@
+use hir::ModuleDef;
+use ide_db::defs::Definition;
+use itertools::Itertools;
+use syntax::{
+    algo::find_node_at_range,
+    ast::{self, edit::IndentLevel, GenericParamsOwner, NameOwner},
+    AstNode, SyntaxKind, TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: convert_to_free_function
+//
+// Moves an associated function which doesn't take `self` out of its impl block.
+//
+// ```
+// struct Foo;
+// impl Foo {
+//     fn <|>helper(x: i32) -> i32 {
+//         x + 1
+//     }
+// }
+//
+// fn main() {
+//     Foo::helper(92);
+// }
+// ```
+// ->
+// ```
+// struct Foo;
+// impl Foo {
+// }
+//
+// fn helper(x: i32) -> i32 {
+//     x + 1
+// }
+//
+// fn main() {
+//     helper(92);
+// }
+// ```
+pub(crate) fn convert_to_free_function(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let fn_ = ctx.find_node_at_offset::<ast::Fn>()?;
+    if fn_.body().map_or(false, |body| body.syntax().text_range().contains(ctx.offset())) {
+        return None;
+    }
+    if fn_.param_list()?.self_param().is_some() {
+        return None;
+    }
+    let impl_ = fn_.syntax().parent().and_then(ast::AssocItemList::cast)?.syntax().parent()?;
+    let impl_ = ast::Impl::cast(impl_)?;
+    if impl_.trait_().is_some() {
+        return None;
+    }
+    let self_ty = impl_.self_ty()?;
+    let name = fn_.name()?;
+    let func = ctx.sema.to_def(&fn_)?;
+
+    // Edits of the function's own text, relative to its start.
+    let fn_start = fn_.syntax().text_range().start();
+    let mut fn_edits: Vec<(TextRange, String)> = Vec::new();
+    let mut call_site_edits = Vec::new();
+
+    let impl_module = ctx.sema.scope(impl_.syntax()).module()?;
+    let usages = Definition::ModuleDef(func.into()).usages(&ctx.sema).all();
+    for usage in usages {
+        let file_id = usage.file_range.file_id;
+        let source_file = ctx.sema.parse(file_id);
+        let path: ast::Path = match find_node_at_range(source_file.syntax(), usage.file_range.range)
+        {
+            Some(it) => it,
+            None => continue,
+        };
+        let qualifier = match path.qualifier() {
+            Some(it) => it,
+            None => continue,
+        };
+        // `Foo::helper` -> `helper`, keeping any generic arguments of the last segment.
+        let range = TextRange::new(
+            qualifier.syntax().text_range().start(),
+            path.segment().map_or(usage.file_range.range, |it| it.syntax().text_range()).start(),
+        );
+        if file_id == ctx.frange.file_id && fn_.syntax().text_range().contains_range(range) {
+            fn_edits.push((range - fn_start, String::new()));
+            continue;
+        }
+        // Outside of the impl's module, the function has to be qualified with that module.
+        let usage_module = ctx.sema.scope(path.syntax()).module()?;
+        let replacement = if usage_module == impl_module {
+            String::new()
+        } else {
+            format!("{}::", usage_module.find_use_path(ctx.db(), ModuleDef::Module(impl_module))?)
+        };
+        call_site_edits.push((file_id, range, replacement));
+    }
+
+    // `Self` can't be used outside of the impl, so spell out the type instead. In expressions,
+    // the type's generic arguments are left for inference.
+    let self_ty_in_expr = match &self_ty {
+        ast::Type::PathType(it) => {
+            let path = it.path()?;
+            let name_ref = path.segment()?.name_ref()?;
+            match path.qualifier() {
+                Some(qualifier) => format!("{}::{}", qualifier, name_ref),
+                None => name_ref.to_string(),
+            }
+        }
+        _ => self_ty.to_string(),
+    };
+    let removed_qualifiers: Vec<TextRange> = fn_edits.iter().map(|(range, _)| *range).collect();
+    for token in fn_.syntax().descendants_with_tokens().filter_map(|it| it.into_token()) {
+        let range = token.text_range() - fn_start;
+        if token.kind() != SyntaxKind::IDENT
+            || token.text() != "Self"
+            || removed_qualifiers.iter().any(|it| it.contains_range(range))
+        {
+            continue;
+        }
+        let in_type = token
+            .parent()
+            .ancestors()
+            .find(|it| {
+                !ast::NameRef::can_cast(it.kind())
+                    && !ast::PathSegment::can_cast(it.kind())
+                    && !ast::Path::can_cast(it.kind())
+            })
+            .map_or(false, |it| ast::PathType::can_cast(it.kind()));
+        let replacement = if in_type { self_ty.to_string() } else { self_ty_in_expr.clone() };
+        fn_edits.push((range, replacement));
+    }
+
+    if let Some(impl_params) = impl_.generic_param_list() {
+        // Lifetimes have to be declared before type and const parameters.
+        let (lifetimes, others): (Vec<_>, Vec<_>) = impl_params
+            .generic_params()
+            .chain(fn_.generic_param_list().into_iter().flat_map(|it| it.generic_params()))
+            .partition(|it| matches!(it, ast::GenericParam::LifetimeParam(_)));
+        let params = format!("<{}>", lifetimes.into_iter().chain(others).join(", "));
+        match fn_.generic_param_list() {
+            Some(it) => fn_edits.push((it.syntax().text_range() - fn_start, params)),
+            None => fn_edits
+                .push((TextRange::empty(name.syntax().text_range().end()) - fn_start, params)),
+        }
+    }
+    if let Some(impl_where) = impl_.where_clause() {
+        let predicates = impl_where.predicates().join(", ");
+        match fn_.where_clause().and_then(|it| it.predicates().last()) {
+            Some(last) => fn_edits.push((
+                TextRange::empty(last.syntax().text_range().end()) - fn_start,
+                format!(", {}", predicates),
+            )),
+            None => {
+                let offset = fn_.body()?.syntax().text_range().start();
+                fn_edits
+                    .push((TextRange::empty(offset) - fn_start, format!("where {} ", predicates)));
+            }
+        }
+    }
+
+    let mut fn_text = fn_.syntax().text().to_string();
+    fn_edits.sort_by_key(|(range, _)| range.start());
+    for (range, replacement) in fn_edits.into_iter().rev() {
+        fn_text.replace_range(std::ops::Range::<usize>::from(range), &replacement);
+    }
+    let fn_text = fn_text.replace(&format!("\n{}", IndentLevel(1)), "\n");
+
+    // Take the whitespace separating the function from the next item, or from the previous one
+    // if it's the last item.
+    let mut range_to_remove = fn_.syntax().text_range();
+    let ws = if fn_.syntax().next_sibling().is_some() {
+        fn_.syntax().next_sibling_or_token()
+    } else {
+        fn_.syntax().prev_sibling_or_token()
+    };
+    if let Some(ws) = ws.filter(|it| it.kind() == SyntaxKind::WHITESPACE) {
+        range_to_remove = range_to_remove.cover(ws.text_range());
+    }
+
+    acc.add(
+        AssistId("convert_to_free_function", AssistKind::RefactorRewrite),
+        "Convert to free function",
+        fn_.syntax().text_range(),
+        |builder| {
+            let indent = IndentLevel::from_node(impl_.syntax());
+            for (file_id, range, replacement) in call_site_edits {
+                builder.edit_file(file_id);
+                builder.replace(range, replacement);
+            }
+            builder.edit_file(ctx.frange.file_id);
+            builder.delete(range_to_remove);
+            builder.insert(impl_.syntax().text_range().end(), format!("\n\n{}{}", indent, fn_text));
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn converts_associated_function() {
+        check_assist(
+            convert_to_free_function,
+            r#"
+struct Foo;
+impl Foo {
+    fn new() -> Self {
+        Foo
+    }
+
+    fn hel<|>per(x: i32) -> i32 {
+        x + 1
+    }
+}
+
+fn main() {
+    Foo::helper(1);
+}
+"#,
+            r#"
+struct Foo;
+impl Foo {
+    fn new() -> Self {
+        Foo
+    }
+}
+
+fn helper(x: i32) -> i32 {
+    x + 1
+}
+
+fn main() {
+    helper(1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn moves_impl_generics_and_self() {
+        check_assist(
+            convert_to_free_function,
+            r#"
+struct Foo<T>(T);
+impl<T: Clone> Foo<T> {
+    fn <|>wrap<U>(x: &T, _u: U) -> Self {
+        Self(x.clone())
+    }
+
+    fn get(&self) -> Self {
+        Self::wrap(&self.0, ())
+    }
+}
+"#,
+            r#"
+struct Foo<T>(T);
+impl<T: Clone> Foo<T> {
+    fn get(&self) -> Self {
+        wrap(&self.0, ())
+    }
+}
+
+fn wrap<T: Clone, U>(x: &T, _u: U) -> Foo<T> {
+    Foo(x.clone())
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_methods() {
+        check_assist_not_applicable(
+            convert_to_free_function,
+            r#"
+struct Foo;
+impl Foo {
+    fn <|>foo(&self) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_in_trait_impls() {
+        check_assist_not_applicable(
+            convert_to_free_function,
+            r#"
+trait Trait { fn foo(); }
+struct Foo;
+impl Trait for Foo {
+    fn <|>foo() {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualifies_calls_from_other_modules() {
+        check_assist(
+            convert_to_free_function,
+            r#"
+//- /main.rs
+mod foo;
+mod bar {
+    pub struct Bar;
+    impl Bar {
+        pub fn <|>make() -> Bar {
+            Bar
+        }
+    }
+}
+//- /foo.rs
+fn f() {
+    crate::bar::Bar::make();
+    super::bar::Bar::make();
+}
+"#,
+            r#"
+//- /main.rs
+mod foo;
+mod bar {
+    pub struct Bar;
+    impl Bar {
+    }
+
+    pub fn make() -> Bar {
+        Bar
+    }
+}
+//- /foo.rs
+fn f() {
+    crate::bar::make();
+    crate::bar::make();
+}
+"#,
+        );
+    }
+}
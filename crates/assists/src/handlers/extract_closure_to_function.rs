@@ -0,0 +1,374 @@
+use hir::{HirDisplay, PathResolution};
+use itertools::Itertools;
+use syntax::{
+    algo::SyntaxRewriter,
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, AstNode, NameOwner,
+    },
+    SyntaxKind, T,
+};
+
+use crate::{
+    utils::{is_autoderefed, is_passed_by_copy},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: extract_closure_to_function
+//
+// Extracts a closure into a free function. Variables captured by the closure
+// become additional parameters of the function, mutable ones by `&mut` and
+// other non-`Copy` ones by `&`.
+//
+// ```
+// fn main() {
+//     let add_one = <|>|x: i32| x + 1;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let add_one = fun_name;
+// }
+//
+// fn $0fun_name(x: i32) -> i32 {
+//     x + 1
+// }
+// ```
+pub(crate) fn extract_closure_to_function(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let closure = ctx.find_node_at_offset::<ast::ClosureExpr>()?;
+    let body = closure.body()?;
+    let module = ctx.sema.scope(closure.syntax()).module()?;
+
+    let mut params = Vec::new();
+    let mut closure_params = Vec::new();
+    let mut args = Vec::new();
+    for (idx, param) in closure.param_list()?.params().enumerate() {
+        let pat = param.pat()?;
+        let ty = match param.ty() {
+            Some(ty) => ty.to_string(),
+            None => {
+                ctx.sema.type_of_pat(&pat)?.display_source_code(ctx.db(), module.into()).ok()?
+            }
+        };
+        params.push(format!("{}: {}", pat, ty));
+        // Only plain bindings can be forwarded as they are, everything else
+        // (`_`, tuple patterns, ...) gets a fresh name in the forwarding closure.
+        let arg = match &pat {
+            ast::Pat::IdentPat(ident) if ident.pat().is_none() && ident.ref_token().is_none() => {
+                ident.name()?.to_string()
+            }
+            _ => format!("arg{}", idx),
+        };
+        closure_params.push(match param.ty() {
+            Some(ty) => format!("{}: {}", arg, ty),
+            None => arg.clone(),
+        });
+        args.push(arg);
+    }
+
+    let mut captures = Vec::new();
+    let mut rewriter = SyntaxRewriter::default();
+    for path_expr in body.syntax().descendants().filter_map(ast::PathExpr::cast) {
+        let local = match ctx.sema.resolve_path(&path_expr.path()?) {
+            Some(PathResolution::Local(local)) => local,
+            _ => continue,
+        };
+        if local.is_self(ctx.db()) {
+            return None;
+        }
+        let is_declared_in_closure = local.source(ctx.db()).value.either(
+            |pat| closure.syntax().text_range().contains_range(pat.syntax().text_range()),
+            |_| false,
+        );
+        if is_declared_in_closure {
+            continue;
+        }
+        // Mutable and non-`Copy` captures are passed by reference, so their uses
+        // need a deref unless autoderef already takes care of it.
+        let by_ref = local.is_mut(ctx.db()) || !is_passed_by_copy(ctx.db(), &local.ty(ctx.db()));
+        if by_ref && !is_autoderefed(&path_expr) {
+            let deref = make::expr_prefix(T![*], path_expr.clone().into());
+            rewriter.replace(path_expr.syntax(), deref.syntax());
+        }
+        if !captures.contains(&local) {
+            captures.push(local);
+        }
+    }
+    let has_captures = !captures.is_empty();
+    for local in captures {
+        let name = local.name(ctx.db())?.to_string();
+        let ty = local.ty(ctx.db()).display_source_code(ctx.db(), module.into()).ok()?;
+        if local.is_mut(ctx.db()) {
+            params.push(format!("{}: &mut {}", name, ty));
+            args.push(format!("&mut {}", name));
+        } else if !is_passed_by_copy(ctx.db(), &local.ty(ctx.db())) {
+            params.push(format!("{}: &{}", name, ty));
+            args.push(format!("&{}", name));
+        } else {
+            params.push(format!("{}: {}", name, ty));
+            args.push(name);
+        }
+    }
+
+    let ret_ty = match closure.ret_type() {
+        Some(ret_type) => Some(ret_type.ty()?.to_string()),
+        None => {
+            let ty = ctx.sema.type_of_expr(&body)?;
+            if ty.is_unit() {
+                None
+            } else {
+                Some(ty.display_source_code(ctx.db(), module.into()).ok()?)
+            }
+        }
+    };
+
+    let new_body = rewriter.rewrite_ast(&body);
+
+    // The function goes right after the item which contains the closure.
+    let item =
+        closure.syntax().ancestors().filter(|it| ast::Item::can_cast(it.kind())).find(|it| {
+            it.parent().map_or(false, |parent| {
+                matches!(parent.kind(), SyntaxKind::SOURCE_FILE | SyntaxKind::ITEM_LIST)
+            })
+        })?;
+    let indent = IndentLevel::from_node(&item);
+
+    let target = closure.syntax().text_range();
+    acc.add(
+        AssistId("extract_closure_to_function", AssistKind::RefactorExtract),
+        "Extract closure to function",
+        target,
+        |builder| {
+            let fn_name = "fun_name";
+            let body = match &new_body {
+                ast::Expr::BlockExpr(block) => block
+                    .dedent(IndentLevel::from_node(closure.syntax()))
+                    .indent(indent)
+                    .to_string(),
+                _ => format!("{{\n{}{}\n{}}}", indent + 1, new_body, indent),
+            };
+            let ret_ty = ret_ty.map(|it| format!(" -> {}", it)).unwrap_or_default();
+            let fn_def = format!(
+                "fn {}{}({}){} {}",
+                if ctx.config.snippet_cap.is_some() { "$0" } else { "" },
+                fn_name,
+                params.iter().format(", "),
+                ret_ty,
+                body
+            );
+            let fn_def = format!("\n\n{}{}", indent, fn_def);
+
+            let replacement = if !has_captures {
+                fn_name.to_string()
+            } else {
+                format!(
+                    "|{}| {}({})",
+                    closure_params.iter().format(", "),
+                    fn_name,
+                    args.iter().format(", ")
+                )
+            };
+            builder.replace(target, replacement);
+
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(cap, item.text_range().end(), fn_def),
+                None => builder.insert(item.text_range().end(), fn_def),
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn extract_non_capturing_closure() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+fn main() {
+    let add_one = <|>|x: i32| x + 1;
+}
+"#,
+            r#"
+fn main() {
+    let add_one = fun_name;
+}
+
+fn $0fun_name(x: i32) -> i32 {
+    x + 1
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_closure_with_block_body() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+fn main() {
+    let print = |<|>x: i32| {
+        let y = x;
+        y;
+    };
+}
+"#,
+            r#"
+fn main() {
+    let print = fun_name;
+}
+
+fn $0fun_name(x: i32) {
+    let y = x;
+    y;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_capturing_closure() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+fn main() {
+    let offset = 92i32;
+    let add = <|>|x: i32| x + offset;
+}
+"#,
+            r#"
+fn main() {
+    let offset = 92i32;
+    let add = |x: i32| fun_name(x, offset);
+}
+
+fn $0fun_name(x: i32, offset: i32) -> i32 {
+    x + offset
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_closure_not_applicable_outside_closure() {
+        check_assist_not_applicable(
+            extract_closure_to_function,
+            r#"
+fn main() {
+    let <|>add_one = |x: i32| x + 1;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_closure_capturing_mutable_variable() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+struct Log;
+impl Log {
+    fn push(&mut self, _: i32) {}
+}
+
+fn main() {
+    let mut count = 0i32;
+    let mut log = Log;
+    let mut inc = <|>|x: i32| {
+        count += x;
+        log.push(count);
+    };
+}
+"#,
+            r#"
+struct Log;
+impl Log {
+    fn push(&mut self, _: i32) {}
+}
+
+fn main() {
+    let mut count = 0i32;
+    let mut log = Log;
+    let mut inc = |x: i32| fun_name(x, &mut count, &mut log);
+}
+
+fn $0fun_name(x: i32, count: &mut i32, log: &mut Log) {
+    *count += x;
+    log.push(*count);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_capturing_closure_with_wildcard_param() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+fn main() {
+    let offset = 92i32;
+    let constant = <|>|_: i32| offset;
+}
+"#,
+            r#"
+fn main() {
+    let offset = 92i32;
+    let constant = |arg0: i32| fun_name(arg0, offset);
+}
+
+fn $0fun_name(_: i32, offset: i32) -> i32 {
+    offset
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_closure_borrowing_non_copy_variable() {
+        check_assist(
+            extract_closure_to_function,
+            r#"
+struct String;
+impl String {
+    fn len(&self) -> usize { 0 }
+}
+fn print(_: &String) {}
+
+fn main() {
+    let name = String;
+    let greet = <|>|x: usize| {
+        print(&name);
+        x + name.len()
+    };
+    greet(1);
+    greet(2);
+}
+"#,
+            r#"
+struct String;
+impl String {
+    fn len(&self) -> usize { 0 }
+}
+fn print(_: &String) {}
+
+fn main() {
+    let name = String;
+    let greet = |x: usize| fun_name(x, &name);
+    greet(1);
+    greet(2);
+}
+
+fn $0fun_name(x: usize, name: &String) -> usize {
+    print(&*name);
+    x + name.len()
+}
+"#,
+        );
+    }
+}
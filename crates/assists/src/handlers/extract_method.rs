@@ -0,0 +1,391 @@
+use hir::{HirDisplay, PathResolution};
+use itertools::Itertools;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    NodeOrToken, SyntaxKind, SyntaxNode, TextRange,
+};
+
+use crate::{
+    utils::{is_autoderefed, is_passed_by_copy},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: extract_method
+//
+// Extracts the selected statements of a method into a new method of the same impl.
+// Locals used by the statements become parameters, non-`Copy` ones by `&`.
+//
+// ```
+// struct Counter { count: u32 }
+// impl Counter {
+//     fn bump(&mut self, step: u32) {
+//         <|>let next = self.count + step;
+//         self.count = next;<|>
+//     }
+// }
+// ```
+// ->
+// ```
+// struct Counter { count: u32 }
+// impl Counter {
+//     fn bump(&mut self, step: u32) {
+//         self.fun_name(step);
+//     }
+//
+//     fn $0fun_name(&mut self, step: u32) {
+//         let next = self.count + step;
+//         self.count = next;
+//     }
+// }
+// ```
+pub(crate) fn extract_method(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let range = ctx.frange.range;
+    if range.is_empty() {
+        return None;
+    }
+    let block = ctx.covering_element().ancestors().find_map(ast::BlockExpr::cast)?;
+    let fn_ = block.syntax().ancestors().find_map(ast::Fn::cast)?;
+    let impl_ = fn_.syntax().parent().and_then(ast::AssocItemList::cast)?.syntax().parent()?;
+    if !ast::Impl::can_cast(impl_.kind()) {
+        return None;
+    }
+    let fn_body = fn_.body()?;
+    // Statements of nested closures or items would end up in the wrong function.
+    if block
+        .syntax()
+        .ancestors()
+        .take_while(|it| it != fn_body.syntax())
+        .any(|it| ast::ClosureExpr::can_cast(it.kind()) || ast::Item::can_cast(it.kind()))
+    {
+        return None;
+    }
+
+    let selected = selected_nodes(&block, range)?;
+    let first = selected.first()?;
+    let last = selected.last()?;
+    let target = TextRange::new(first.text_range().start(), last.text_range().end());
+    let tail = block.expr().filter(|it| it.syntax() == last);
+
+    // Control flow can't leave the new method.
+    if selected.iter().flat_map(|it| it.descendants()).any(|it| {
+        matches!(
+            it.kind(),
+            SyntaxKind::RETURN_EXPR
+                | SyntaxKind::BREAK_EXPR
+                | SyntaxKind::CONTINUE_EXPR
+                | SyntaxKind::TRY_EXPR
+                | SyntaxKind::AWAIT_EXPR
+        )
+    }) {
+        return None;
+    }
+
+    let module = ctx.sema.scope(block.syntax()).module()?;
+    let mut uses_self = false;
+    let mut captures = Vec::new();
+    let mut derefs = Vec::new();
+    for path_expr in selected.iter().flat_map(|it| it.descendants()).filter_map(ast::PathExpr::cast)
+    {
+        let local = match ctx.sema.resolve_path(&path_expr.path()?) {
+            Some(PathResolution::Local(local)) => local,
+            _ => continue,
+        };
+        if local.is_self(ctx.db()) {
+            uses_self = true;
+            continue;
+        }
+        let is_declared_inside = local
+            .source(ctx.db())
+            .value
+            .either(|pat| target.contains_range(pat.syntax().text_range()), |_| false);
+        if is_declared_inside {
+            continue;
+        }
+        // Changes to a mutable local wouldn't be visible to the caller.
+        if local.is_mut(ctx.db()) {
+            return None;
+        }
+        // Non-`Copy` locals are passed by reference, so their uses need a deref
+        // unless autoderef already takes care of it.
+        if !is_passed_by_copy(ctx.db(), &local.ty(ctx.db())) && !is_autoderefed(&path_expr) {
+            derefs.push(path_expr.syntax().text_range().start() - target.start());
+        }
+        if !captures.contains(&local) {
+            captures.push(local);
+        }
+    }
+
+    // Bindings introduced by the selection can't be used after it.
+    for path_expr in fn_body.syntax().descendants().filter_map(ast::PathExpr::cast) {
+        if path_expr.syntax().text_range().start() < target.end() {
+            continue;
+        }
+        if let Some(PathResolution::Local(local)) = ctx.sema.resolve_path(&path_expr.path()?) {
+            let is_declared_inside = local
+                .source(ctx.db())
+                .value
+                .either(|pat| target.contains_range(pat.syntax().text_range()), |_| false);
+            if is_declared_inside {
+                return None;
+            }
+        }
+    }
+
+    let self_param = if uses_self {
+        let self_param = fn_.param_list()?.self_param()?;
+        // Moving `self` into the new method would leave nothing for the rest of the body.
+        if self_param.amp_token().is_none() {
+            return None;
+        }
+        Some(self_param.to_string())
+    } else {
+        None
+    };
+
+    let mut params = Vec::new();
+    let mut args = Vec::new();
+    for local in captures {
+        let name = local.name(ctx.db())?.to_string();
+        let ty = local.ty(ctx.db());
+        let ty_text = ty.display_source_code(ctx.db(), module.into()).ok()?;
+        if is_passed_by_copy(ctx.db(), &ty) {
+            params.push(format!("{}: {}", name, ty_text));
+            args.push(name);
+        } else {
+            params.push(format!("{}: &{}", name, ty_text));
+            args.push(format!("&{}", name));
+        }
+    }
+    let ret_ty = match &tail {
+        Some(tail) => {
+            let ty = ctx.sema.type_of_expr(tail)?;
+            if ty.is_unit() {
+                None
+            } else {
+                Some(ty.display_source_code(ctx.db(), module.into()).ok()?)
+            }
+        }
+        None => None,
+    };
+
+    let fn_indent = IndentLevel::from_node(fn_.syntax());
+    let body_indent = IndentLevel::from_node(first);
+    let mut text =
+        block.syntax().text().slice(target - block.syntax().text_range().start()).to_string();
+    for offset in derefs.into_iter().rev() {
+        text.insert(usize::from(offset), '*');
+    }
+    acc.add(
+        AssistId("extract_method", AssistKind::RefactorExtract),
+        "Extract to method",
+        target,
+        |builder| {
+            let fn_name = "fun_name";
+            let body = text
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    let line = if i == 0 {
+                        line
+                    } else {
+                        line.strip_prefix(&body_indent.to_string()).unwrap_or(line)
+                    };
+                    if line.is_empty() {
+                        String::new()
+                    } else {
+                        format!("{}{}", fn_indent + 1, line)
+                    }
+                })
+                .join("\n");
+            let params = self_param.iter().chain(params.iter()).format(", ");
+            let ret_ty = ret_ty.as_ref().map(|it| format!(" -> {}", it)).unwrap_or_default();
+            let method = format!(
+                "\n\n{}fn {}{}({}){} {{\n{}\n{}}}",
+                fn_indent,
+                if ctx.config.snippet_cap.is_some() { "$0" } else { "" },
+                fn_name,
+                params,
+                ret_ty,
+                body,
+                fn_indent,
+            );
+
+            let receiver = if uses_self { "self" } else { "Self" };
+            let separator = if uses_self { "." } else { "::" };
+            let call =
+                format!("{}{}{}({})", receiver, separator, fn_name, args.iter().format(", "));
+            let call = if tail.is_some() { call } else { format!("{};", call) };
+            builder.replace(target, call);
+
+            let position = fn_.syntax().text_range().end();
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(cap, position, method),
+                None => builder.insert(position, method),
+            }
+        },
+    )
+}
+
+/// Returns the statements, and possibly the tail expression, of `block` covered by `range`.
+/// Only whitespace and comments may be selected partially.
+fn selected_nodes(block: &ast::BlockExpr, range: TextRange) -> Option<Vec<SyntaxNode>> {
+    let mut selected = Vec::new();
+    for child in block.syntax().children_with_tokens() {
+        if child.text_range().intersect(range).map_or(true, |it| it.is_empty()) {
+            continue;
+        }
+        match child {
+            NodeOrToken::Node(node) => {
+                if !range.contains_range(node.text_range()) {
+                    return None;
+                }
+                selected.push(node);
+            }
+            NodeOrToken::Token(token) => {
+                if !matches!(token.kind(), SyntaxKind::WHITESPACE | SyntaxKind::COMMENT) {
+                    return None;
+                }
+            }
+        }
+    }
+    Some(selected)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn extracts_statements_using_self() {
+        check_assist(
+            extract_method,
+            r#"
+struct Counter { count: u32 }
+impl Counter {
+    fn bump(&mut self, step: u32) -> u32 {
+        let before = self.count;
+        <|>let next = self.count + step;
+        self.count = next;<|>
+        before
+    }
+}
+"#,
+            r#"
+struct Counter { count: u32 }
+impl Counter {
+    fn bump(&mut self, step: u32) -> u32 {
+        let before = self.count;
+        self.fun_name(step);
+        before
+    }
+
+    fn $0fun_name(&mut self, step: u32) {
+        let next = self.count + step;
+        self.count = next;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extracts_tail_expression_without_self() {
+        check_assist(
+            extract_method,
+            r#"
+struct S;
+impl S {
+    fn sum(a: i32, b: i32) -> i32 {
+        let c = 2;
+        <|>a + b * c<|>
+    }
+}
+"#,
+            r#"
+struct S;
+impl S {
+    fn sum(a: i32, b: i32) -> i32 {
+        let c = 2;
+        Self::fun_name(a, b, c)
+    }
+
+    fn $0fun_name(a: i32, b: i32, c: i32) -> i32 {
+        a + b * c
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_bindings_are_used_later() {
+        check_assist_not_applicable(
+            extract_method,
+            r#"
+struct S;
+impl S {
+    fn foo(&self) -> i32 {
+        <|>let a = 1;<|>
+        a
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_outside_impls() {
+        check_assist_not_applicable(
+            extract_method,
+            r#"
+fn foo() -> i32 {
+    <|>let a = 1;
+    a<|>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn borrows_non_copy_locals() {
+        check_assist(
+            extract_method,
+            r#"
+struct String;
+impl String {
+    fn len(&self) -> usize { 0 }
+}
+fn print(_: &String) {}
+
+struct S;
+impl S {
+    fn greet(&self, name: String) -> usize {
+        <|>print(&name);<|>
+        name.len()
+    }
+}
+"#,
+            r#"
+struct String;
+impl String {
+    fn len(&self) -> usize { 0 }
+}
+fn print(_: &String) {}
+
+struct S;
+impl S {
+    fn greet(&self, name: String) -> usize {
+        Self::fun_name(&name);
+        name.len()
+    }
+
+    fn $0fun_name(name: &String) {
+        print(&*name);
+    }
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,143 @@
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    SyntaxKind,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: extract_type_alias
+//
+// Extracts the type under the cursor into a type alias.
+//
+// ```
+// struct S {
+//     field: <|>HashMap<String, Vec<(u32, String)>>,
+// }
+// ```
+// ->
+// ```
+// type $0Type = HashMap<String, Vec<(u32, String)>>;
+//
+// struct S {
+//     field: Type,
+// }
+// ```
+pub(crate) fn extract_type_alias(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let innermost_ty = if ctx.frange.range.is_empty() {
+        ctx.find_node_at_offset::<ast::Type>()?
+    } else {
+        ctx.covering_element().ancestors().find_map(ast::Type::cast)?
+    };
+    // Aliasing a plain name like `u32` doesn't buy anything, so look for the enclosing type.
+    let ty = innermost_ty.syntax().ancestors().filter_map(ast::Type::cast).find(|ty| match ty {
+        ast::Type::PathType(path_type) => {
+            path_type.syntax().descendants().any(|it| ast::GenericArgList::can_cast(it.kind()))
+        }
+        _ => true,
+    })?;
+
+    // The alias goes right before the module level item which contains the type.
+    let item = ty.syntax().ancestors().filter(|it| ast::Item::can_cast(it.kind())).find(|it| {
+        it.parent().map_or(false, |parent| {
+            matches!(parent.kind(), SyntaxKind::SOURCE_FILE | SyntaxKind::ITEM_LIST)
+        })
+    })?;
+    let indent = IndentLevel::from_node(&item);
+
+    let target = ty.syntax().text_range();
+    acc.add(
+        AssistId("extract_type_alias", AssistKind::RefactorExtract),
+        "Extract type as type alias",
+        target,
+        |builder| {
+            let name = "Type";
+            builder.replace(target, name);
+            let insert_offset = item.text_range().start();
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(
+                    cap,
+                    insert_offset,
+                    format!("type $0{} = {};\n\n{}", name, ty, indent),
+                ),
+                None => {
+                    builder.insert(insert_offset, format!("type {} = {};\n\n{}", name, ty, indent))
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn extract_nested_generic_type() {
+        check_assist(
+            extract_type_alias,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Vec<T>(T);
+
+fn foo(map: <|>HashMap<String, Vec<(u32, String)>>) {}
+"#,
+            r#"
+struct HashMap<K, V>(K, V);
+struct Vec<T>(T);
+
+type $0Type = HashMap<String, Vec<(u32, String)>>;
+
+fn foo(map: Type) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_inner_type() {
+        check_assist(
+            extract_type_alias,
+            r#"
+struct Vec<T>(T);
+
+fn foo(v: Vec<(u32, <|>String)>) {}
+"#,
+            r#"
+struct Vec<T>(T);
+
+type $0Type = (u32, String);
+
+fn foo(v: Vec<Type>) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn extract_type_in_nested_module() {
+        check_assist(
+            extract_type_alias,
+            r#"
+mod m {
+    struct S {
+        field: <|>(u8, u8, u8),
+    }
+}
+"#,
+            r#"
+mod m {
+    type $0Type = (u8, u8, u8);
+
+    struct S {
+        field: Type,
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_plain_path() {
+        check_assist_not_applicable(extract_type_alias, "fn foo(x: u<|>32) {}");
+    }
+}
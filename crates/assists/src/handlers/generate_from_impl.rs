@@ -0,0 +1,127 @@
+use syntax::ast::{self, AstNode, NameOwner};
+
+use crate::{utils::generate_trait_impl_text, AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: generate_from_impl
+//
+// Adds a `From` impl skeleton for the type. The source type defaults to the
+// type of the only field, if there is one.
+//
+// ```
+// struct <|>Meters(u32);
+// ```
+// ->
+// ```
+// struct Meters(u32);
+//
+// impl From<${1:u32}> for Meters {
+//     fn from(value: ${1:u32}) -> Self {
+//         ${0:todo!()}
+//     }
+// }
+//
+// ```
+pub(crate) fn generate_from_impl(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let adt = ctx.find_node_at_offset::<ast::AdtDef>()?;
+    let name = adt.name()?;
+    if ctx.offset() > name.syntax().text_range().end() {
+        return None;
+    }
+
+    let source_type = match &adt {
+        ast::AdtDef::Struct(strukt) => single_field_type(strukt),
+        _ => None,
+    }
+    .map_or_else(|| "()".to_string(), |it| it.to_string());
+
+    let target = adt.syntax().text_range();
+    acc.add(
+        AssistId("generate_from_impl", AssistKind::Generate),
+        "Implement `From`",
+        target,
+        |builder| {
+            let start_offset = adt.syntax().text_range().end();
+            let (source_type, body) = match ctx.config.snippet_cap {
+                Some(_) => (format!("${{1:{}}}", source_type), "${0:todo!()}"),
+                None => (source_type, "todo!()"),
+            };
+            let code =
+                format!("    fn from(value: {}) -> Self {{\n        {}\n    }}", source_type, body);
+            let buf = generate_trait_impl_text(&adt, &format!("From<{}>", source_type), &code);
+            match ctx.config.snippet_cap {
+                Some(cap) => builder.insert_snippet(cap, start_offset, buf),
+                None => builder.insert(start_offset, buf),
+            }
+        },
+    )
+}
+
+fn single_field_type(strukt: &ast::Struct) -> Option<ast::Type> {
+    let mut types: Vec<ast::Type> = match strukt.kind() {
+        ast::StructKind::Record(fields) => fields.fields().filter_map(|it| it.ty()).collect(),
+        ast::StructKind::Tuple(fields) => fields.fields().filter_map(|it| it.ty()).collect(),
+        ast::StructKind::Unit => return None,
+    };
+    if types.len() != 1 {
+        return None;
+    }
+    types.pop()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn generates_from_impl_for_newtype() {
+        check_assist(
+            generate_from_impl,
+            r#"
+struct <|>Meters(u32);
+"#,
+            r#"
+struct Meters(u32);
+
+impl From<${1:u32}> for Meters {
+    fn from(value: ${1:u32}) -> Self {
+        ${0:todo!()}
+    }
+}
+
+"#,
+        );
+    }
+
+    #[test]
+    fn generates_from_impl_with_generics() {
+        check_assist(
+            generate_from_impl,
+            r#"
+enum <|>Either<L, R> {
+    Left(L),
+    Right(R),
+}
+"#,
+            r#"
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L, R> From<${1:()}> for Either<L, R> {
+    fn from(value: ${1:()}) -> Self {
+        ${0:todo!()}
+    }
+}
+
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_inside_body() {
+        check_assist_not_applicable(generate_from_impl, r#"struct Point { x: <|>i32, y: i32 }"#);
+    }
+}
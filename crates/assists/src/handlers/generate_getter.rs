@@ -0,0 +1,211 @@
+use syntax::{
+    ast::{self, AstNode},
+    TextSize,
+};
+
+use crate::{
+    utils::{find_field_at_offset, find_struct_impl, generate_impl_text, getter_name},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_getter
+//
+// Generate a getter method for a field. `Copy` fields are returned by value,
+// everything else by reference. Getters for tuple fields are named `field_0`,
+// `field_1`, etc.
+//
+// ```
+// struct Person {
+//     nam<|>e: String,
+// }
+// ```
+// ->
+// ```
+// struct Person {
+//     name: String,
+// }
+//
+// impl Person {
+//     pub fn $0name(&self) -> &String {
+//         &self.name
+//     }
+// }
+//
+// ```
+pub(crate) fn generate_getter(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+    let (field, field_name, field_ty, target) = find_field_at_offset(ctx)?;
+    let is_copy = field.signature_ty(ctx.db()).is_copy(ctx.db());
+
+    // Return early if we've found an existing fn
+    let fn_name = getter_name(&field_name);
+    let impl_def = find_struct_impl(&ctx, &strukt, &fn_name)?;
+
+    acc.add(
+        AssistId("generate_getter", AssistKind::Generate),
+        "Generate getter",
+        target,
+        |builder| {
+            let (ret_ty, body) = if is_copy {
+                (field_ty.to_string(), format!("self.{}", field_name))
+            } else {
+                (format!("&{}", field_ty), format!("&self.{}", field_name))
+            };
+            let mut buf =
+                format!("    pub fn {}(&self) -> {} {{\n        {}\n    }}", fn_name, ret_ty, body);
+
+            let start_offset = match impl_def {
+                Some(impl_def) => {
+                    buf = format!("\n{}\n", buf);
+                    impl_def.syntax().text_range().end() - TextSize::of('}')
+                }
+                None => {
+                    buf = generate_impl_text(&strukt, &buf);
+                    strukt.syntax().text_range().end()
+                }
+            };
+
+            match ctx.config.snippet_cap {
+                None => builder.insert(start_offset, buf),
+                Some(cap) => {
+                    buf = buf.replace(&format!("fn {}(", fn_name), &format!("fn $0{}(", fn_name));
+                    builder.insert_snippet(cap, start_offset, buf);
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn test_generate_getter_for_non_copy_field() {
+        check_assist(
+            generate_getter,
+            r#"
+struct Person {
+    name: String<|>,
+}
+"#,
+            r#"
+struct Person {
+    name: String,
+}
+
+impl Person {
+    pub fn $0name(&self) -> &String {
+        &self.name
+    }
+}
+
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_getter_for_copy_field() {
+        check_assist(
+            generate_getter,
+            r#"
+#[lang = "copy"]
+pub trait Copy {}
+impl Copy for u32 {}
+
+struct Person {
+    age<|>: u32,
+}
+
+impl Person {
+    fn older(&self) -> bool {
+        self.age > 30
+    }
+}
+"#,
+            r#"
+#[lang = "copy"]
+pub trait Copy {}
+impl Copy for u32 {}
+
+struct Person {
+    age: u32,
+}
+
+impl Person {
+    fn older(&self) -> bool {
+        self.age > 30
+    }
+
+    pub fn $0age(&self) -> u32 {
+        self.age
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_getter_not_applicable_if_fn_exists() {
+        check_assist_not_applicable(
+            generate_getter,
+            r#"
+struct Person {
+    name: String<|>,
+}
+
+impl Person {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_getter_not_applicable_if_fn_exists_in_other_impl() {
+        check_assist_not_applicable(
+            generate_getter,
+            r#"
+struct Person {
+    name: String<|>,
+}
+
+impl Person {
+    fn new() -> Person {
+        Person { name: String::new() }
+    }
+}
+
+impl Person {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_getter_for_tuple_field() {
+        check_assist(
+            generate_getter,
+            r#"
+struct Wrapper(String<|>);
+"#,
+            r#"
+struct Wrapper(String);
+
+impl Wrapper {
+    pub fn $0field_0(&self) -> &String {
+        &self.0
+    }
+}
+
+"#,
+        );
+    }
+}
@@ -1,12 +1,14 @@
-use hir::Adt;
 use itertools::Itertools;
 use stdx::format_to;
 use syntax::{
-    ast::{self, AstNode, GenericParamsOwner, NameOwner, StructKind, VisibilityOwner},
+    ast::{self, AstNode, NameOwner, StructKind, VisibilityOwner},
     T,
 };
 
-use crate::{AssistContext, AssistId, AssistKind, Assists};
+use crate::{
+    utils::{find_struct_impl, generate_impl_text},
+    AssistContext, AssistId, AssistKind, Assists,
+};
 
 // Assist: generate_new
 //
@@ -31,14 +33,30 @@ use crate::{AssistContext, AssistId, AssistKind, Assists};
 pub(crate) fn generate_new(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
     let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
 
-    // We want to only apply this to non-union structs with named fields
-    let field_list = match strukt.kind() {
-        StructKind::Record(named) => named,
-        _ => return None,
+    // We want to only apply this to structs with fields, named or not
+    let (params, init) = match strukt.kind() {
+        StructKind::Record(named) => {
+            let params = named
+                .fields()
+                .filter_map(|f| Some(format!("{}: {}", f.name()?.syntax(), f.ty()?.syntax())))
+                .join(", ");
+            let fields = named.fields().filter_map(|f| f.name()).join(", ");
+            (params, format!("Self {{ {} }}", fields))
+        }
+        StructKind::Tuple(tuple) => {
+            let params = tuple
+                .fields()
+                .enumerate()
+                .filter_map(|(idx, f)| Some(format!("field{}: {}", idx, f.ty()?.syntax())))
+                .join(", ");
+            let fields = (0..tuple.fields().count()).map(|idx| format!("field{}", idx)).join(", ");
+            (params, format!("Self({})", fields))
+        }
+        StructKind::Unit => return None,
     };
 
     // Return early if we've found an existing new fn
-    let impl_def = find_struct_impl(&ctx, &strukt)?;
+    let impl_def = find_struct_impl(&ctx, &strukt, "new")?;
 
     let target = strukt.syntax().text_range();
     acc.add(AssistId("generate_new", AssistKind::Generate), "Generate `new`", target, |builder| {
@@ -50,13 +68,7 @@ pub(crate) fn generate_new(acc: &mut Assists, ctx: &AssistContext) -> Option<()>
 
         let vis = strukt.visibility().map_or(String::new(), |v| format!("{} ", v));
 
-        let params = field_list
-            .fields()
-            .filter_map(|f| Some(format!("{}: {}", f.name()?.syntax(), f.ty()?.syntax())))
-            .format(", ");
-        let fields = field_list.fields().filter_map(|f| f.name()).format(", ");
-
-        format_to!(buf, "    {}fn new({}) -> Self {{ Self {{ {} }} }}", vis, params, fields);
+        format_to!(buf, "    {}fn new({}) -> Self {{ {} }}", vis, params, init);
 
         let start_offset = impl_def
             .and_then(|impl_def| {
@@ -85,91 +97,6 @@ pub(crate) fn generate_new(acc: &mut Assists, ctx: &AssistContext) -> Option<()>
     })
 }
 
-// Generates the surrounding `impl Type { <code> }` including type and lifetime
-// parameters
-fn generate_impl_text(strukt: &ast::Struct, code: &str) -> String {
-    let type_params = strukt.generic_param_list();
-    let mut buf = String::with_capacity(code.len());
-    buf.push_str("\n\nimpl");
-    if let Some(type_params) = &type_params {
-        format_to!(buf, "{}", type_params.syntax());
-    }
-    buf.push_str(" ");
-    buf.push_str(strukt.name().unwrap().text().as_str());
-    if let Some(type_params) = type_params {
-        let lifetime_params = type_params
-            .lifetime_params()
-            .filter_map(|it| it.lifetime())
-            .map(|it| it.text().clone());
-        let type_params =
-            type_params.type_params().filter_map(|it| it.name()).map(|it| it.text().clone());
-        format_to!(buf, "<{}>", lifetime_params.chain(type_params).format(", "))
-    }
-
-    format_to!(buf, " {{\n{}\n}}\n", code);
-
-    buf
-}
-
-// Uses a syntax-driven approach to find any impl blocks for the struct that
-// exist within the module/file
-//
-// Returns `None` if we've found an existing `new` fn
-//
-// FIXME: change the new fn checking to a more semantic approach when that's more
-// viable (e.g. we process proc macros, etc)
-fn find_struct_impl(ctx: &AssistContext, strukt: &ast::Struct) -> Option<Option<ast::Impl>> {
-    let db = ctx.db();
-    let module = strukt.syntax().ancestors().find(|node| {
-        ast::Module::can_cast(node.kind()) || ast::SourceFile::can_cast(node.kind())
-    })?;
-
-    let struct_def = ctx.sema.to_def(strukt)?;
-
-    let block = module.descendants().filter_map(ast::Impl::cast).find_map(|impl_blk| {
-        let blk = ctx.sema.to_def(&impl_blk)?;
-
-        // FIXME: handle e.g. `struct S<T>; impl<U> S<U> {}`
-        // (we currently use the wrong type parameter)
-        // also we wouldn't want to use e.g. `impl S<u32>`
-        let same_ty = match blk.target_ty(db).as_adt() {
-            Some(def) => def == Adt::Struct(struct_def),
-            None => false,
-        };
-        let not_trait_impl = blk.target_trait(db).is_none();
-
-        if !(same_ty && not_trait_impl) {
-            None
-        } else {
-            Some(impl_blk)
-        }
-    });
-
-    if let Some(ref impl_blk) = block {
-        if has_new_fn(impl_blk) {
-            return None;
-        }
-    }
-
-    Some(block)
-}
-
-fn has_new_fn(imp: &ast::Impl) -> bool {
-    if let Some(il) = imp.assoc_item_list() {
-        for item in il.assoc_items() {
-            if let ast::AssocItem::Fn(f) = item {
-                if let Some(name) = f.name() {
-                    if name.text().eq_ignore_ascii_case("new") {
-                        return true;
-                    }
-                }
-            }
-        }
-    }
-
-    false
-}
-
 #[cfg(test)]
 mod tests {
     use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
@@ -322,6 +249,44 @@ impl Foo {
         );
     }
 
+    #[test]
+    fn test_generate_new_tuple_struct() {
+        check_assist(
+            generate_new,
+            "pub struct Foo(String, Vec<i32>)<|>;",
+            "pub struct Foo(String, Vec<i32>);
+
+impl Foo {
+    pub fn $0new(field0: String, field1: Vec<i32>) -> Self { Self(field0, field1) }
+}
+",
+        );
+        check_assist(
+            generate_new,
+            "
+struct Foo<T>(T<|>);
+
+impl<T> Foo<T> {
+    fn get(&self) -> &T { &self.0 }
+}
+",
+            "
+struct Foo<T>(T);
+
+impl<T> Foo<T> {
+    fn $0new(field0: T) -> Self { Self(field0) }
+
+    fn get(&self) -> &T { &self.0 }
+}
+",
+        );
+    }
+
+    #[test]
+    fn generate_new_not_applicable_for_unit_struct() {
+        check_assist_not_applicable(generate_new, "struct Foo<|>;");
+    }
+
     #[test]
     fn generate_new_not_applicable_if_fn_exists() {
         check_assist_not_applicable(
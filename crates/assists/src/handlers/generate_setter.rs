@@ -0,0 +1,174 @@
+use syntax::{
+    ast::{self, AstNode},
+    TextSize,
+};
+
+use crate::{
+    utils::{find_field_at_offset, find_struct_impl, generate_impl_text, getter_name},
+    AssistContext, AssistId, AssistKind, Assists,
+};
+
+// Assist: generate_setter
+//
+// Generate a setter method for a field.
+//
+// ```
+// struct Person {
+//     nam<|>e: String,
+// }
+// ```
+// ->
+// ```
+// struct Person {
+//     name: String,
+// }
+//
+// impl Person {
+//     pub fn $0set_name(&mut self, name: String) {
+//         self.name = name;
+//     }
+// }
+//
+// ```
+pub(crate) fn generate_setter(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let strukt = ctx.find_node_at_offset::<ast::Struct>()?;
+    let (_, field_name, field_ty, target) = find_field_at_offset(ctx)?;
+
+    // Return early if we've found an existing fn
+    let param_name = getter_name(&field_name);
+    let fn_name = format!("set_{}", param_name);
+    let impl_def = find_struct_impl(&ctx, &strukt, &fn_name)?;
+
+    acc.add(
+        AssistId("generate_setter", AssistKind::Generate),
+        "Generate setter",
+        target,
+        |builder| {
+            let mut buf = format!(
+                "    pub fn {}(&mut self, {}: {}) {{\n        self.{} = {};\n    }}",
+                fn_name, param_name, field_ty, field_name, param_name
+            );
+
+            let start_offset = match impl_def {
+                Some(impl_def) => {
+                    buf = format!("\n{}\n", buf);
+                    impl_def.syntax().text_range().end() - TextSize::of('}')
+                }
+                None => {
+                    buf = generate_impl_text(&strukt, &buf);
+                    strukt.syntax().text_range().end()
+                }
+            };
+
+            match ctx.config.snippet_cap {
+                None => builder.insert(start_offset, buf),
+                Some(cap) => {
+                    buf = buf.replace(&format!("fn {}(", fn_name), &format!("fn $0{}(", fn_name));
+                    builder.insert_snippet(cap, start_offset, buf);
+                }
+            }
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn test_generate_setter_with_new_impl() {
+        check_assist(
+            generate_setter,
+            r#"
+struct Person<T: Clone> {
+    data<|>: T,
+}
+"#,
+            r#"
+struct Person<T: Clone> {
+    data: T,
+}
+
+impl<T: Clone> Person<T> {
+    pub fn $0set_data(&mut self, data: T) {
+        self.data = data;
+    }
+}
+
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_setter_with_existing_impl() {
+        check_assist(
+            generate_setter,
+            r#"
+struct Person {
+    name<|>: String,
+}
+
+impl Person {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+}
+"#,
+            r#"
+struct Person {
+    name: String,
+}
+
+impl Person {
+    pub fn name(&self) -> &String {
+        &self.name
+    }
+
+    pub fn $0set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_setter_not_applicable_if_fn_exists() {
+        check_assist_not_applicable(
+            generate_setter,
+            r#"
+struct Person {
+    name<|>: String,
+}
+
+impl Person {
+    fn set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_generate_setter_for_tuple_field() {
+        check_assist(
+            generate_setter,
+            r#"
+struct Wrapper(String<|>);
+"#,
+            r#"
+struct Wrapper(String);
+
+impl Wrapper {
+    pub fn $0set_field_0(&mut self, field_0: String) {
+        self.0 = field_0;
+    }
+}
+
+"#,
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use syntax::{
-    ast::{edit::AstNodeEdit, make, AstNode, BlockExpr, Expr, IfExpr, MatchArm},
+    ast::{edit::AstNodeEdit, make, AstNode, BlockExpr, ElseBranch, Expr, IfExpr, MatchArm, Pat},
     SyntaxKind::WHITESPACE,
 };
 
@@ -7,7 +7,9 @@ use crate::{AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: move_guard_to_arm_body
 //
-// Moves match guard into match arm body.
+// Moves match guard into match arm body. Values rejected by the guard would fall through to
+// the next arm, so this is only available when that arm is a catch-all `_`, whose body becomes
+// the `else` branch.
 //
 // ```
 // enum Action { Move { distance: u32 }, Stop }
@@ -39,10 +41,11 @@ pub(crate) fn move_guard_to_arm_body(acc: &mut Assists, ctx: &AssistContext) ->
 
     let guard_condition = guard.expr()?;
     let arm_expr = match_arm.expr()?;
+    let else_branch = fallthrough_branch(&match_arm)?;
     let if_expr = make::expr_if(
         make::condition(guard_condition, None),
         make::block_expr(None, Some(arm_expr.clone())),
-        None,
+        else_branch,
     )
     .indent(arm_expr.indent_level());
 
@@ -65,6 +68,26 @@ pub(crate) fn move_guard_to_arm_body(acc: &mut Assists, ctx: &AssistContext) ->
     )
 }
 
+/// Returns the `else` branch handling values rejected by the guard of `match_arm`. This is
+/// `Some(None)` if the catch-all arm does nothing, and `None` if the next arm isn't a catch-all.
+fn fallthrough_branch(match_arm: &MatchArm) -> Option<Option<ElseBranch>> {
+    let next_arm = match_arm.syntax().next_sibling().and_then(MatchArm::cast)?;
+    if next_arm.guard().is_some() || !matches!(next_arm.pat()?, Pat::WildcardPat(_)) {
+        return None;
+    }
+    let block = match next_arm.expr()? {
+        Expr::TupleExpr(it) if it.fields().next().is_none() => return Some(None),
+        Expr::BlockExpr(it) => {
+            if it.statements().next().is_none() && it.expr().is_none() {
+                return Some(None);
+            }
+            it.reset_indent()
+        }
+        it => make::block_expr(None, Some(it)),
+    };
+    Some(Some(ElseBranch::Block(block)))
+}
+
 // Assist: move_arm_cond_to_match_guard
 //
 // Moves if expression from match arm body into a guard.
@@ -184,6 +207,8 @@ fn main() {
     match 92 {
         x => if x > 10 {
             false
+        } else {
+            true
         },
         _ => true
     }
@@ -209,6 +234,8 @@ fn main() {
     match 92 {
         x @ 4 | x @ 5 => if x > 5 {
             true
+        } else {
+            false
         },
         _ => false
     }
@@ -217,6 +244,56 @@ fn main() {
         );
     }
 
+    #[test]
+    fn move_guard_to_arm_body_with_block_fallthrough() {
+        check_assist(
+            move_guard_to_arm_body,
+            r#"
+fn main() {
+    match 92 {
+        x <|>if x > 10 => false,
+        _ => {
+            let y = 1;
+            y > 0
+        }
+    }
+}
+"#,
+            r#"
+fn main() {
+    match 92 {
+        x => if x > 10 {
+            false
+        } else {
+            let y = 1;
+            y > 0
+        },
+        _ => {
+            let y = 1;
+            y > 0
+        }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn move_guard_to_arm_body_not_applicable_without_catch_all() {
+        check_assist_not_applicable(
+            move_guard_to_arm_body,
+            r#"
+fn main() {
+    match 92 {
+        x <|>if x > 10 => false,
+        1 => true,
+        _ => false
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn move_arm_cond_to_match_guard_works() {
         check_assist(
@@ -0,0 +1,342 @@
+use hir::{PathResolution, ScopeDef};
+use ide_db::defs::{Definition, NameClass};
+use syntax::{
+    algo::SyntaxRewriter,
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make, AstNode, NameOwner,
+    },
+    Direction, NodeOrToken, SyntaxKind, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists, GroupLabel};
+
+// Assist: move_to_module
+//
+// Moves a top-level item into a sibling inline module. Usages outside of the
+// module are kept working by importing the item in its old place.
+//
+// ```
+// mod shapes {}
+//
+// fn <|>area(w: u32, h: u32) -> u32 { w * h }
+//
+// fn main() { area(2, 3); }
+// ```
+// ->
+// ```
+// mod shapes {
+//     pub(crate) fn area(w: u32, h: u32) -> u32 { w * h }
+// }
+//
+// use shapes::area;
+//
+// fn main() { area(2, 3); }
+// ```
+pub(crate) fn move_to_module(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let item = ctx.find_node_at_offset::<ast::Item>()?;
+    match item {
+        ast::Item::Const(_)
+        | ast::Item::Enum(_)
+        | ast::Item::Fn(_)
+        | ast::Item::Static(_)
+        | ast::Item::Struct(_)
+        | ast::Item::Trait(_)
+        | ast::Item::TypeAlias(_)
+        | ast::Item::Union(_) => {}
+        _ => return None,
+    }
+    let parent = item.syntax().parent()?;
+    if !matches!(parent.kind(), SyntaxKind::SOURCE_FILE | SyntaxKind::ITEM_LIST) {
+        return None;
+    }
+    let name = item.syntax().children().find_map(ast::Name::cast)?;
+    if ctx.offset() > name.syntax().text_range().end() {
+        return None;
+    }
+    let def = NameClass::classify(&ctx.sema, &name)?.defined(ctx.db())?;
+    let usages = def.usages(&ctx.sema).all();
+    let qualified_item = qualify_parent_paths(ctx, &item, def)?;
+
+    let group = GroupLabel("Move to module...".to_string());
+    let item_range = item.syntax().text_range();
+    for module in parent.children().filter_map(ast::Module::cast) {
+        let item_list = match module.item_list() {
+            Some(it) => it,
+            None => continue,
+        };
+        let module_name = match module.name() {
+            Some(it) => it,
+            None => continue,
+        };
+        let module_range = module.syntax().text_range();
+        let visibility = item.syntax().children().find_map(ast::Visibility::cast);
+        // Usages inside the module and inside the item itself keep resolving after the move.
+        // Items with a visibility may be used from other crates, so their path is kept as well.
+        let needs_import = visibility.is_some()
+            || usages.iter().any(|usage| {
+                usage.file_range.file_id != ctx.frange.file_id
+                    || !(module_range.contains_range(usage.file_range.range)
+                        || item_range.contains_range(usage.file_range.range))
+            });
+        let r_curly = item_list.r_curly_token()?;
+        let last = r_curly
+            .siblings_with_tokens(Direction::Prev)
+            .skip(1)
+            .find(|it| it.kind() != SyntaxKind::WHITESPACE)?;
+        let module_indent = IndentLevel::from_node(module.syntax());
+        let indent = module_indent + 1;
+
+        acc.add_group(
+            &group,
+            AssistId("move_to_module", AssistKind::RefactorRewrite),
+            format!("Move to module `{}`", module_name),
+            item_range,
+            |builder| {
+                let moved = qualified_item.reset_indent().indent(indent);
+                let mut text = indent.to_string();
+                let mut needs_visibility = visibility.is_none() && needs_import;
+                for element in moved.syntax().children_with_tokens() {
+                    if needs_visibility
+                        && !matches!(
+                            element.kind(),
+                            SyntaxKind::ATTR | SyntaxKind::COMMENT | SyntaxKind::WHITESPACE
+                        )
+                    {
+                        text.push_str("pub(crate) ");
+                        needs_visibility = false;
+                    }
+                    text.push_str(&element.to_string());
+                }
+
+                if last.kind() == T!['{'] {
+                    let range =
+                        TextRange::new(last.text_range().end(), r_curly.text_range().start());
+                    builder.replace(range, format!("\n{}\n{}", text, module_indent));
+                } else {
+                    builder.insert(last.text_range().end(), format!("\n\n{}", text));
+                }
+
+                if needs_import {
+                    let vis = visibility.as_ref().map(|it| format!("{} ", it)).unwrap_or_default();
+                    builder.replace(item_range, format!("{}use {}::{};", vis, module_name, name));
+                } else {
+                    let range = match item.syntax().prev_sibling_or_token() {
+                        Some(NodeOrToken::Token(ws)) if ws.kind() == SyntaxKind::WHITESPACE => {
+                            TextRange::new(ws.text_range().start(), item_range.end())
+                        }
+                        _ => item_range,
+                    };
+                    builder.delete(range);
+                }
+            },
+        );
+    }
+    Some(())
+}
+
+/// Paths in `item` which refer to items of its current module only resolve from
+/// inside the target module when they go through `super`.
+fn qualify_parent_paths(
+    ctx: &AssistContext,
+    item: &ast::Item,
+    def: Definition,
+) -> Option<ast::Item> {
+    let parent_scope = ctx.sema.scope(item.syntax()).module()?.scope(ctx.db(), None);
+    let super_path = || make::path_unqualified(make::path_segment_super());
+    let mut rewriter = SyntaxRewriter::default();
+    for path in item.syntax().descendants().filter_map(ast::Path::cast) {
+        if path.qualifier().is_some() {
+            continue;
+        }
+        let segment = match path.segment() {
+            Some(it) => it,
+            None => continue,
+        };
+        // `self` and `super` on their own are expressions, not module paths.
+        let is_qualifier = path.syntax().parent().and_then(ast::Path::cast).is_some();
+        let replacement = match segment.kind() {
+            Some(ast::PathSegmentKind::SelfKw) if is_qualifier => super_path(),
+            Some(ast::PathSegmentKind::SuperKw) if is_qualifier => {
+                make::path_qualified(super_path(), segment)
+            }
+            Some(ast::PathSegmentKind::Name(name_ref)) => {
+                let resolved = match ctx.sema.resolve_path(&path) {
+                    Some(PathResolution::Def(it)) if Definition::ModuleDef(it) != def => {
+                        ScopeDef::ModuleDef(it)
+                    }
+                    Some(PathResolution::Macro(it)) => ScopeDef::MacroDef(it),
+                    _ => continue,
+                };
+                let in_parent_scope = parent_scope.iter().any(|(name, it)| {
+                    *it == resolved && name.to_string() == name_ref.text().as_str()
+                });
+                if !in_parent_scope {
+                    continue;
+                }
+                make::path_qualified(super_path(), segment)
+            }
+            _ => continue,
+        };
+        rewriter.replace_ast(&path, &replacement);
+    }
+    // Patterns like `None` are bindings unless they resolve to a constant or a variant.
+    for ident_pat in item.syntax().descendants().filter_map(ast::IdentPat::cast) {
+        let name = match ident_pat.name() {
+            Some(it) => it,
+            None => continue,
+        };
+        let resolved = match ctx.sema.resolve_bind_pat_to_const(&ident_pat) {
+            Some(it) => ScopeDef::ModuleDef(it),
+            None => continue,
+        };
+        if parent_scope
+            .iter()
+            .any(|(it, def)| *def == resolved && it.to_string() == name.text().as_str())
+        {
+            let segment = make::path_segment(make::name_ref(name.text()));
+            let pat: ast::Pat = ident_pat.clone().into();
+            rewriter
+                .replace_ast(&pat, &make::path_pat(make::path_qualified(super_path(), segment)));
+        }
+    }
+    Some(rewriter.rewrite_ast(item))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn moves_function_into_existing_module() {
+        check_assist(
+            move_to_module,
+            r#"
+mod utils {
+    pub fn helper() {}
+}
+
+fn <|>double(x: i32) -> i32 {
+    x * 2
+}
+
+fn main() {
+    double(2);
+}
+"#,
+            r#"
+mod utils {
+    pub fn helper() {}
+
+    pub(crate) fn double(x: i32) -> i32 {
+        x * 2
+    }
+}
+
+use utils::double;
+
+fn main() {
+    double(2);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn moves_unused_item_without_import() {
+        check_assist(
+            move_to_module,
+            r#"
+mod consts {}
+
+const <|>LIMIT: u32 = 10;
+
+fn main() {}
+"#,
+            r#"
+mod consts {
+    const LIMIT: u32 = 10;
+}
+
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_public_visibility() {
+        check_assist(
+            move_to_module,
+            r#"
+pub mod shapes {
+    pub struct Square;
+}
+
+/// A circle.
+pub struct <|>Circle;
+"#,
+            r#"
+pub mod shapes {
+    pub struct Square;
+
+    /// A circle.
+    pub struct Circle;
+}
+
+pub use shapes::Circle;
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_without_inline_module() {
+        check_assist_not_applicable(
+            move_to_module,
+            r#"
+mod other;
+
+fn <|>foo() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn qualifies_paths_to_parent_items() {
+        check_assist(
+            move_to_module,
+            r#"
+mod geometry {}
+
+struct Point { x: u32 }
+enum Axis { X, Y }
+use self::Axis::*;
+
+fn <|>project(p: Point, axis: Axis) -> Point {
+    let x = match axis {
+        X => p.x,
+        Y => 0,
+    };
+    if x > 0 { project(Point { x }, self::Axis::Y) } else { Point { x } }
+}
+"#,
+            r#"
+mod geometry {
+    fn project(p: super::Point, axis: super::Axis) -> super::Point {
+        let x = match axis {
+            super::X => p.x,
+            super::Y => 0,
+        };
+        if x > 0 { project(super::Point { x }, super::Axis::Y) } else { super::Point { x } }
+    }
+}
+
+struct Point { x: u32 }
+enum Axis { X, Y }
+use self::Axis::*;
+"#,
+        );
+    }
+}
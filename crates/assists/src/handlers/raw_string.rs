@@ -7,17 +7,17 @@ use crate::{AssistContext, AssistId, AssistKind, Assists};
 
 // Assist: make_raw_string
 //
-// Adds `r#` to a plain string literal.
+// Adds `r` to a plain string literal, with as many `#`s as the contents need.
 //
 // ```
 // fn main() {
-//     "Hello,<|> World!";
+//     "Hello,<|> \"World!\"";
 // }
 // ```
 // ->
 // ```
 // fn main() {
-//     r#"Hello, World!"#;
+//     r#"Hello, "World!""#;
 // }
 // ```
 pub(crate) fn make_raw_string(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
@@ -32,7 +32,7 @@ pub(crate) fn make_raw_string(acc: &mut Assists, ctx: &AssistContext) -> Option<
         "Rewrite as raw string",
         target,
         |edit| {
-            let hashes = "#".repeat(required_hashes(&value).max(1));
+            let hashes = "#".repeat(required_hashes(&value));
             if matches!(value, Cow::Borrowed(_)) {
                 // Avoid replacing the whole string to better position the cursor.
                 edit.insert(token.syntax().text_range().start(), format!("r{}", hashes));
@@ -210,12 +210,12 @@ fn f() {
     let s = <|>"random\nstring";
 }
 "#,
-            r##"
+            r#"
 fn f() {
-    let s = r#"random
-string"#;
+    let s = r"random
+string";
 }
-"##,
+"#,
         )
     }
 
@@ -228,11 +228,11 @@ string"#;
                 format!(<|>"x = {}", 92)
             }
             "#,
-            r##"
+            r#"
             fn f() {
-                format!(r#"x = {}"#, 92)
+                format!(r"x = {}", 92)
             }
-            "##,
+            "#,
         )
     }
 
@@ -245,12 +245,12 @@ fn f() {
     let s = <|>"#random##\nstring";
 }
 "###,
-            r####"
+            r###"
 fn f() {
-    let s = r#"#random##
-string"#;
+    let s = r"#random##
+string";
 }
-"####,
+"###,
         )
     }
 
@@ -281,11 +281,29 @@ string"###;
                 let s = <|>"random string";
             }
             "#,
-            r##"
+            r#"
             fn f() {
-                let s = r#"random string"#;
+                let s = r"random string";
             }
-            "##,
+            "#,
+        )
+    }
+
+    #[test]
+    fn make_raw_string_backslash_works() {
+        check_assist(
+            make_raw_string,
+            r#"fn f() { let s = <|>"a\\b"; }"#,
+            r#"fn f() { let s = r"a\b"; }"#,
+        )
+    }
+
+    #[test]
+    fn make_raw_string_with_quote_works() {
+        check_assist(
+            make_raw_string,
+            r#"fn f() { let s = <|>"say \"hi\"\\n"; }"#,
+            r##"fn f() { let s = r#"say "hi"\n"#; }"##,
         )
     }
 
@@ -481,6 +499,15 @@ string"###;
         )
     }
 
+    #[test]
+    fn make_usual_string_backslash_works() {
+        check_assist(
+            make_usual_string,
+            r#"fn f() { let s = <|>r"a\b"; }"#,
+            r#"fn f() { let s = "a\\b"; }"#,
+        )
+    }
+
     #[test]
     fn make_usual_string_more_hash_works() {
         check_assist(
@@ -3,7 +3,9 @@ use rustc_hash::FxHashMap;
 
 use hir::{Adt, ModuleDef, PathResolution, Semantics, Struct};
 use ide_db::RootDatabase;
-use syntax::{algo, ast, match_ast, AstNode, SyntaxKind, SyntaxKind::*, SyntaxNode};
+use syntax::{
+    ast, match_ast, AstNode, SyntaxElement, SyntaxKind, SyntaxKind::*, SyntaxNode, TextRange,
+};
 use test_utils::mark;
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
@@ -46,18 +48,48 @@ fn reorder<R: AstNode>(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
     let target = record.syntax().text_range();
     acc.add(
         AssistId("reorder_fields", AssistKind::RefactorRewrite),
-        "Reorder record fields",
+        "Reorder fields to match declaration",
         target,
         |edit| {
-            let mut rewriter = algo::SyntaxRewriter::default();
             for (old, new) in fields.iter().zip(&sorted_fields) {
-                rewriter.replace(old, new);
+                if old != new {
+                    let (range, _) = field_with_comments(old);
+                    let (_, text) = field_with_comments(new);
+                    edit.replace(range, text);
+                }
             }
-            edit.rewrite(rewriter);
         },
     )
 }
 
+/// Returns the range and the text of the field together with the comments
+/// on the lines right above it, so that they move along with the field.
+/// Comments trailing a previous field on its line stay where they are.
+fn field_with_comments(field: &SyntaxNode) -> (TextRange, String) {
+    let mut elements = vec![SyntaxElement::from(field.clone())];
+    let mut prev = field.prev_sibling_or_token();
+    while let Some(element) = prev {
+        match element.kind() {
+            COMMENT if starts_line(&element) => elements.push(element.clone()),
+            WHITESPACE if !element.to_string().contains("\n\n") => elements.push(element.clone()),
+            _ => break,
+        }
+        prev = element.prev_sibling_or_token();
+    }
+    while elements.last().map_or(false, |it| it.kind() == WHITESPACE) {
+        elements.pop();
+    }
+    let start = elements.last().map_or(field.text_range().start(), |it| it.text_range().start());
+    let text = elements.iter().rev().map(|it| it.to_string()).collect();
+    (TextRange::new(start, field.text_range().end()), text)
+}
+
+fn starts_line(element: &SyntaxElement) -> bool {
+    element
+        .prev_sibling_or_token()
+        .map_or(false, |it| it.kind() == WHITESPACE && it.to_string().contains('\n'))
+}
+
 fn get_fields_kind(node: &SyntaxNode) -> Vec<SyntaxKind> {
     match node.kind() {
         RECORD_EXPR => vec![RECORD_EXPR_FIELD],
@@ -221,6 +253,37 @@ impl Foo {
         }
     }
 }
+"#,
+        )
+    }
+
+    #[test]
+    fn reorder_fields_with_comments() {
+        check_assist(
+            reorder_fields,
+            r#"
+struct Foo { foo: i32, bar: i32 }
+
+fn f() -> Foo {
+    <|>Foo {
+        // The bar.
+        bar: 0,
+        // The foo.
+        foo: 1, // trailing
+    }
+}
+"#,
+            r#"
+struct Foo { foo: i32, bar: i32 }
+
+fn f() -> Foo {
+    Foo {
+        // The foo.
+        foo: 1,
+        // The bar.
+        bar: 0, // trailing
+    }
+}
 "#,
         )
     }
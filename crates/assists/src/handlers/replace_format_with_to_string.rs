@@ -0,0 +1,164 @@
+use syntax::{
+    ast::{self, AstNode},
+    NodeOrToken, SyntaxKind, TextRange, TextSize, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: replace_format_with_to_string
+//
+// Replaces `format!("{}", x)` with `x.to_string()`.
+//
+// ```
+// fn main() {
+//     let x = 92;
+//     let s = <|>format!("{}", x);
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let x = 92;
+//     let s = x.to_string();
+// }
+// ```
+pub(crate) fn replace_format_with_to_string(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let macro_call = ctx.find_node_at_offset::<ast::MacroCall>()?;
+    if macro_call.path()?.segment()?.name_ref()?.text() != "format" {
+        return None;
+    }
+    let arg = single_display_arg(&macro_call.token_tree()?)?;
+    let receiver = match arg {
+        ast::Expr::BinExpr(_)
+        | ast::Expr::CastExpr(_)
+        | ast::Expr::ClosureExpr(_)
+        | ast::Expr::PrefixExpr(_)
+        | ast::Expr::RangeExpr(_)
+        | ast::Expr::RefExpr(_) => format!("({})", arg),
+        _ => arg.to_string(),
+    };
+
+    let macro_range = macro_call.syntax().text_range();
+    let macro_end = if macro_call.semicolon_token().is_some() {
+        macro_range.end() - TextSize::of(';')
+    } else {
+        macro_range.end()
+    };
+    acc.add(
+        AssistId("replace_format_with_to_string", AssistKind::RefactorRewrite),
+        "Use to_string()",
+        macro_range,
+        |builder| {
+            builder.replace(
+                TextRange::new(macro_range.start(), macro_end),
+                format!("{}.to_string()", receiver),
+            );
+        },
+    )
+}
+
+/// Returns the argument of `("{}", arg)`, if that's all the token tree contains.
+fn single_display_arg(token_tree: &ast::TokenTree) -> Option<ast::Expr> {
+    let mut elements =
+        token_tree.syntax().children_with_tokens().filter(|it| !it.kind().is_trivia());
+    if elements.next()?.kind() != T!['('] {
+        return None;
+    }
+    match elements.next()? {
+        NodeOrToken::Token(it) if it.kind() == SyntaxKind::STRING && it.text() == "\"{}\"" => {}
+        _ => return None,
+    }
+    if elements.next()?.kind() != T![,] {
+        return None;
+    }
+
+    let mut rest: Vec<_> = elements.collect();
+    if rest.pop()?.kind() != T![')'] {
+        return None;
+    }
+    if rest.last().map(|it| it.kind()) == Some(T![,]) {
+        rest.pop();
+    }
+    // A comma at this level separates another argument.
+    if rest.is_empty() || rest.iter().any(|it| it.kind() == T![,]) {
+        return None;
+    }
+    let start = rest.first()?.text_range().start();
+    let end = rest.last()?.text_range().end();
+    let tt_start = token_tree.syntax().text_range().start();
+    let text = token_tree.syntax().text().slice(TextRange::new(start, end) - tt_start);
+    ast::Expr::parse(&text.to_string()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn replaces_format_of_single_variable() {
+        check_assist(
+            replace_format_with_to_string,
+            r#"
+fn foo(x: i32) -> String {
+    <|>format!("{}", x)
+}
+"#,
+            r#"
+fn foo(x: i32) -> String {
+    x.to_string()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn parenthesizes_operators() {
+        check_assist(
+            replace_format_with_to_string,
+            r#"
+fn foo(x: i32) {
+    <|>format!("{}", x + 1);
+}
+"#,
+            r#"
+fn foo(x: i32) {
+    (x + 1).to_string();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_multiple_args() {
+        check_assist_not_applicable(
+            replace_format_with_to_string,
+            r#"
+fn foo(x: i32, y: i32) -> String {
+    <|>format!("{}", x, y)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_other_format_strings() {
+        check_assist_not_applicable(
+            replace_format_with_to_string,
+            r#"
+fn foo(x: i32) -> String {
+    <|>format!("{}, {}", x, x)
+}
+"#,
+        );
+        check_assist_not_applicable(
+            replace_format_with_to_string,
+            r#"
+fn foo(x: i32) -> String {
+    <|>format!("{:?}", x)
+}
+"#,
+        );
+    }
+}
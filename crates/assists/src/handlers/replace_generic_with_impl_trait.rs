@@ -0,0 +1,160 @@
+use syntax::{
+    ast::{self, AstNode, NameOwner, TypeBoundsOwner},
+    Direction, SyntaxKind, TextRange, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: replace_generic_with_impl_trait
+//
+// Replaces a generic parameter which is only used as the type of one argument with `impl Trait`.
+//
+// ```
+// fn foo<<|>B: Bar>(bar: B) {}
+// ```
+// ->
+// ```
+// fn foo(bar: impl Bar) {}
+// ```
+pub(crate) fn replace_generic_with_impl_trait(
+    acc: &mut Assists,
+    ctx: &AssistContext,
+) -> Option<()> {
+    let type_param = ctx.find_node_at_offset::<ast::TypeParam>()?;
+    let param_list = type_param.syntax().parent().and_then(ast::GenericParamList::cast)?;
+    let fn_ = param_list.syntax().parent().and_then(ast::Fn::cast)?;
+    if type_param.default_type().is_some() {
+        return None;
+    }
+    let name = type_param.name()?;
+    let bounds = type_param.type_bound_list()?;
+
+    // `T` must be mentioned exactly once, as the whole type of an argument: in
+    // `where` clauses, return types, other bounds or the body it can't be replaced.
+    let mut usages = fn_
+        .syntax()
+        .descendants()
+        .filter_map(ast::NameRef::cast)
+        .filter(|it| it.text() == name.text());
+    let usage = usages.next()?;
+    if usages.next().is_some() {
+        return None;
+    }
+    let path_type = usage
+        .syntax()
+        .ancestors()
+        .find_map(ast::PathType::cast)
+        .filter(|it| it.path().map_or(false, |path| path.qualifier().is_none()))?;
+    let param = path_type.syntax().parent().and_then(ast::Param::cast)?;
+    if param.syntax().parent().and_then(ast::ParamList::cast)? != fn_.param_list()? {
+        return None;
+    }
+
+    let param_range = if param_list.generic_params().count() == 1 {
+        param_list.syntax().text_range()
+    } else {
+        range_with_separator(&type_param)
+    };
+    acc.add(
+        AssistId("replace_generic_with_impl_trait", AssistKind::RefactorRewrite),
+        "Convert to impl Trait",
+        type_param.syntax().text_range(),
+        |builder| {
+            builder.delete(param_range);
+            builder.replace(path_type.syntax().text_range(), format!("impl {}", bounds));
+        },
+    )
+}
+
+/// Extends the range of `type_param` to cover the comma separating it from its neighbour.
+fn range_with_separator(type_param: &ast::TypeParam) -> TextRange {
+    let range = type_param.syntax().text_range();
+    let mut after = type_param.syntax().siblings_with_tokens(Direction::Next).skip(1);
+    if let Some(comma) = after.by_ref().find(|it| it.kind() != SyntaxKind::WHITESPACE) {
+        if comma.kind() == T![,] {
+            let end = match after.next() {
+                Some(ws) if ws.kind() == SyntaxKind::WHITESPACE => ws.text_range().end(),
+                _ => comma.text_range().end(),
+            };
+            return TextRange::new(range.start(), end);
+        }
+    }
+    let comma = type_param
+        .syntax()
+        .siblings_with_tokens(Direction::Prev)
+        .skip(1)
+        .find(|it| it.kind() != SyntaxKind::WHITESPACE)
+        .filter(|it| it.kind() == T![,]);
+    match comma {
+        Some(comma) => TextRange::new(comma.text_range().start(), range.end()),
+        None => range,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn replaces_only_generic_param() {
+        check_assist(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<<|>T: Clone + Copy>(x: T, y: u32) {}
+"#,
+            r#"
+fn foo(x: impl Clone + Copy, y: u32) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn keeps_other_generic_params() {
+        check_assist(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<'a, <|>T: Clone, U>(x: T, y: &'a U) {}
+"#,
+            r#"
+fn foo<'a, U>(x: impl Clone, y: &'a U) {}
+"#,
+        );
+        check_assist(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<U, <|>T: Clone>(x: T, y: U) {}
+"#,
+            r#"
+fn foo<U>(x: impl Clone, y: U) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_used_elsewhere() {
+        check_assist_not_applicable(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<<|>T: Clone>(x: T) -> T { x }
+"#,
+        );
+        check_assist_not_applicable(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<<|>T: Default>(x: T) { bar::<T>(); }
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_nested_in_argument_type() {
+        check_assist_not_applicable(
+            replace_generic_with_impl_trait,
+            r#"
+fn foo<<|>T: Clone>(x: Vec<T>) {}
+"#,
+        );
+    }
+}
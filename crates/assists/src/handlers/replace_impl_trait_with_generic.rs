@@ -1,4 +1,5 @@
-use syntax::ast::{self, edit::AstNodeEdit, make, AstNode, GenericParamsOwner};
+use rustc_hash::FxHashSet;
+use syntax::ast::{self, edit::AstNodeEdit, make, AstNode, GenericParamsOwner, NameOwner};
 
 use crate::{AssistContext, AssistId, AssistKind, Assists};
 
@@ -26,10 +27,10 @@ pub(crate) fn replace_impl_trait_with_generic(
     let target = type_fn.syntax().text_range();
     acc.add(
         AssistId("replace_impl_trait_with_generic", AssistKind::RefactorRewrite),
-        "Replace impl trait with generic",
+        "Convert to generic parameter",
         target,
         |edit| {
-            let generic_letter = impl_trait_ty.to_string().chars().next().unwrap().to_string();
+            let generic_letter = fresh_type_param_name(&type_fn, &impl_trait_ty);
 
             let generic_param_list = type_fn
                 .generic_param_list()
@@ -45,6 +46,34 @@ pub(crate) fn replace_impl_trait_with_generic(
     )
 }
 
+/// Picks a single-letter name for the new type parameter, starting with the first letter of
+/// the bound, which doesn't clash with the names already used in the function's signature.
+fn fresh_type_param_name(type_fn: &ast::Fn, bounds: &ast::TypeBoundList) -> String {
+    let mut taken: FxHashSet<String> = type_fn
+        .generic_param_list()
+        .into_iter()
+        .flat_map(|it| it.type_params())
+        .filter_map(|it| it.name())
+        .map(|it| it.text().to_string())
+        .collect();
+    let signature = type_fn.syntax().children().filter(|it| !ast::BlockExpr::can_cast(it.kind()));
+    taken.extend(
+        signature
+            .flat_map(|it| it.descendants())
+            .filter_map(ast::NameRef::cast)
+            .map(|it| it.text().to_string()),
+    );
+
+    let first = bounds
+        .to_string()
+        .chars()
+        .find(|it| it.is_ascii_alphabetic())
+        .map_or(b'T', |it| it.to_ascii_uppercase() as u8);
+    let letters = (first..=b'Z').chain(b'A'..first).map(|it| (it as char).to_string());
+    let mut candidates = letters.chain((1..).map(|n| format!("{}{}", first as char, n)));
+    candidates.find(|it| !taken.contains(it)).unwrap()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -119,7 +148,6 @@ mod tests {
     }
 
     #[test]
-    #[ignore = "This case is very rare but there is no simple solutions to fix it."]
     fn replace_impl_trait_with_exist_generic_letter() {
         check_assist(
             replace_impl_trait_with_generic,
@@ -153,6 +181,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn replace_impl_trait_avoids_types_in_signature() {
+        check_assist(
+            replace_impl_trait_with_generic,
+            r#"
+            struct I;
+            fn foo(i: I, iter: <|>impl Iterator) {}
+            "#,
+            r#"
+            struct I;
+            fn foo<J: Iterator>(i: I, iter: J) {}
+            "#,
+        );
+    }
+
+    #[test]
+    fn replace_impl_iterator() {
+        check_assist(
+            replace_impl_trait_with_generic,
+            r#"
+            fn f(x: <|>impl Iterator) {}
+            "#,
+            r#"
+            fn f<I: Iterator>(x: I) {}
+            "#,
+        );
+    }
+
     #[test]
     fn replace_impl_trait_multiple() {
         check_assist(
@@ -0,0 +1,187 @@
+use std::iter;
+
+use ide_db::ty_filter::TryEnum;
+use syntax::{
+    ast::{
+        self,
+        edit::{AstNodeEdit, IndentLevel},
+        make,
+    },
+    AstNode, T,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: replace_try_expr_with_match
+//
+// Replaces the `?` operator with an explicit `match` expression.
+//
+// ```
+// # enum Result<T, E> { Ok(T), Err(E) }
+// fn foo() -> Result<i32, ()> {
+//     let x: Result<i32, ()> = Result::Ok(92);
+//     let y = x<|>?;
+//     Result::Ok(y)
+// }
+// ```
+// ->
+// ```
+// # enum Result<T, E> { Ok(T), Err(E) }
+// fn foo() -> Result<i32, ()> {
+//     let x: Result<i32, ()> = Result::Ok(92);
+//     let y = match x {
+//         Ok(v) => v,
+//         Err(e) => return Err(e.into()),
+//     };
+//     Result::Ok(y)
+// }
+// ```
+pub(crate) fn replace_try_expr_with_match(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let question_mark = ctx.find_token_syntax_at_offset(T![?])?;
+    let try_expr = ast::TryExpr::cast(question_mark.parent())?;
+    let expr = try_expr.expr()?;
+    let ty = ctx.sema.type_of_expr(&expr)?;
+    let try_enum = TryEnum::from_ty(&ctx.sema, &ty)?;
+
+    let target = try_expr.syntax().text_range();
+    acc.add(
+        AssistId("replace_try_expr_with_match", AssistKind::RefactorRewrite),
+        "Replace ? with match",
+        target,
+        |builder| {
+            let happy_arm = {
+                let pat = make::tuple_struct_pat(
+                    path_from_name(try_enum.happy_case()),
+                    iter::once(make::ident_pat(make::name("v")).into()),
+                );
+                make::match_arm(iter::once(pat.into()), make::expr_path(path_from_name("v")))
+            };
+
+            let sad_arm = match try_enum {
+                TryEnum::Result => {
+                    let pat = make::tuple_struct_pat(
+                        path_from_name("Err"),
+                        iter::once(make::ident_pat(make::name("e")).into()),
+                    );
+                    let into_call = make::expr_method_call(
+                        make::expr_path(path_from_name("e")),
+                        "into",
+                        make::arg_list(iter::empty()),
+                    );
+                    let err_call = make::expr_call(
+                        make::expr_path(path_from_name("Err")),
+                        make::arg_list(iter::once(into_call)),
+                    );
+                    make::match_arm(iter::once(pat.into()), make::expr_return(Some(err_call)))
+                }
+                TryEnum::Option => {
+                    let none = make::expr_path(path_from_name("None"));
+                    make::match_arm(
+                        iter::once(make::path_pat(path_from_name("None"))),
+                        make::expr_return(Some(none)),
+                    )
+                }
+            };
+
+            let match_arm_list = make::match_arm_list(vec![happy_arm, sad_arm]);
+            let match_expr = make::expr_match(expr, match_arm_list)
+                .indent(IndentLevel::from_node(try_expr.syntax()));
+
+            builder.replace_ast(ast::Expr::TryExpr(try_expr.clone()), match_expr);
+        },
+    )
+}
+
+fn path_from_name(name: &str) -> ast::Path {
+    make::path_unqualified(make::path_segment(make::name_ref(name)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
+
+    use super::*;
+
+    #[test]
+    fn replace_result_try_expr_with_match() {
+        check_assist(
+            replace_try_expr_with_match,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    let y = x<|>?;
+    Result::Ok(y)
+}
+",
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    let y = match x {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    Result::Ok(y)
+}
+",
+        )
+    }
+
+    #[test]
+    fn replace_option_try_expr_with_match() {
+        check_assist(
+            replace_try_expr_with_match,
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = x?<|>;
+    Option::Some(y)
+}
+",
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = match x {
+        Some(v) => v,
+        None => return None,
+    };
+    Option::Some(y)
+}
+",
+        )
+    }
+
+    #[test]
+    fn replace_try_expr_with_match_not_applicable_outside_question_mark() {
+        check_assist_not_applicable(
+            replace_try_expr_with_match,
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = <|>x?;
+    Option::Some(y)
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_try_expr_with_match_target() {
+        check_assist_target(
+            replace_try_expr_with_match,
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = x<|>?;
+    Option::Some(y)
+}
+",
+            r"x?",
+        );
+    }
+}
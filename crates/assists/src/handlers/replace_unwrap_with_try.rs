@@ -0,0 +1,223 @@
+use ide_db::ty_filter::TryEnum;
+use syntax::{ast, AstNode, SyntaxKind::CLOSURE_EXPR};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: replace_unwrap_with_try
+//
+// Replaces `unwrap` or `expect` call with the `?` operator, if the enclosing
+// function returns a compatible `Result` or `Option`.
+//
+// ```
+// # enum Result<T, E> { Ok(T), Err(E) }
+// fn foo() -> Result<i32, ()> {
+//     let x: Result<i32, ()> = Result::Ok(92);
+//     let y = x.<|>unwrap();
+//     Result::Ok(y)
+// }
+// ```
+// ->
+// ```
+// # enum Result<T, E> { Ok(T), Err(E) }
+// fn foo() -> Result<i32, ()> {
+//     let x: Result<i32, ()> = Result::Ok(92);
+//     let y = x?;
+//     Result::Ok(y)
+// }
+// ```
+pub(crate) fn replace_unwrap_with_try(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let method_call: ast::MethodCallExpr = ctx.find_node_at_offset()?;
+    let name = method_call.name_ref()?;
+    if name.text() != "unwrap" && name.text() != "expect" {
+        return None;
+    }
+    let receiver = method_call.receiver()?;
+    let receiver_ty = ctx.sema.type_of_expr(&receiver)?;
+
+    // `?` inside of a closure would return from the closure, not from the function.
+    let fn_def = method_call
+        .syntax()
+        .ancestors()
+        .take_while(|it| it.kind() != CLOSURE_EXPR)
+        .find_map(ast::Fn::cast)?;
+    let ret_ty = ctx.sema.to_def(&fn_def)?.ret_type(ctx.db());
+    TryEnum::from_propagation(&ctx.sema, &receiver_ty, &ret_ty)?;
+
+    let target = method_call.syntax().text_range();
+    acc.add(
+        AssistId("replace_unwrap_with_try", AssistKind::RefactorRewrite),
+        format!("Replace {} with ?", name.text()),
+        target,
+        |builder| builder.replace(target, format!("{}?", receiver)),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable, check_assist_target};
+
+    use super::*;
+
+    #[test]
+    fn replace_result_unwrap_with_try() {
+        check_assist(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn i<T>(a: T) -> T { a }
+fn foo() -> Result<i32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    let y = i(x).<|>unwrap();
+    Result::Ok(y)
+}
+",
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn i<T>(a: T) -> T { a }
+fn foo() -> Result<i32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    let y = i(x)?;
+    Result::Ok(y)
+}
+",
+        )
+    }
+
+    #[test]
+    fn replace_option_expect_with_try() {
+        check_assist(
+            replace_unwrap_with_try,
+            r#"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = x.<|>expect("no value");
+    Option::Some(y)
+}
+"#,
+            r#"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = x?;
+    Option::Some(y)
+}
+"#,
+        )
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_chaining() {
+        check_assist(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<u32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    Result::Ok(x.<|>unwrap().count_zeroes())
+}
+",
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<u32, i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    Result::Ok(x?.count_zeroes())
+}
+",
+        )
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_not_applicable_in_unit_fn() {
+        check_assist_not_applicable(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn main() {
+    let x: Result<i32, i32> = Result::Ok(92);
+    let y = x.<|>unwrap();
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_not_applicable_for_mismatched_return_type() {
+        check_assist_not_applicable(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x: Result<i32, i32> = Result::Ok(92);
+    Option::Some(x.<|>unwrap())
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_generic_error_type() {
+        check_assist(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo<E>(x: Result<i32, E>) -> Result<i32, E> {
+    Result::Ok(x.<|>unwrap() + 1)
+}
+",
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo<E>(x: Result<i32, E>) -> Result<i32, E> {
+    Result::Ok(x? + 1)
+}
+",
+        )
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_not_applicable_for_mismatched_error_type() {
+        check_assist_not_applicable(
+            replace_unwrap_with_try,
+            r"
+enum Result<T, E> { Ok(T), Err(E) }
+struct E1;
+struct E2;
+fn foo(x: Result<i32, E1>) -> Result<i32, E2> {
+    Result::Ok(x.<|>unwrap())
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_not_applicable_in_closure() {
+        check_assist_not_applicable(
+            replace_unwrap_with_try,
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let f = || x.<|>unwrap();
+    Option::Some(f())
+}
+",
+        );
+    }
+
+    #[test]
+    fn replace_unwrap_with_try_target() {
+        check_assist_target(
+            replace_unwrap_with_try,
+            r"
+enum Option<T> { Some(T), None }
+fn foo() -> Option<i32> {
+    let x = Option::Some(92);
+    let y = x.<|>unwrap();
+    Option::Some(y)
+}
+",
+            r"x.unwrap()",
+        );
+    }
+}
@@ -0,0 +1,211 @@
+use itertools::Itertools;
+use syntax::{
+    ast::{self, edit::IndentLevel, AstNode},
+    NodeOrToken, SyntaxKind, TextRange,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: surround_with_block
+//
+// Wraps the selected statements in a block, limiting the scope of their bindings.
+//
+// ```
+// fn main() {
+//     <|>let x = 92;
+//     println!("{}", x);<|>
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     {
+//         let x = 92;
+//         println!("{}", x);
+//     }
+// }
+// ```
+pub(crate) fn surround_with_block(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let range = ctx.frange.range;
+    if range.is_empty() {
+        return None;
+    }
+    let block = ctx.covering_element().ancestors().find_map(ast::BlockExpr::cast)?;
+
+    // The selection has to consist of whole statements, and whitespace or comments around them.
+    let mut selected = Vec::new();
+    for child in block.syntax().children_with_tokens() {
+        if child.text_range().intersect(range).map_or(true, |it| it.is_empty()) {
+            continue;
+        }
+        match child {
+            NodeOrToken::Node(node) => {
+                if !range.contains_range(node.text_range()) {
+                    return None;
+                }
+                selected.push(node);
+            }
+            NodeOrToken::Token(token) => {
+                if !matches!(token.kind(), SyntaxKind::WHITESPACE | SyntaxKind::COMMENT) {
+                    return None;
+                }
+            }
+        }
+    }
+    let statements =
+        TextRange::new(selected.first()?.text_range().start(), selected.last()?.text_range().end());
+
+    let indent = IndentLevel::from_node(selected.first()?);
+    let text = block.syntax().text().slice(statements - block.syntax().text_range().start());
+    acc.add(
+        AssistId("surround_with_block", AssistKind::RefactorRewrite),
+        "Surround with { }",
+        statements,
+        |builder| {
+            let text = text.to_string();
+            let body = text
+                .lines()
+                .enumerate()
+                .map(|(i, line)| {
+                    if i == 0 || line.is_empty() {
+                        line.to_string()
+                    } else {
+                        format!("{}{}", IndentLevel(1), line)
+                    }
+                })
+                .join("\n");
+            builder.replace(statements, format!("{{\n{}{}\n{}}}", indent + 1, body, indent));
+        },
+    )
+}
+
+// Assist: surround_with_parens
+//
+// Wraps the selected expression in parentheses.
+//
+// ```
+// fn main() {
+//     let x = <|>1 * 2<|> + 3;
+// }
+// ```
+// ->
+// ```
+// fn main() {
+//     let x = (1 * 2) + 3;
+// }
+// ```
+pub(crate) fn surround_with_parens(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let range = ctx.frange.range;
+    if range.is_empty() {
+        return None;
+    }
+    let expr = ctx
+        .covering_element()
+        .ancestors()
+        .take_while(|it| it.text_range() == range)
+        .find_map(ast::Expr::cast)?;
+
+    acc.add(
+        AssistId("surround_with_parens", AssistKind::RefactorRewrite),
+        "Surround with ( )",
+        range,
+        |builder| {
+            builder.replace(range, format!("({})", expr));
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    #[test]
+    fn surrounds_statement_with_block() {
+        check_assist(
+            surround_with_block,
+            r#"
+fn foo() {
+    let a = 1;
+    <|>let b = a + 1;<|>
+    a
+}
+"#,
+            r#"
+fn foo() {
+    let a = 1;
+    {
+        let b = a + 1;
+    }
+    a
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn surrounds_selected_lines_with_block() {
+        check_assist(
+            surround_with_block,
+            r#"
+fn foo() -> i32 {
+<|>    let a = 1;
+    // the answer
+    a + 91
+<|>}
+"#,
+            r#"
+fn foo() -> i32 {
+    {
+        let a = 1;
+        // the answer
+        a + 91
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn block_not_applicable_to_partial_statements() {
+        check_assist_not_applicable(
+            surround_with_block,
+            r#"
+fn foo() {
+    let a = <|>1;
+    let b = 2;<|>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn surrounds_expression_with_parens() {
+        check_assist(
+            surround_with_parens,
+            r#"
+fn foo(a: i32, b: i32) {
+    let c = <|>a + b<|>;
+}
+"#,
+            r#"
+fn foo(a: i32, b: i32) {
+    let c = (a + b);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn parens_not_applicable_to_partial_expressions() {
+        check_assist_not_applicable(
+            surround_with_parens,
+            r#"
+fn foo(a: i32, b: i32) -> i32 {
+    a <|>+ b<|>
+}
+"#,
+        );
+    }
+}
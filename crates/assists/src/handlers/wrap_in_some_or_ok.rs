@@ -0,0 +1,278 @@
+use std::iter;
+
+use hir::{Adt, Type};
+use ide_db::{call_info::ActiveParameter, helpers::FamousDefs};
+use syntax::{
+    ast::{self, make},
+    AstNode,
+};
+
+use crate::{AssistContext, AssistId, AssistKind, Assists};
+
+// Assist: wrap_in_some_or_ok
+//
+// Wraps an expression in `Some` or `Ok` if an `Option` or `Result` of its type is expected.
+//
+// ```
+// # //- /main.rs crate:main deps:core
+// fn foo() -> Option<i32> {
+//     42<|>
+// }
+// # //- /libcore.rs crate:core
+// # pub mod option { pub enum Option<T> { None, Some(T) } }
+// # pub mod prelude { pub use crate::option::Option::{self, *}; }
+// # #[prelude_import] pub use prelude::*;
+// ```
+// ->
+// ```
+// fn foo() -> Option<i32> {
+//     Some(42)
+// }
+// ```
+pub(crate) fn wrap_in_some_or_ok(acc: &mut Assists, ctx: &AssistContext) -> Option<()> {
+    let (expr, expected_ty, is_fn_tail) = ctx
+        .find_node_at_offset::<ast::Expr>()?
+        .syntax()
+        .ancestors()
+        .filter_map(ast::Expr::cast)
+        .find_map(|expr| {
+            let (ty, is_fn_tail) = expected_type(ctx, &expr)?;
+            Some((expr, ty, is_fn_tail))
+        })?;
+
+    let famous_defs = FamousDefs(&ctx.sema, ctx.sema.scope(expr.syntax()).krate());
+    let expected_adt = expected_ty.as_adt()?;
+    let variant = if Some(expected_adt) == famous_defs.core_option_Option().map(Adt::from) {
+        "Some"
+    } else if Some(expected_adt) == famous_defs.core_result_Result().map(Adt::from) {
+        // The missing `Ok` diagnostic already offers a fix for tail expressions.
+        if is_fn_tail {
+            return None;
+        }
+        "Ok"
+    } else {
+        return None;
+    };
+    let inner_ty = expected_ty.type_arguments().next()?;
+    if ctx.sema.type_of_expr(&expr)? != inner_ty {
+        return None;
+    }
+
+    let target = expr.syntax().text_range();
+    acc.add(
+        AssistId("wrap_in_some_or_ok", AssistKind::QuickFix),
+        format!("Wrap in {}", variant),
+        target,
+        |builder| {
+            let wrapped = make::expr_call(
+                make::expr_path(make::path_unqualified(make::path_segment(make::name_ref(
+                    variant,
+                )))),
+                make::arg_list(iter::once(expr.clone())),
+            );
+            builder.replace_ast(expr, wrapped);
+        },
+    )
+}
+
+/// The type the context of `expr` expects, and whether `expr` is the tail of a function.
+fn expected_type(ctx: &AssistContext, expr: &ast::Expr) -> Option<(Type, bool)> {
+    let parent = expr.syntax().parent()?;
+    if let Some(block) = ast::BlockExpr::cast(parent.clone()) {
+        let fn_ = block.syntax().parent().and_then(ast::Fn::cast)?;
+        if block.expr().as_ref() != Some(expr) {
+            return None;
+        }
+        return Some((ctx.sema.to_def(&fn_)?.ret_type(ctx.db()), true));
+    }
+    if ast::ReturnExpr::can_cast(parent.kind()) {
+        // `return` inside a closure returns from the closure.
+        let fn_ = parent
+            .ancestors()
+            .find(|it| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind()))?;
+        let fn_ = ast::Fn::cast(fn_)?;
+        return Some((ctx.sema.to_def(&fn_)?.ret_type(ctx.db()), false));
+    }
+    if let Some(let_stmt) = ast::LetStmt::cast(parent.clone()) {
+        if let_stmt.initializer().as_ref() != Some(expr) {
+            return None;
+        }
+        // Without an annotation, the binding just gets the type of the initializer.
+        let_stmt.ty()?;
+        return Some((ctx.sema.type_of_pat(&let_stmt.pat()?)?, false));
+    }
+    if ast::ArgList::can_cast(parent.kind()) {
+        let param = ActiveParameter::at_token(&ctx.sema, expr.syntax().first_token()?)?;
+        return Some((param.ty, false));
+    }
+    if let Some(field) = ast::RecordExprField::cast(parent) {
+        let (field, _) = ctx.sema.resolve_record_field(&field)?;
+        return Some((field.signature_ty(ctx.db()), false));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use ide_db::helpers::FamousDefs;
+
+    use crate::tests::{check_assist, check_assist_not_applicable};
+
+    use super::*;
+
+    fn check(ra_fixture_before: &str, ra_fixture_after: &str) {
+        let before = &format!(
+            "//- /main.rs crate:main deps:core{}{}",
+            ra_fixture_before,
+            FamousDefs::FIXTURE
+        );
+        check_assist(wrap_in_some_or_ok, before, ra_fixture_after);
+    }
+
+    fn check_not_applicable(ra_fixture: &str) {
+        let fixture =
+            format!("//- /main.rs crate:main deps:core{}{}", ra_fixture, FamousDefs::FIXTURE);
+        check_assist_not_applicable(wrap_in_some_or_ok, &fixture)
+    }
+
+    #[test]
+    fn wrap_tail_expr_in_some() {
+        check(
+            r#"
+fn foo(x: i32) -> Option<i32> {
+    x + 1<|>
+}
+"#,
+            r#"
+fn foo(x: i32) -> Option<i32> {
+    Some(x + 1)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_returned_expr_in_ok() {
+        check(
+            r#"
+struct Error;
+fn foo(x: u32) -> Result<u32, Error> {
+    if x > 0 {
+        return <|>x;
+    }
+    Err(Error)
+}
+"#,
+            r#"
+struct Error;
+fn foo(x: u32) -> Result<u32, Error> {
+    if x > 0 {
+        return Ok(x);
+    }
+    Err(Error)
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_when_already_wrapped() {
+        check_not_applicable(
+            r#"
+fn foo() -> Option<i32> {
+    None<|>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_mismatched_inner_type() {
+        check_not_applicable(
+            r#"
+fn foo() -> Option<i32> {
+    "foo"<|>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_result_tail_expr() {
+        check_not_applicable(
+            r#"
+struct Error;
+fn foo(x: u32) -> Result<u32, Error> {
+    x<|>
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_let_initializer_in_some() {
+        check(
+            r#"
+fn foo(y: u32) {
+    let x: Option<u32> = y<|>;
+}
+"#,
+            r#"
+fn foo(y: u32) {
+    let x: Option<u32> = Some(y);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_argument_in_some() {
+        check(
+            r#"
+fn bar(x: Option<u32>) {}
+fn foo(y: u32) {
+    bar(y<|>);
+}
+"#,
+            r#"
+fn bar(x: Option<u32>) {}
+fn foo(y: u32) {
+    bar(Some(y));
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn wrap_record_field_in_ok() {
+        check(
+            r#"
+struct Error;
+struct S { x: Result<u32, Error> }
+fn foo(y: u32) -> S {
+    S { x: y<|> }
+}
+"#,
+            r#"
+struct Error;
+struct S { x: Result<u32, Error> }
+fn foo(y: u32) -> S {
+    S { x: Ok(y) }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn not_applicable_to_user_defined_option() {
+        check_assist_not_applicable(
+            wrap_in_some_or_ok,
+            r#"
+enum Option<T> { None, Some(T) }
+fn foo() -> Option<i32> {
+    42<|>
+}
+"#,
+        );
+    }
+}
@@ -120,17 +120,29 @@ mod handlers {
 
     pub(crate) type Handler = fn(&mut Assists, &AssistContext) -> Option<()>;
 
+    mod add_digit_separators;
     mod add_explicit_type;
+    mod add_lifetime_to_type;
     mod add_missing_impl_members;
+    mod add_must_use;
     mod add_turbo_fish;
     mod apply_demorgan;
     mod auto_import;
     mod change_visibility;
+    mod collapse_nested_if;
+    mod convert_concat_to_format;
+    mod convert_enum_to_struct;
+    mod convert_for_to_find;
     mod convert_integer_literal;
+    mod convert_return_str_to_string;
+    mod convert_to_free_function;
     mod early_return;
     mod expand_glob_import;
+    mod extract_closure_to_function;
+    mod extract_method;
     mod extract_module_to_file;
     mod extract_struct_from_enum_variant;
+    mod extract_type_alias;
     mod extract_variable;
     mod fill_match_arms;
     mod fix_visibility;
@@ -139,10 +151,13 @@ mod handlers {
     mod flip_trait_bound;
     mod generate_derive;
     mod generate_default_from_enum_variant;
+    mod generate_from_impl;
     mod generate_from_impl_for_enum;
     mod generate_function;
+    mod generate_getter;
     mod generate_impl;
     mod generate_new;
+    mod generate_setter;
     mod infer_function_return_type;
     mod inline_local_variable;
     mod introduce_named_lifetime;
@@ -151,6 +166,7 @@ mod handlers {
     mod merge_match_arms;
     mod move_bounds;
     mod move_guard;
+    mod move_to_module;
     mod qualify_path;
     mod raw_string;
     mod remove_dbg;
@@ -158,30 +174,49 @@ mod handlers {
     mod remove_unused_param;
     mod reorder_fields;
     mod replace_derive_with_manual_impl;
+    mod replace_format_with_to_string;
+    mod replace_generic_with_impl_trait;
     mod replace_if_let_with_match;
     mod replace_impl_trait_with_generic;
     mod replace_let_with_if_let;
     mod replace_qualified_name_with_use;
     mod replace_string_with_char;
+    mod replace_try_expr_with_match;
     mod replace_unwrap_with_match;
+    mod replace_unwrap_with_try;
     mod split_import;
+    mod surround_with;
     mod toggle_ignore;
     mod unwrap_block;
+    mod wrap_in_some_or_ok;
     mod wrap_return_type_in_result;
 
     pub(crate) fn all() -> &'static [Handler] {
         &[
             // These are alphabetic for the foolish consistency
+            add_digit_separators::add_digit_separators,
             add_explicit_type::add_explicit_type,
+            add_lifetime_to_type::add_lifetime_to_type,
+            add_must_use::add_must_use,
             add_turbo_fish::add_turbo_fish,
             apply_demorgan::apply_demorgan,
             auto_import::auto_import,
             change_visibility::change_visibility,
+            collapse_nested_if::collapse_nested_if,
+            convert_concat_to_format::convert_concat_to_format,
+            convert_enum_to_struct::convert_enum_to_struct,
+            convert_for_to_find::convert_for_to_find,
             convert_integer_literal::convert_integer_literal,
+            convert_return_str_to_string::convert_return_str_to_string,
+            convert_return_str_to_string::convert_return_string_to_str,
+            convert_to_free_function::convert_to_free_function,
             early_return::convert_to_guarded_return,
             expand_glob_import::expand_glob_import,
+            extract_closure_to_function::extract_closure_to_function,
+            extract_method::extract_method,
             extract_module_to_file::extract_module_to_file,
             extract_struct_from_enum_variant::extract_struct_from_enum_variant,
+            extract_type_alias::extract_type_alias,
             extract_variable::extract_variable,
             fill_match_arms::fill_match_arms,
             fix_visibility::fix_visibility,
@@ -190,10 +225,13 @@ mod handlers {
             flip_trait_bound::flip_trait_bound,
             generate_derive::generate_derive,
             generate_default_from_enum_variant::generate_default_from_enum_variant,
+            generate_from_impl::generate_from_impl,
             generate_from_impl_for_enum::generate_from_impl_for_enum,
             generate_function::generate_function,
+            generate_getter::generate_getter,
             generate_impl::generate_impl,
             generate_new::generate_new,
+            generate_setter::generate_setter,
             infer_function_return_type::infer_function_return_type,
             inline_local_variable::inline_local_variable,
             introduce_named_lifetime::introduce_named_lifetime,
@@ -203,6 +241,7 @@ mod handlers {
             move_bounds::move_bounds_to_where_clause,
             move_guard::move_arm_cond_to_match_guard,
             move_guard::move_guard_to_arm_body,
+            move_to_module::move_to_module,
             qualify_path::qualify_path,
             raw_string::add_hash,
             raw_string::make_usual_string,
@@ -212,15 +251,22 @@ mod handlers {
             remove_unused_param::remove_unused_param,
             reorder_fields::reorder_fields,
             replace_derive_with_manual_impl::replace_derive_with_manual_impl,
+            replace_format_with_to_string::replace_format_with_to_string,
+            replace_generic_with_impl_trait::replace_generic_with_impl_trait,
             replace_if_let_with_match::replace_if_let_with_match,
             replace_if_let_with_match::replace_match_with_if_let,
             replace_impl_trait_with_generic::replace_impl_trait_with_generic,
             replace_let_with_if_let::replace_let_with_if_let,
             replace_qualified_name_with_use::replace_qualified_name_with_use,
+            replace_try_expr_with_match::replace_try_expr_with_match,
             replace_unwrap_with_match::replace_unwrap_with_match,
+            replace_unwrap_with_try::replace_unwrap_with_try,
             split_import::split_import,
+            surround_with::surround_with_block,
+            surround_with::surround_with_parens,
             toggle_ignore::toggle_ignore,
             unwrap_block::unwrap_block,
+            wrap_in_some_or_ok::wrap_in_some_or_ok,
             wrap_return_type_in_result::wrap_return_type_in_result,
             // These are manually sorted for better priorities
             add_missing_impl_members::add_missing_impl_members,
@@ -158,10 +158,13 @@ fn assist_order_field_struct() {
     let assists = Assist::resolved(&db, &AssistConfig::default(), frange);
     let mut assists = assists.iter();
 
+    assert_eq!(assists.next().expect("expected assist").assist.label, "Change visibility to pub");
     assert_eq!(
         assists.next().expect("expected assist").assist.label,
         "Change visibility to pub(crate)"
     );
+    assert_eq!(assists.next().expect("expected assist").assist.label, "Generate getter");
+    assert_eq!(assists.next().expect("expected assist").assist.label, "Generate setter");
     assert_eq!(assists.next().expect("expected assist").assist.label, "Add `#[derive]`");
 }
 
@@ -2,6 +2,19 @@
 
 use super::check_doc_test;
 
+#[test]
+fn doctest_add_digit_separators() {
+    check_doc_test(
+        "add_digit_separators",
+        r#####"
+const _: i32 = 1000000<|>;
+"#####,
+        r#####"
+const _: i32 = 1_000_000;
+"#####,
+    )
+}
+
 #[test]
 fn doctest_add_explicit_type() {
     check_doc_test(
@@ -101,6 +114,43 @@ impl Trait<u32> for () {
     )
 }
 
+#[test]
+fn doctest_add_lifetime_to_type() {
+    check_doc_test(
+        "add_lifetime_to_type",
+        r#####"
+struct Point {
+    x: &<|>u32,
+    y: u32,
+}
+"#####,
+        r#####"
+struct Point<'a> {
+    x: &'a u32,
+    y: u32,
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_add_must_use() {
+    check_doc_test(
+        "add_must_use",
+        r#####"
+fn is_<|>empty(s: &str) -> bool {
+    s.len() == 0
+}
+"#####,
+        r#####"
+#[must_use]
+fn is_empty(s: &str) -> bool {
+    s.len() == 0
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_add_turbo_fish() {
     check_doc_test(
@@ -166,7 +216,102 @@ fn doctest_change_visibility() {
 <|>fn frobnicate() {}
 "#####,
         r#####"
-pub(crate) fn frobnicate() {}
+pub fn frobnicate() {}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_collapse_nested_if() {
+    check_doc_test(
+        "collapse_nested_if",
+        r#####"
+fn main() {
+    <|>if x > 0 {
+        if x < 10 {
+            println!("small");
+        }
+    }
+}
+"#####,
+        r#####"
+fn main() {
+    if x > 0 && x < 10 {
+        println!("small");
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_concat_to_format() {
+    check_doc_test(
+        "convert_concat_to_format",
+        r#####"
+//- /main.rs crate:main deps:alloc
+use alloc::string::String;
+fn greet(greeting: String, name: &str) {
+    let s = greeting <|>+ ", " + name;
+}
+//- /liballoc.rs crate:alloc
+pub mod string { pub struct String; }
+"#####,
+        r#####"
+use alloc::string::String;
+fn greet(greeting: String, name: &str) {
+    let s = format!("{}, {}", greeting, name);
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_enum_to_struct() {
+    check_doc_test(
+        "convert_enum_to_struct",
+        r#####"
+enum <|>Meters { Meters(u32) }
+"#####,
+        r#####"
+struct Meters(u32);
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_for_to_find() {
+    check_doc_test(
+        "convert_for_to_find",
+        r#####"
+//- /main.rs crate:main deps:core
+fn first_long<'a>(words: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    <|>for word in words {
+        if word.len() > 5 {
+            return Some(word);
+        }
+    }
+    None
+}
+//- /libcore.rs crate:core
+pub mod iter {
+    pub use self::traits::{collect::IntoIterator, iterator::Iterator};
+    mod traits {
+        pub(crate) mod iterator { pub trait Iterator { type Item; } }
+        pub(crate) mod collect {
+            pub trait IntoIterator { type Item; }
+            impl<I: super::iterator::Iterator> IntoIterator for I { type Item = I::Item; }
+        }
+    }
+}
+pub mod option { pub enum Option<T> { None, Some(T) } }
+pub mod prelude { pub use crate::{iter::Iterator, option::Option::{self, *}}; }
+#[prelude_import] pub use prelude::*;
+"#####,
+        r#####"
+fn first_long<'a>(words: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    words.into_iter().find(|&word| word.len() > 5)
+}
 "#####,
     )
 }
@@ -184,6 +329,77 @@ const _: i32 = 0b1010;
     )
 }
 
+#[test]
+fn doctest_convert_return_str_to_string() {
+    check_doc_test(
+        "convert_return_str_to_string",
+        r#####"
+fn name() -> &'static str<|> {
+    "Ferris"
+}
+"#####,
+        r#####"
+fn name() -> String {
+    "Ferris".to_string()
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_return_string_to_str() {
+    check_doc_test(
+        "convert_return_string_to_str",
+        r#####"
+//- /main.rs crate:main deps:alloc
+use alloc::string::String;
+fn name() -> String<|> {
+    "Ferris".to_string()
+}
+//- /liballoc.rs crate:alloc
+pub mod string { pub struct String; }
+"#####,
+        r#####"
+use alloc::string::String;
+fn name() -> &'static str {
+    "Ferris"
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_convert_to_free_function() {
+    check_doc_test(
+        "convert_to_free_function",
+        r#####"
+struct Foo;
+impl Foo {
+    fn <|>helper(x: i32) -> i32 {
+        x + 1
+    }
+}
+
+fn main() {
+    Foo::helper(92);
+}
+"#####,
+        r#####"
+struct Foo;
+impl Foo {
+}
+
+fn helper(x: i32) -> i32 {
+    x + 1
+}
+
+fn main() {
+    helper(92);
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_convert_to_guarded_return() {
     check_doc_test(
@@ -235,6 +451,56 @@ fn qux(bar: Bar, baz: Baz) {}
     )
 }
 
+#[test]
+fn doctest_extract_closure_to_function() {
+    check_doc_test(
+        "extract_closure_to_function",
+        r#####"
+fn main() {
+    let add_one = <|>|x: i32| x + 1;
+}
+"#####,
+        r#####"
+fn main() {
+    let add_one = fun_name;
+}
+
+fn $0fun_name(x: i32) -> i32 {
+    x + 1
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_extract_method() {
+    check_doc_test(
+        "extract_method",
+        r#####"
+struct Counter { count: u32 }
+impl Counter {
+    fn bump(&mut self, step: u32) {
+        <|>let next = self.count + step;
+        self.count = next;<|>
+    }
+}
+"#####,
+        r#####"
+struct Counter { count: u32 }
+impl Counter {
+    fn bump(&mut self, step: u32) {
+        self.fun_name(step);
+    }
+
+    fn $0fun_name(&mut self, step: u32) {
+        let next = self.count + step;
+        self.count = next;
+    }
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_extract_module_to_file() {
     check_doc_test(
@@ -265,6 +531,25 @@ enum A { One(One) }
     )
 }
 
+#[test]
+fn doctest_extract_type_alias() {
+    check_doc_test(
+        "extract_type_alias",
+        r#####"
+struct S {
+    field: <|>HashMap<String, Vec<(u32, String)>>,
+}
+"#####,
+        r#####"
+type $0Type = HashMap<String, Vec<(u32, String)>>;
+
+struct S {
+    field: Type,
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_extract_variable() {
     check_doc_test(
@@ -426,6 +711,26 @@ struct Point {
     )
 }
 
+#[test]
+fn doctest_generate_from_impl() {
+    check_doc_test(
+        "generate_from_impl",
+        r#####"
+struct <|>Meters(u32);
+"#####,
+        r#####"
+struct Meters(u32);
+
+impl From<${1:u32}> for Meters {
+    fn from(value: ${1:u32}) -> Self {
+        ${0:todo!()}
+    }
+}
+
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_from_impl_for_enum() {
     check_doc_test(
@@ -472,6 +777,30 @@ fn bar(arg: &str, baz: Baz) ${0:-> ()} {
     )
 }
 
+#[test]
+fn doctest_generate_getter() {
+    check_doc_test(
+        "generate_getter",
+        r#####"
+struct Person {
+    nam<|>e: String,
+}
+"#####,
+        r#####"
+struct Person {
+    name: String,
+}
+
+impl Person {
+    pub fn $0name(&self) -> &String {
+        &self.name
+    }
+}
+
+"#####,
+    )
+}
+
 #[test]
 fn doctest_generate_impl() {
     check_doc_test(
@@ -515,6 +844,30 @@ impl<T: Clone> Ctx<T> {
     )
 }
 
+#[test]
+fn doctest_generate_setter() {
+    check_doc_test(
+        "generate_setter",
+        r#####"
+struct Person {
+    nam<|>e: String,
+}
+"#####,
+        r#####"
+struct Person {
+    name: String,
+}
+
+impl Person {
+    pub fn $0set_name(&mut self, name: String) {
+        self.name = name;
+    }
+}
+
+"#####,
+    )
+}
+
 #[test]
 fn doctest_infer_function_return_type() {
     check_doc_test(
@@ -594,12 +947,12 @@ fn doctest_make_raw_string() {
         "make_raw_string",
         r#####"
 fn main() {
-    "Hello,<|> World!";
+    "Hello,<|> \"World!\"";
 }
 "#####,
         r#####"
 fn main() {
-    r#"Hello, World!"#;
+    r#"Hello, "World!""#;
 }
 "#####,
     )
@@ -735,6 +1088,29 @@ fn handle(action: Action) {
     )
 }
 
+#[test]
+fn doctest_move_to_module() {
+    check_doc_test(
+        "move_to_module",
+        r#####"
+mod shapes {}
+
+fn <|>area(w: u32, h: u32) -> u32 { w * h }
+
+fn main() { area(2, 3); }
+"#####,
+        r#####"
+mod shapes {
+    pub(crate) fn area(w: u32, h: u32) -> u32 { w * h }
+}
+
+use shapes::area;
+
+fn main() { area(2, 3); }
+"#####,
+    )
+}
+
 #[test]
 fn doctest_qualify_path() {
     check_doc_test(
@@ -864,6 +1240,38 @@ impl Debug for S {
     )
 }
 
+#[test]
+fn doctest_replace_format_with_to_string() {
+    check_doc_test(
+        "replace_format_with_to_string",
+        r#####"
+fn main() {
+    let x = 92;
+    let s = <|>format!("{}", x);
+}
+"#####,
+        r#####"
+fn main() {
+    let x = 92;
+    let s = x.to_string();
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_replace_generic_with_impl_trait() {
+    check_doc_test(
+        "replace_generic_with_impl_trait",
+        r#####"
+fn foo<<|>B: Bar>(bar: B) {}
+"#####,
+        r#####"
+fn foo(bar: impl Bar) {}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_replace_if_let_with_match() {
     check_doc_test(
@@ -991,6 +1399,32 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_replace_try_expr_with_match() {
+    check_doc_test(
+        "replace_try_expr_with_match",
+        r#####"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, ()> {
+    let x: Result<i32, ()> = Result::Ok(92);
+    let y = x<|>?;
+    Result::Ok(y)
+}
+"#####,
+        r#####"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, ()> {
+    let x: Result<i32, ()> = Result::Ok(92);
+    let y = match x {
+        Ok(v) => v,
+        Err(e) => return Err(e.into()),
+    };
+    Result::Ok(y)
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_replace_unwrap_with_match() {
     check_doc_test(
@@ -1015,6 +1449,29 @@ fn main() {
     )
 }
 
+#[test]
+fn doctest_replace_unwrap_with_try() {
+    check_doc_test(
+        "replace_unwrap_with_try",
+        r#####"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, ()> {
+    let x: Result<i32, ()> = Result::Ok(92);
+    let y = x.<|>unwrap();
+    Result::Ok(y)
+}
+"#####,
+        r#####"
+enum Result<T, E> { Ok(T), Err(E) }
+fn foo() -> Result<i32, ()> {
+    let x: Result<i32, ()> = Result::Ok(92);
+    let y = x?;
+    Result::Ok(y)
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_split_import() {
     check_doc_test(
@@ -1028,6 +1485,44 @@ use std::{collections::HashMap};
     )
 }
 
+#[test]
+fn doctest_surround_with_block() {
+    check_doc_test(
+        "surround_with_block",
+        r#####"
+fn main() {
+    <|>let x = 92;
+    println!("{}", x);<|>
+}
+"#####,
+        r#####"
+fn main() {
+    {
+        let x = 92;
+        println!("{}", x);
+    }
+}
+"#####,
+    )
+}
+
+#[test]
+fn doctest_surround_with_parens() {
+    check_doc_test(
+        "surround_with_parens",
+        r#####"
+fn main() {
+    let x = <|>1 * 2<|> + 3;
+}
+"#####,
+        r#####"
+fn main() {
+    let x = (1 * 2) + 3;
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_toggle_ignore() {
     check_doc_test(
@@ -1067,6 +1562,28 @@ fn foo() {
     )
 }
 
+#[test]
+fn doctest_wrap_in_some_or_ok() {
+    check_doc_test(
+        "wrap_in_some_or_ok",
+        r#####"
+//- /main.rs crate:main deps:core
+fn foo() -> Option<i32> {
+    42<|>
+}
+//- /libcore.rs crate:core
+pub mod option { pub enum Option<T> { None, Some(T) } }
+pub mod prelude { pub use crate::option::Option::{self, *}; }
+#[prelude_import] pub use prelude::*;
+"#####,
+        r#####"
+fn foo() -> Option<i32> {
+    Some(42)
+}
+"#####,
+    )
+}
+
 #[test]
 fn doctest_wrap_return_type_in_result() {
     check_doc_test(
@@ -3,22 +3,24 @@ pub(crate) mod import_assets;
 
 use std::ops;
 
-use hir::HasSource;
+use hir::{Adt, HasSource};
 use ide_db::RootDatabase;
 use itertools::Itertools;
+use stdx::format_to;
 use syntax::{
     ast::edit::AstNodeEdit,
     ast::AttrsOwner,
     ast::NameOwner,
-    ast::{self, edit, make, ArgListOwner},
+    ast::{self, edit, make, ArgListOwner, GenericParamsOwner},
     AstNode, Direction,
     SyntaxKind::*,
-    SyntaxNode, TextSize, T,
+    SyntaxNode, TextRange, TextSize, T,
 };
 
 use crate::{
     assist_config::SnippetCap,
     ast_transform::{self, AstTransform, QualifyPaths, SubstituteTypeParams},
+    AssistContext,
 };
 
 pub(crate) fn unwrap_trivial_block(block: ast::BlockExpr) -> ast::Expr {
@@ -245,6 +247,161 @@ fn invert_special_case(expr: &ast::Expr) -> Option<ast::Expr> {
     }
 }
 
+/// Whether a local of type `ty` stays usable after being passed to a function by value.
+/// Primitive types count even when there's no `Copy` lang item, and references are reborrowed.
+pub(crate) fn is_passed_by_copy(db: &RootDatabase, ty: &hir::Type) -> bool {
+    ty.is_copy(db)
+        || ty.is_bool()
+        || ty.is_float()
+        || ty.int_bounds().is_some()
+        || ty.remove_ref().is_some()
+}
+
+/// Whether `path_expr` is the receiver of a method call or field access, which
+/// dereference it automatically.
+pub(crate) fn is_autoderefed(path_expr: &ast::PathExpr) -> bool {
+    let parent = match path_expr.syntax().parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    if let Some(method_call) = ast::MethodCallExpr::cast(parent.clone()) {
+        return method_call.receiver().map_or(false, |it| it.syntax() == path_expr.syntax());
+    }
+    if let Some(field_expr) = ast::FieldExpr::cast(parent) {
+        return field_expr.expr().map_or(false, |it| it.syntax() == path_expr.syntax());
+    }
+    false
+}
+
 pub(crate) fn next_prev() -> impl Iterator<Item = Direction> {
     [Direction::Next, Direction::Prev].iter().copied()
 }
+
+// Finds the record or tuple field under the cursor, returning it together with
+// the name used to access it (the index, for tuple fields)
+pub(crate) fn find_field_at_offset(
+    ctx: &AssistContext,
+) -> Option<(hir::Field, String, ast::Type, TextRange)> {
+    if let Some(field) = ctx.find_node_at_offset::<ast::RecordField>() {
+        let def = ctx.sema.to_def(&field)?;
+        return Some((def, field.name()?.to_string(), field.ty()?, field.syntax().text_range()));
+    }
+    let field = ctx.find_node_at_offset::<ast::TupleField>()?;
+    let field_list = field.syntax().parent().and_then(ast::TupleFieldList::cast)?;
+    let idx = field_list.fields().position(|it| it == field)?;
+    let def = ctx.sema.to_def(&field)?;
+    Some((def, idx.to_string(), field.ty()?, field.syntax().text_range()))
+}
+
+// Tuple fields are accessed by index, which isn't a valid function name
+pub(crate) fn getter_name(field_name: &str) -> String {
+    if field_name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("field_{}", field_name)
+    } else {
+        field_name.to_string()
+    }
+}
+
+// Generates the surrounding `impl Type { <code> }` including type and lifetime
+// parameters
+pub(crate) fn generate_impl_text(strukt: &ast::Struct, code: &str) -> String {
+    generate_impl_text_inner(&strukt.clone().into(), None, code)
+}
+
+// Generates the surrounding `impl Trait for Type { <code> }` including type and
+// lifetime parameters
+pub(crate) fn generate_trait_impl_text(adt: &ast::AdtDef, trait_text: &str, code: &str) -> String {
+    generate_impl_text_inner(adt, Some(trait_text), code)
+}
+
+fn generate_impl_text_inner(adt: &ast::AdtDef, trait_text: Option<&str>, code: &str) -> String {
+    let type_params = adt.generic_param_list();
+    let mut buf = String::with_capacity(code.len());
+    buf.push_str("\n\nimpl");
+    if let Some(type_params) = &type_params {
+        format_to!(buf, "{}", type_params.syntax());
+    }
+    buf.push_str(" ");
+    if let Some(trait_text) = trait_text {
+        format_to!(buf, "{} for ", trait_text);
+    }
+    buf.push_str(adt.name().unwrap().text().as_str());
+    if let Some(type_params) = type_params {
+        let lifetime_params = type_params
+            .lifetime_params()
+            .filter_map(|it| it.lifetime())
+            .map(|it| it.text().clone());
+        let type_params =
+            type_params.type_params().filter_map(|it| it.name()).map(|it| it.text().clone());
+        format_to!(buf, "<{}>", lifetime_params.chain(type_params).format(", "))
+    }
+
+    format_to!(buf, " {{\n{}\n}}\n", code);
+
+    buf
+}
+
+// Uses a syntax-driven approach to find any impl blocks for the struct that
+// exist within the module/file
+//
+// Returns `None` if we've found an existing fn with the given name in any of
+// them, otherwise the first impl block, if there is one
+//
+// FIXME: change the new fn checking to a more semantic approach when that's more
+// viable (e.g. we process proc macros, etc)
+pub(crate) fn find_struct_impl(
+    ctx: &AssistContext,
+    strukt: &ast::Struct,
+    name: &str,
+) -> Option<Option<ast::Impl>> {
+    let db = ctx.db();
+    let module = strukt.syntax().ancestors().find(|node| {
+        ast::Module::can_cast(node.kind()) || ast::SourceFile::can_cast(node.kind())
+    })?;
+
+    let struct_def = ctx.sema.to_def(strukt)?;
+
+    let blocks: Vec<_> = module
+        .descendants()
+        .filter_map(ast::Impl::cast)
+        .filter(|impl_blk| {
+            let blk = match ctx.sema.to_def(impl_blk) {
+                Some(it) => it,
+                None => return false,
+            };
+
+            // FIXME: handle e.g. `struct S<T>; impl<U> S<U> {}`
+            // (we currently use the wrong type parameter)
+            // also we wouldn't want to use e.g. `impl S<u32>`
+            let same_ty = match blk.target_ty(db).as_adt() {
+                Some(def) => def == Adt::Struct(struct_def),
+                None => false,
+            };
+            let not_trait_impl = blk.target_trait(db).is_none();
+
+            same_ty && not_trait_impl
+        })
+        .collect();
+
+    if blocks.iter().any(|impl_blk| has_fn(impl_blk, name)) {
+        return None;
+    }
+
+    Some(blocks.into_iter().next())
+}
+
+fn has_fn(imp: &ast::Impl, rhs_name: &str) -> bool {
+    if let Some(il) = imp.assoc_item_list() {
+        for item in il.assoc_items() {
+            if let ast::AssocItem::Fn(f) = item {
+                if let Some(name) = f.name() {
+                    if name.text().eq_ignore_ascii_case(rhs_name) {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+
+    false
+}
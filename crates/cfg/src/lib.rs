@@ -41,6 +41,17 @@ impl CfgOptions {
         self.enabled.insert(CfgAtom::KeyValue { key, value });
     }
 
+    /// Returns the values of all enabled `key = "value"` options with the given key.
+    pub fn get_cfg_values<'a>(
+        &'a self,
+        cfg_key: &'a str,
+    ) -> impl Iterator<Item = &'a SmolStr> + 'a {
+        self.enabled.iter().filter_map(move |atom| match atom {
+            CfgAtom::KeyValue { key, value } if key == cfg_key => Some(value),
+            _ => None,
+        })
+    }
+
     pub fn apply_diff(&mut self, diff: CfgDiff) {
         for atom in diff.enable {
             self.enabled.insert(atom);
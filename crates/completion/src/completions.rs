@@ -13,22 +13,28 @@ pub(crate) mod postfix;
 pub(crate) mod macro_in_item_position;
 pub(crate) mod trait_impl;
 pub(crate) mod mod_;
+pub(crate) mod format_string;
+pub(crate) mod label;
+pub(crate) mod doc_link;
+
+use std::mem;
 
 use hir::{ModPath, ScopeDef, Type};
 
 use crate::{
     item::Builder,
+    matcher::fuzzy_match,
     render::{
         const_::render_const,
-        enum_variant::render_variant,
-        function::render_fn,
+        enum_variant::{render_variant, render_variant_by_name},
+        function::{render_fn, render_fn_with_try},
         macro_::render_macro,
         pattern::{render_struct_pat, render_variant_pat},
         render_field, render_resolution, render_tuple_field,
         type_alias::render_type_alias,
         RenderContext,
     },
-    CompletionContext, CompletionItem,
+    CompletionContext, CompletionItem, ImportEdit,
 };
 
 /// Represents an in-progress set of completions being built.
@@ -64,6 +70,18 @@ impl Completions {
         items.into_iter().for_each(|item| self.add(item.into()))
     }
 
+    /// Drops the items whose lookup doesn't match the identifier typed so far,
+    /// and records how the remaining ones matched it, for ranking.
+    pub(crate) fn retain_matching(&mut self, typed: &str) {
+        self.buf = mem::take(&mut self.buf)
+            .into_iter()
+            .filter_map(|item| {
+                let match_kind = fuzzy_match(typed, item.lookup())?.kind;
+                Some(item.with_match_kind(match_kind))
+            })
+            .collect();
+    }
+
     pub(crate) fn add_field(&mut self, ctx: &CompletionContext, field: hir::Field, ty: &Type) {
         let item = render_field(RenderContext::new(ctx), field, ty);
         self.add(item);
@@ -85,6 +103,19 @@ impl Completions {
         }
     }
 
+    /// Adds a resolution which is not visible from the current module, ranked
+    /// below all other completions.
+    pub(crate) fn add_private_resolution(
+        &mut self,
+        ctx: &CompletionContext,
+        local_name: String,
+        resolution: &ScopeDef,
+    ) {
+        if let Some(item) = render_resolution(RenderContext::new(ctx), local_name, resolution) {
+            self.add(item.into_private());
+        }
+    }
+
     pub(crate) fn add_macro(
         &mut self,
         ctx: &CompletionContext,
@@ -110,6 +141,22 @@ impl Completions {
         self.add(item)
     }
 
+    pub(crate) fn add_function_with_try(&mut self, ctx: &CompletionContext, func: hir::Function) {
+        if let Some(item) = render_fn_with_try(RenderContext::new(ctx), func) {
+            self.add(item)
+        }
+    }
+
+    pub(crate) fn add_function_with_import(
+        &mut self,
+        ctx: &CompletionContext,
+        func: hir::Function,
+        import_to_add: ImportEdit,
+    ) {
+        let item = render_fn(RenderContext::new(ctx), Some(import_to_add), None, func);
+        self.add(item)
+    }
+
     pub(crate) fn add_variant_pat(
         &mut self,
         ctx: &CompletionContext,
@@ -154,6 +201,16 @@ impl Completions {
         self.add(item);
     }
 
+    pub(crate) fn add_enum_variant_by_name(
+        &mut self,
+        ctx: &CompletionContext,
+        variant: hir::Variant,
+        path: ModPath,
+    ) {
+        let item = render_variant_by_name(RenderContext::new(ctx), variant, path);
+        self.add(item);
+    }
+
     pub(crate) fn add_enum_variant(
         &mut self,
         ctx: &CompletionContext,
@@ -3,9 +3,12 @@
 //! This module uses a bit of static metadata to provide completions
 //! for built-in attributes.
 
+use std::iter::successors;
+
+use ide_db::base_db::SourceDatabase;
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
-use syntax::{ast, AstNode, SyntaxKind};
+use syntax::{ast, AstNode, SyntaxKind, TextRange, TextSize, T};
 
 use crate::{
     context::CompletionContext,
@@ -35,6 +38,18 @@ pub(crate) fn complete_attribute(acc: &mut Completions, ctx: &CompletionContext)
             complete_lint(acc, ctx, token_tree.clone(), DEFAULT_LINT_COMPLETIONS);
             complete_lint(acc, ctx, token_tree, CLIPPY_LINTS);
         }
+        (Some(path), Some(_token_tree))
+            if path.to_string() == "cfg" || path.to_string() == "cfg_attr" =>
+        {
+            if is_feature_value(ctx) {
+                complete_cfg_features(acc, ctx);
+            } else {
+                complete_attribute_args(acc, ctx, CFG_ARGS);
+            }
+        }
+        (Some(path), Some(_token_tree)) if path.to_string() == "serde" => {
+            complete_attribute_args(acc, ctx, SERDE_ARGS);
+        }
         (_, Some(_token_tree)) => {}
         _ => complete_attribute_start(acc, ctx, attribute),
     }
@@ -67,6 +82,61 @@ fn complete_attribute_start(acc: &mut Completions, ctx: &CompletionContext, attr
     }
 }
 
+fn complete_attribute_args(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    args: &[AttrCompletion],
+) {
+    for arg in args {
+        let mut item =
+            CompletionItem::new(CompletionKind::Attribute, ctx.source_range(), arg.label)
+                .kind(CompletionItemKind::Attribute);
+
+        if let Some(lookup) = arg.lookup {
+            item = item.lookup_by(lookup);
+        }
+
+        match (arg.snippet, ctx.config.snippet_cap) {
+            (Some(snippet), Some(cap)) => {
+                item = item.insert_snippet(cap, snippet);
+            }
+            _ => {}
+        }
+
+        acc.add(item.build());
+    }
+}
+
+/// Checks whether the cursor is inside the string of a `feature = "…"` predicate.
+fn is_feature_value(ctx: &CompletionContext) -> bool {
+    if ctx.original_token.kind() != SyntaxKind::STRING {
+        return false;
+    }
+    let mut prev_tokens = successors(ctx.original_token.prev_token(), |it| it.prev_token())
+        .filter(|it| !it.kind().is_trivia());
+    matches!(
+        (prev_tokens.next(), prev_tokens.next()),
+        (Some(eq), Some(key)) if eq.kind() == T![=] && key.text() == "feature"
+    )
+}
+
+fn complete_cfg_features(acc: &mut Completions, ctx: &CompletionContext) {
+    let krate = match ctx.krate {
+        Some(it) => it,
+        None => return,
+    };
+    let token_range = ctx.original_token.text_range();
+    // Replace everything between the opening quote and the cursor.
+    let range = TextRange::new(token_range.start() + TextSize::of('"'), ctx.position.offset);
+    let crate_graph = ctx.db.crate_graph();
+    let features = crate_graph[krate.into()].cfg_options.get_cfg_values("feature").sorted();
+    for feature in features {
+        CompletionItem::new(CompletionKind::Attribute, range, feature.as_str())
+            .kind(CompletionItemKind::Attribute)
+            .add_to(acc);
+    }
+}
+
 struct AttrCompletion {
     label: &'static str,
     lookup: Option<&'static str>,
@@ -160,6 +230,73 @@ const ATTRIBUTES: &[AttrCompletion] = &[
     .prefer_inner(),
 ];
 
+/// https://doc.rust-lang.org/reference/conditional-compilation.html
+const CFG_ARGS: &[AttrCompletion] = &[
+    attr("all(…)", Some("all"), Some("all(${0:predicates})")),
+    attr("any(…)", Some("any"), Some("any(${0:predicates})")),
+    attr("debug_assertions", None, None),
+    attr("doc", None, None),
+    attr(r#"feature = "…""#, Some("feature"), Some(r#"feature = "${0:feature}""#)),
+    attr("not(…)", Some("not"), Some("not(${0:predicate})")),
+    attr(r#"target_arch = "…""#, Some("target_arch"), Some(r#"target_arch = "${0:x86_64}""#)),
+    attr(r#"target_endian = "…""#, Some("target_endian"), Some(r#"target_endian = "${0:little}""#)),
+    attr(r#"target_env = "…""#, Some("target_env"), Some(r#"target_env = "${0:gnu}""#)),
+    attr(r#"target_family = "…""#, Some("target_family"), Some(r#"target_family = "${0:unix}""#)),
+    attr(r#"target_os = "…""#, Some("target_os"), Some(r#"target_os = "${0:linux}""#)),
+    attr(
+        r#"target_pointer_width = "…""#,
+        Some("target_pointer_width"),
+        Some(r#"target_pointer_width = "${0:64}""#),
+    ),
+    attr(
+        r#"target_vendor = "…""#,
+        Some("target_vendor"),
+        Some(r#"target_vendor = "${0:unknown}""#),
+    ),
+    attr("test", None, None),
+    attr("unix", None, None),
+    attr("windows", None, None),
+];
+
+/// https://serde.rs/attributes.html
+const SERDE_ARGS: &[AttrCompletion] = &[
+    attr(r#"alias = "…""#, Some("alias"), Some(r#"alias = "${0:name}""#)),
+    attr("borrow", None, None),
+    attr(r#"bound = "…""#, Some("bound"), Some(r#"bound = "${0:T: MyTrait}""#)),
+    attr(r#"content = "…""#, Some("content"), Some(r#"content = "${0:content}""#)),
+    attr("default", None, None),
+    attr("deny_unknown_fields", None, None),
+    attr(
+        r#"deserialize_with = "…""#,
+        Some("deserialize_with"),
+        Some(r#"deserialize_with = "${0:path}""#),
+    ),
+    attr("flatten", None, None),
+    attr(r#"from = "…""#, Some("from"), Some(r#"from = "${0:FromType}""#)),
+    attr(r#"into = "…""#, Some("into"), Some(r#"into = "${0:IntoType}""#)),
+    attr(r#"remote = "…""#, Some("remote"), Some(r#"remote = "${0:Type}""#)),
+    attr(r#"rename = "…""#, Some("rename"), Some(r#"rename = "${0:name}""#)),
+    attr(r#"rename_all = "…""#, Some("rename_all"), Some(r#"rename_all = "${0:snake_case}""#)),
+    attr(
+        r#"serialize_with = "…""#,
+        Some("serialize_with"),
+        Some(r#"serialize_with = "${0:path}""#),
+    ),
+    attr("skip", None, None),
+    attr("skip_deserializing", None, None),
+    attr("skip_serializing", None, None),
+    attr(
+        r#"skip_serializing_if = "…""#,
+        Some("skip_serializing_if"),
+        Some(r#"skip_serializing_if = "${0:path}""#),
+    ),
+    attr(r#"tag = "…""#, Some("tag"), Some(r#"tag = "${0:type}""#)),
+    attr("transparent", None, None),
+    attr(r#"try_from = "…""#, Some("try_from"), Some(r#"try_from = "${0:FromType}""#)),
+    attr("untagged", None, None),
+    attr(r#"with = "…""#, Some("with"), Some(r#"with = "${0:module}""#)),
+];
+
 fn complete_derive(acc: &mut Completions, ctx: &CompletionContext, derive_input: ast::TokenTree) {
     if let Ok(existing_derives) = parse_comma_sep_input(derive_input) {
         for derive_completion in DEFAULT_DERIVE_COMPLETIONS
@@ -406,7 +543,10 @@ const DEFAULT_LINT_COMPLETIONS: &[LintCompletion] = &[
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Attribute);
@@ -508,7 +648,84 @@ struct Test {}
 
     #[test]
     fn test_attribute_completion_inside_nested_attr() {
-        check(r#"#[cfg(<|>)]"#, expect![[]])
+        check(r#"#[repr(<|>)]"#, expect![[]])
+    }
+
+    #[test]
+    fn test_cfg_completion() {
+        check(
+            r#"#[cfg(<|>)]"#,
+            expect![[r#"
+                at all(…)
+                at any(…)
+                at debug_assertions
+                at doc
+                at feature = "…"
+                at not(…)
+                at target_arch = "…"
+                at target_endian = "…"
+                at target_env = "…"
+                at target_family = "…"
+                at target_os = "…"
+                at target_pointer_width = "…"
+                at target_vendor = "…"
+                at test
+                at unix
+                at windows
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_cfg_key_value_completion() {
+        check_edit(
+            "target_os",
+            r#"#[cfg(all(unix, <|>))] fn f() {}"#,
+            r#"#[cfg(all(unix, target_os = "${0:linux}"))] fn f() {}"#,
+        );
+        check_edit(
+            "feature",
+            r#"#[cfg_attr(<|>, derive(Debug))] struct S;"#,
+            r#"#[cfg_attr(feature = "${0:feature}", derive(Debug))] struct S;"#,
+        );
+    }
+
+    #[test]
+    fn test_cfg_feature_completion() {
+        check(
+            r#"
+//- /lib.rs crate:foo cfg:feature=std,feature=serde
+#[cfg(feature = "<|>")] fn f() {}
+"#,
+            expect![[r#"
+                at serde
+                at std
+            "#]],
+        );
+        check_edit(
+            "serde",
+            r#"
+//- /lib.rs crate:foo cfg:feature=std,feature=serde
+#[cfg(all(unix, feature = "se<|>"))] fn f() {}
+"#,
+            r#"
+#[cfg(all(unix, feature = "serde"))] fn f() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_cfg_feature_completion_without_features() {
+        check(r#"#[cfg(feature = "<|>")] fn f() {}"#, expect![[""]]);
+    }
+
+    #[test]
+    fn test_serde_completion() {
+        check_edit(
+            "rename_all",
+            r#"#[serde(<|>)] struct S;"#,
+            r#"#[serde(rename_all = "${0:snake_case}")] struct S;"#,
+        );
     }
 
     #[test]
@@ -0,0 +1,112 @@
+//! Completes item names in intra-doc links, e.g. `/// See [Fo<|>]`.
+
+use hir::ScopeDef;
+use syntax::{ast, AstToken, TextRange, TextSize};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_intra_doc_link(acc: &mut Completions, ctx: &CompletionContext) {
+    let comment = match ast::Comment::cast(ctx.original_token.clone()) {
+        Some(it) if it.kind().doc.is_some() => it,
+        _ => return,
+    };
+    let comment_start = comment.syntax().text_range().start();
+    let prefix = &comment.text()[..usize::from(ctx.position.offset - comment_start)];
+
+    let bracket = match prefix.rfind('[') {
+        Some(it) => it,
+        None => return,
+    };
+    let link = &prefix[bracket + 1..];
+    // Links are often written as code, e.g. [`Foo`].
+    let typed = link.strip_prefix('`').unwrap_or(link);
+    if !typed.chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return;
+    }
+
+    let range = TextRange::new(ctx.position.offset - TextSize::of(typed), ctx.position.offset);
+    ctx.scope.process_all_names(&mut |name, res| {
+        let kind = match res {
+            ScopeDef::ModuleDef(def) => match def {
+                hir::ModuleDef::Module(_) => CompletionItemKind::Module,
+                hir::ModuleDef::Function(_) => CompletionItemKind::Function,
+                hir::ModuleDef::Adt(hir::Adt::Enum(_)) => CompletionItemKind::Enum,
+                hir::ModuleDef::Adt(_) => CompletionItemKind::Struct,
+                hir::ModuleDef::Variant(_) => CompletionItemKind::EnumVariant,
+                hir::ModuleDef::Const(_) => CompletionItemKind::Const,
+                hir::ModuleDef::Static(_) => CompletionItemKind::Static,
+                hir::ModuleDef::Trait(_) => CompletionItemKind::Trait,
+                hir::ModuleDef::TypeAlias(_) => CompletionItemKind::TypeAlias,
+                hir::ModuleDef::BuiltinType(_) => return,
+            },
+            ScopeDef::MacroDef(_) => CompletionItemKind::Macro,
+            _ => return,
+        };
+        CompletionItem::new(CompletionKind::Reference, range, name.to_string())
+            .kind(kind)
+            .add_to(acc);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    #[test]
+    fn completes_type_in_doc_link() {
+        check_edit(
+            "Foo",
+            r#"
+struct Foo;
+
+/// See [Fo<|>]
+fn bar() {}
+"#,
+            r#"
+struct Foo;
+
+/// See [Foo]
+fn bar() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_function_in_code_doc_link() {
+        check_edit(
+            "helper",
+            r#"
+fn helper() {}
+
+/// Delegates to [`hel<|>`].
+fn run() {}
+"#,
+            r#"
+fn helper() {}
+
+/// Delegates to [`helper`].
+fn run() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_completion_outside_of_doc_links() {
+        let actual = completion_list(
+            r#"
+struct Foo;
+
+// See [Fo<|>]
+/// See Fo
+fn bar() {}
+"#,
+            CompletionKind::Reference,
+        );
+        expect![[""]].assert_eq(&actual);
+    }
+}
@@ -1,10 +1,21 @@
 //! Completes references after dot (fields and method calls).
 
-use hir::{HasVisibility, Type};
+use std::iter;
+
+use either::Either;
+use hir::{
+    Access, AsAssocItem, AssocItemContainer, BuiltinInt, BuiltinType, HasSource, HasVisibility,
+    ModuleDef, PathResolution, Type, TypeParam,
+};
+use ide_db::{helpers::insert_use::ImportScope, imports_locator};
 use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, NameOwner, TypeBoundsOwner},
+    AstNode, SyntaxKind,
+};
 use test_utils::mark;
 
-use crate::{context::CompletionContext, Completions};
+use crate::{context::CompletionContext, Completions, ImportEdit};
 
 /// Complete dot accesses, i.e. fields or methods.
 pub(crate) fn complete_dot(acc: &mut Completions, ctx: &CompletionContext) {
@@ -13,9 +24,17 @@ pub(crate) fn complete_dot(acc: &mut Completions, ctx: &CompletionContext) {
         _ => return,
     };
 
-    let receiver_ty = match ctx.sema.type_of_expr(&dot_receiver) {
-        Some(ty) => ty,
-        _ => return,
+    let receiver_ty = if ctx.dot_receiver_is_ambiguous_float_literal {
+        // `5.` lexes as a float, but once a method name follows it, it's the integer `5`.
+        match ctx.krate {
+            Some(krate) => Type::from_builtin(ctx.db, krate, BuiltinType::Int(BuiltinInt::I32)),
+            None => return,
+        }
+    } else {
+        match ctx.sema.type_of_expr(&dot_receiver) {
+            Some(ty) => ty,
+            _ => return,
+        }
     };
 
     if ctx.is_call {
@@ -47,16 +66,134 @@ fn complete_methods(acc: &mut Completions, ctx: &CompletionContext, receiver: &T
     if let Some(krate) = ctx.krate {
         let mut seen_methods = FxHashSet::default();
         let traits_in_scope = ctx.scope.traits_in_scope();
+        // Methods taking `self` by value can't be called on a `?Sized` type parameter.
+        let is_maybe_unsized = receiver
+            .autoderef(ctx.db)
+            .any(|ty| ty.as_type_param().map_or(false, |param| is_maybe_unsized_param(ctx, param)));
+        // Methods with a `where Self: Sized` bound can't be called on trait objects.
+        let is_dyn = receiver.autoderef(ctx.db).any(|ty| ty.as_dyn_trait().is_some());
         receiver.iterate_method_candidates(ctx.db, krate, &traits_in_scope, None, |_ty, func| {
+            let takes_self_by_value = func
+                .self_param(ctx.db)
+                .map_or(false, |it| matches!(it.access(ctx.db), Access::Owned));
             if func.self_param(ctx.db).is_some()
+                && !(is_maybe_unsized && takes_self_by_value)
+                && !(is_dyn && func.has_sized_self_bound(ctx.db))
                 && ctx.scope.module().map_or(true, |m| func.is_visible_from(ctx.db, m))
                 && seen_methods.insert(func.name(ctx.db))
             {
                 acc.add_function(ctx, func, None);
+                acc.add_function_with_try(ctx, func);
             }
             None::<()>
         });
+
+        if ctx.config.enable_autoimport_completions && ctx.config.resolve_additional_edits_lazily()
+        {
+            complete_methods_of_unimported_traits(acc, ctx, receiver, &seen_methods);
+        }
+    }
+}
+
+/// Checks whether `param` is declared with a `?Sized` bound, either inline or in a `where` clause.
+fn is_maybe_unsized_param(ctx: &CompletionContext, param: TypeParam) -> bool {
+    let param = match param.source(ctx.db).value {
+        Either::Right(it) => it,
+        Either::Left(_) => return false,
+    };
+    let is_maybe_sized = |bound: ast::TypeBound| {
+        let path = match bound.ty() {
+            Some(ast::Type::PathType(it)) if bound.question_mark_token().is_some() => it.path(),
+            _ => None,
+        };
+        match path.and_then(|it| ctx.scope.speculative_resolve(&it)) {
+            Some(PathResolution::Def(ModuleDef::Trait(it))) => it.is_sized(ctx.db),
+            _ => false,
+        }
+    };
+    if param.type_bound_list().map_or(false, |it| it.bounds().any(is_maybe_sized)) {
+        return true;
     }
+    let name = match param.name() {
+        Some(it) => it,
+        None => return false,
+    };
+    let where_clause = param
+        .syntax()
+        .ancestors()
+        .find(|it| ast::GenericParamList::can_cast(it.kind()))
+        .and_then(|it| it.parent())
+        .and_then(|it| it.children().find_map(ast::WhereClause::cast));
+    where_clause.map_or(false, |it| {
+        it.predicates()
+            .filter(|pred| pred.ty().map_or(false, |ty| ty.syntax().text() == name.text().as_str()))
+            .filter_map(|pred| pred.type_bound_list())
+            .any(|it| it.bounds().any(is_maybe_sized))
+    })
+}
+
+/// Completes methods of traits which are not in scope yet, importing the trait when the
+/// completion is applied.
+fn complete_methods_of_unimported_traits(
+    acc: &mut Completions,
+    ctx: &CompletionContext,
+    receiver: &Type,
+    seen_methods: &FxHashSet<hir::Name>,
+) -> Option<()> {
+    let _p = profile::span("complete_methods_of_unimported_traits");
+    if ctx.token.kind() != SyntaxKind::IDENT {
+        return None;
+    }
+    let db = ctx.db;
+    let krate = ctx.krate?;
+    let current_module = ctx.scope.module()?;
+    let anchor = ctx.name_ref_syntax.as_ref()?;
+    let import_scope = ImportScope::find_insert_use_container(anchor.syntax(), &ctx.sema)?;
+    let traits_in_scope = ctx.scope.traits_in_scope();
+
+    let candidates = imports_locator::find_similar_imports(
+        &ctx.sema,
+        krate,
+        Some(40),
+        ctx.token.text().as_str(),
+        true,
+    );
+    for candidate in candidates {
+        let func = match candidate {
+            Either::Left(ModuleDef::Function(it)) => it,
+            _ => continue,
+        };
+        let trait_ = match func.as_assoc_item(db).map(|it| it.container(db)) {
+            Some(AssocItemContainer::Trait(it)) => it,
+            _ => continue,
+        };
+        if traits_in_scope.contains(&trait_.into()) || seen_methods.contains(&func.name(db)) {
+            continue;
+        }
+        let trait_set = iter::once(trait_.into()).collect();
+        let applicable = receiver
+            .iterate_method_candidates(db, krate, &trait_set, None, |_ty, it| {
+                if it == func {
+                    Some(())
+                } else {
+                    None
+                }
+            })
+            .is_some();
+        if !applicable || func.self_param(db).is_none() {
+            continue;
+        }
+        let import_path = match current_module.find_use_path(db, ModuleDef::Trait(trait_)) {
+            Some(it) => it,
+            None => continue,
+        };
+        acc.add_function_with_import(
+            ctx,
+            func,
+            ImportEdit { import_path, import_scope: import_scope.clone() },
+        );
+    }
+    Some(())
 }
 
 #[cfg(test)]
@@ -64,13 +201,24 @@ mod tests {
     use expect_test::{expect, Expect};
     use test_utils::mark;
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{
+            check_edit, check_edit_with_config, completion_list, completion_list_with_config,
+        },
+        CompletionConfig, CompletionKind, CompletionResolveCapability,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Reference);
         expect.assert_eq(&actual);
     }
 
+    fn autoimport_config() -> CompletionConfig {
+        let mut config = CompletionConfig::default();
+        config.active_resolve_capabilities.insert(CompletionResolveCapability::AdditionalTextEdits);
+        config
+    }
+
     #[test]
     fn test_struct_field_and_method_completion() {
         check(
@@ -88,6 +236,24 @@ fn foo(s: S) { s.<|> }
         );
     }
 
+    #[test]
+    fn test_method_completion_on_self_constructor() {
+        check(
+            r#"
+struct S { foo: u32 }
+impl S {
+    fn new() -> Self { S { foo: 0 } }
+    fn bar(&self) {}
+    fn baz() { Self::new().<|> }
+}
+"#,
+            expect![[r#"
+                fd foo   u32
+                me bar() fn bar(&self)
+            "#]],
+        );
+    }
+
     #[test]
     fn test_struct_field_completion_self() {
         check(
@@ -120,6 +286,167 @@ impl A {
         )
     }
 
+    #[test]
+    fn test_method_completion_through_deref() {
+        check(
+            r#"
+mod ops {
+    #[lang = "deref"]
+    pub trait Deref {
+        type Target;
+        fn deref(&self) -> &Self::Target;
+    }
+}
+mod boxed {
+    pub struct Box<T> { inner: T }
+    impl<T> crate::ops::Deref for Box<T> {
+        type Target = T;
+        fn deref(&self) -> &T { &self.inner }
+    }
+}
+mod vec {
+    pub struct Vec<T> { buf: T }
+    impl<T> Vec<T> {
+        pub fn push(&mut self, value: T) {}
+        pub fn len(&self) -> usize { 0 }
+    }
+}
+use boxed::Box;
+use vec::Vec;
+fn foo(v: Box<Vec<i32>>) { v.<|> }
+"#,
+            expect![[r#"
+                me len()   pub fn len(&self) -> usize
+                me push(…) pub fn push(&mut self, value: T)
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_method_completion_on_iterator_chain() {
+        check(
+            r#"
+mod iter {
+    pub trait Iterator {
+        type Item;
+        fn map<B, F>(self, f: F) -> Map<Self, F> { loop {} }
+        fn filter<P>(self, predicate: P) -> Filter<Self, P> { loop {} }
+        fn collect<B>(self) -> B { loop {} }
+    }
+    pub struct Iter<'a, T> { item: &'a T }
+    impl<'a, T> Iterator for Iter<'a, T> { type Item = &'a T; }
+    pub struct Map<I, F> { iter: I, f: F }
+    impl<I: Iterator, F> Iterator for Map<I, F> { type Item = I::Item; }
+    pub struct Filter<I, P> { iter: I, predicate: P }
+}
+use iter::Iterator;
+struct Vec<T> { item: T }
+impl<T> Vec<T> {
+    fn iter(&self) -> iter::Iter<'_, T> { loop {} }
+}
+fn main(v: Vec<i32>) {
+    v.iter().map(|x| x + 1).<|>
+}
+"#,
+            expect![[r#"
+                me map(…)    fn map<B, F>(self, f: F) -> Map<Self, F>
+                me filter(…) fn filter<P>(self, predicate: P) -> Filter<Self, P>
+                me collect() fn collect<B>(self) -> B
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_no_by_value_methods_on_unsized_type_param() {
+        check(
+            r#"
+#[lang = "sized"]
+trait Sized {}
+trait Shape {
+    fn area(&self) -> f64;
+    fn into_sides(self) -> u32;
+}
+fn foo<T: ?Sized + Shape>(shape: &T) { shape.<|> }
+"#,
+            expect![[r#"
+                me area() fn area(&self) -> f64
+            "#]],
+        );
+        check(
+            r#"
+#[lang = "sized"]
+trait Sized {}
+trait Shape {
+    fn area(&self) -> f64;
+    fn into_sides(self) -> u32;
+}
+fn foo<T>(shape: &T) where T: Shape + ?Sized { shape.<|> }
+"#,
+            expect![[r#"
+                me area() fn area(&self) -> f64
+            "#]],
+        );
+        check(
+            r#"
+#[lang = "sized"]
+trait Sized {}
+trait Shape {
+    fn area(&self) -> f64;
+    fn into_sides(self) -> u32;
+}
+fn foo<T: Shape>(shape: &T) { shape.<|> }
+"#,
+            expect![[r#"
+                me area()       fn area(&self) -> f64
+                me into_sides() fn into_sides(self) -> u32
+            "#]],
+        ); // Only the `Sized` lang item makes a bound relaxed.
+        check(
+            r#"
+trait Sized {}
+trait Shape {
+    fn area(&self) -> f64;
+    fn into_sides(self) -> u32;
+}
+fn foo<T: ?Sized + Shape>(shape: &T) { shape.<|> }
+"#,
+            expect![[r#"
+                me area()       fn area(&self) -> f64
+                me into_sides() fn into_sides(self) -> u32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_no_sized_self_methods_on_trait_objects() {
+        check(
+            r#"
+trait Shape {
+    fn area(&self) -> f64;
+    fn scaled(&self, factor: f64) -> Self where Self: Sized;
+}
+fn foo(shape: &dyn Shape) { shape.<|> }
+"#,
+            expect![[r#"
+                me area() fn area(&self) -> f64
+            "#]],
+        );
+        check(
+            r#"
+trait Shape {
+    fn area(&self) -> f64;
+    fn scaled(&self, factor: f64) -> Self where Self: Sized;
+}
+fn foo<T: Shape>(shape: &T) { shape.<|> }
+"#,
+            expect![[r#"
+                me area()    fn area(&self) -> f64
+                me scaled(…) fn scaled(&self, factor: f64) -> Self
+                where Self: Sized
+            "#]],
+        );
+    }
+
     #[test]
     fn test_no_struct_field_completion_for_method_call() {
         mark::check!(test_no_struct_field_completion_for_method_call);
@@ -251,6 +578,25 @@ fn foo(a: A) { a.<|> }
         );
     }
 
+    #[test]
+    fn completes_methods_of_return_position_impl_trait() {
+        check(
+            r"
+mod iter {
+    pub trait Iterator {
+        type Item;
+        fn next(&mut self) -> Self::Item;
+    }
+}
+fn numbers() -> impl iter::Iterator<Item = u32> { loop {} }
+fn foo() { numbers().<|> }
+",
+            expect![[r#"
+                me next() fn next(&mut self) -> Self::Item
+            "#]],
+        );
+    }
+
     #[test]
     fn test_no_non_self_method() {
         check(
@@ -283,6 +629,38 @@ fn foo() {
         )
     }
 
+    #[test]
+    fn test_tuple_field_completion_with_adt() {
+        check(
+            r#"
+struct String;
+fn foo(pair: (i32, String)) {
+   pair.<|>
+}
+"#,
+            expect![[r#"
+                fd 0 i32
+                fd 1 String
+            "#]],
+        )
+    }
+
+    #[test]
+    fn test_tuple_struct_field_completion() {
+        check(
+            r#"
+struct Pair(i32, u8);
+fn foo(pair: Pair) {
+   pair.<|>
+}
+"#,
+            expect![[r#"
+                fd 0 i32
+                fd 1 u8
+            "#]],
+        )
+    }
+
     #[test]
     fn test_tuple_field_inference() {
         check(
@@ -327,7 +705,7 @@ const X: u32 = {
 macro_rules! m { ($e:expr) => { $e } }
 struct A { the_field: u32 }
 fn foo(a: A) {
-    m!(a.x<|>)
+    m!(a.th<|>)
 }
 "#,
             expect![[r#"
@@ -360,7 +738,7 @@ fn foo(a: A) {
 macro_rules! m { ($e:expr) => { $e } }
 struct A { the_field: u32 }
 fn foo(a: A) {
-    m!(m!(m!(a.x<|>)))
+    m!(m!(m!(a.th<|>)))
 }
 "#,
             expect![[r#"
@@ -428,4 +806,133 @@ fn main() { make_s!().f<|>; }
             "#]],
         )
     }
+
+    #[test]
+    fn completes_methods_generated_by_macros() {
+        check(
+            r#"
+macro_rules! builder {
+    ($name:ident) => {
+        impl $name {
+            fn build(&self) {}
+        }
+    };
+}
+struct S;
+builder!(S);
+fn main(s: S) { s.<|> }
+"#,
+            expect![[r#"
+                me build() fn build(&self)
+            "#]],
+        )
+    }
+
+    #[test]
+    fn completes_methods_of_unimported_traits_with_trait_in_detail() {
+        let actual = completion_list_with_config(
+            autoimport_config(),
+            r#"
+//- /lib.rs crate:dep
+pub mod iter {
+    pub trait Iterator {
+        fn next(&mut self) -> Option<u32>;
+    }
+}
+pub struct Numbers;
+impl iter::Iterator for Numbers {
+    fn next(&mut self) -> Option<u32> { None }
+}
+//- /main.rs crate:main deps:dep
+fn main() {
+    let mut n = dep::Numbers;
+    n.next<|>
+}
+"#,
+            CompletionKind::Reference,
+        );
+        expect![[r#"
+            me next() fn next(&mut self) -> Option<u32> (as dep::iter::Iterator)
+        "#]]
+        .assert_eq(&actual);
+    }
+
+    #[test]
+    fn imports_trait_of_completed_method() {
+        check_edit_with_config(
+            autoimport_config(),
+            "next",
+            r#"
+//- /lib.rs crate:dep
+pub mod iter {
+    pub trait Iterator {
+        fn next(&mut self) -> Option<u32>;
+    }
+}
+pub struct Numbers;
+impl iter::Iterator for Numbers {
+    fn next(&mut self) -> Option<u32> { None }
+}
+//- /main.rs crate:main deps:dep
+fn main() {
+    let mut n = dep::Numbers;
+    n.next<|>
+}
+"#,
+            r#"
+use dep::iter::Iterator;
+
+fn main() {
+    let mut n = dep::Numbers;
+    n.next()$0
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_integer_methods_on_ambiguous_float_literal() {
+        check_edit(
+            "count_ones",
+            r#"
+#[lang = "i32"]
+impl i32 {
+    pub fn count_ones(self) -> u32 { 0 }
+}
+fn main() {
+    5.<|>
+}
+"#,
+            r#"
+#[lang = "i32"]
+impl i32 {
+    pub fn count_ones(self) -> u32 { 0 }
+}
+fn main() {
+    5.count_ones()$0
+}
+"#,
+        );
+        check_edit(
+            "count_ones",
+            r#"
+#[lang = "i32"]
+impl i32 {
+    pub fn count_ones(self) -> u32 { 0 }
+}
+fn main() {
+    5.co<|>
+}
+"#,
+            r#"
+#[lang = "i32"]
+impl i32 {
+    pub fn count_ones(self) -> u32 { 0 }
+}
+fn main() {
+    5.count_ones()$0
+}
+"#,
+        );
+    }
 }
@@ -0,0 +1,177 @@
+//! Completes inline captures of local variables in format strings, e.g.
+//! `format!("{na<|>}")`.
+
+use hir::{HirDisplay, ScopeDef};
+use syntax::{
+    ast::{self, AstToken},
+    AstNode, SyntaxKind, TextRange, TextSize,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_format_string(acc: &mut Completions, ctx: &CompletionContext) {
+    if !is_format_string(ctx) {
+        return;
+    }
+    let string = match ast::String::cast(ctx.original_token.clone()) {
+        Some(it) => it,
+        None => return,
+    };
+    let string_start = string.syntax().text_range().start();
+    let prefix = &string.text()[..usize::from(ctx.position.offset - string_start)];
+
+    let brace = match prefix.rfind('{') {
+        Some(it) => it,
+        None => return,
+    };
+    // `{{` is an escaped brace, not a placeholder.
+    let braces_before = prefix[..brace].chars().rev().take_while(|&c| c == '{').count();
+    if braces_before % 2 == 1 {
+        return;
+    }
+    if !prefix[brace + 1..].chars().all(|c| c.is_alphanumeric() || c == '_') {
+        return;
+    }
+
+    let range = TextRange::new(string_start + TextSize::of(&prefix[..=brace]), ctx.position.offset);
+    // The string itself isn't lowered to an expression and so has no scope of its own,
+    // but the expansion of `format_args!` lives in the scope of the macro call.
+    let format_args = match ctx.token.parent().parent().and_then(ast::MacroCall::cast) {
+        Some(it) => it,
+        None => return,
+    };
+    let expansion = match ctx.sema.expand(&format_args) {
+        Some(it) => it,
+        None => return,
+    };
+    let expr = match expansion.descendants().find_map(ast::Expr::cast) {
+        Some(it) => it,
+        None => return,
+    };
+    let scope = ctx.sema.scope(expr.syntax());
+    scope.process_all_names(&mut |name, def| {
+        let local = match def {
+            ScopeDef::Local(it) => it,
+            _ => return,
+        };
+        let ty = local.ty(ctx.db);
+        let mut item = CompletionItem::new(CompletionKind::Reference, range, name.to_string())
+            .kind(CompletionItemKind::Binding);
+        if !ty.is_unknown() {
+            item = item.detail(ty.display(ctx.db).to_string());
+        }
+        item.add_to(acc);
+    });
+}
+
+/// Whether the cursor is in the format string of a `format_args!` call, which
+/// is what all the formatting macros expand to.
+fn is_format_string(ctx: &CompletionContext) -> bool {
+    if ctx.token.kind() != SyntaxKind::STRING {
+        return false;
+    }
+    let token_tree = ctx.token.parent();
+    let is_format_args = token_tree
+        .parent()
+        .and_then(ast::MacroCall::cast)
+        .and_then(|it| it.path())
+        .and_then(|it| it.segment())
+        .and_then(|it| it.name_ref())
+        .map_or(false, |it| matches!(it.text().as_str(), "format_args" | "format_args_nl"));
+    let is_first_arg = token_tree
+        .children_with_tokens()
+        .filter(|it| it.kind() != SyntaxKind::WHITESPACE)
+        .nth(1)
+        .map_or(false, |it| it.as_token() == Some(&ctx.token));
+    is_format_args && is_first_arg
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    const FORMAT_MACROS: &str = r#"
+#[rustc_builtin_macro]
+macro_rules! format_args {
+    ($fmt:expr) => {{ /* compiler built-in */ }};
+    ($fmt:expr, $($args:tt)*) => {{ /* compiler built-in */ }};
+}
+macro_rules! format {
+    ($($arg:tt)*) => { format_args!($($arg)*) }
+}
+"#;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual =
+            completion_list(&format!("{}{}", FORMAT_MACROS, ra_fixture), CompletionKind::Reference);
+        expect.assert_eq(&actual)
+    }
+
+    #[test]
+    fn completes_locals_in_format_args() {
+        check_edit(
+            "name",
+            &format!(
+                r#"{}
+fn main() {{
+    let name = 92;
+    format!("{{}}", n<|>);
+}}
+"#,
+                FORMAT_MACROS
+            ),
+            &format!(
+                r#"{}
+fn main() {{
+    let name = 92;
+    format!("{{}}", name);
+}}
+"#,
+                FORMAT_MACROS
+            ),
+        );
+    }
+
+    #[test]
+    fn completes_inline_captures() {
+        check_edit(
+            "name",
+            &format!(
+                r#"{}
+fn main() {{
+    let name = 92;
+    format!("hello {{na<|>}}");
+}}
+"#,
+                FORMAT_MACROS
+            ),
+            &format!(
+                r#"{}
+fn main() {{
+    let name = 92;
+    format!("hello {{name}}");
+}}
+"#,
+                FORMAT_MACROS
+            ),
+        );
+    }
+
+    #[test]
+    fn no_inline_captures_after_escaped_brace() {
+        check(
+            r#"
+fn main() {
+    let name = 92;
+    format!("hello {{na<|>");
+}
+"#,
+            expect![[""]],
+        );
+    }
+}
@@ -1,6 +1,6 @@
 //! Completes keywords.
 
-use syntax::{ast, SyntaxKind};
+use syntax::{ast, match_ast, AstNode, SyntaxKind};
 use test_utils::mark;
 
 use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
@@ -19,14 +19,16 @@ pub(crate) fn complete_use_tree_keyword(acc: &mut Completions, ctx: &CompletionC
         CompletionItem::new(CompletionKind::Keyword, source_range, "self")
             .kind(CompletionItemKind::Keyword)
             .add_to(acc);
-        CompletionItem::new(CompletionKind::Keyword, source_range, "super::")
-            .kind(CompletionItemKind::Keyword)
-            .insert_text("super::")
-            .add_to(acc);
+        if ctx.path_qual.is_some() || !is_in_crate_root(ctx) {
+            CompletionItem::new(CompletionKind::Keyword, source_range, "super::")
+                .kind(CompletionItemKind::Keyword)
+                .insert_text("super::")
+                .add_to(acc);
+        }
     }
 
     // Suggest .await syntax for types that implement Future trait
-    if let Some(receiver) = &ctx.dot_receiver {
+    if let Some(receiver) = ctx.dot_receiver.as_ref().filter(|it| is_in_async_context(it)) {
         if let Some(ty) = ctx.sema.type_of_expr(receiver) {
             if ty.impls_future(ctx.db) {
                 CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), "await")
@@ -88,6 +90,11 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
         add_keyword(ctx, acc, "loop", "loop {$0}");
         add_keyword(ctx, acc, "if", "if ");
         add_keyword(ctx, acc, "if let", "if let ");
+        add_keyword(ctx, acc, "crate::", "crate::");
+        add_keyword(ctx, acc, "self::", "self::");
+        if !is_in_crate_root(ctx) {
+            add_keyword(ctx, acc, "super::", "super::");
+        }
     }
 
     if ctx.if_is_prev || ctx.block_expr_parent {
@@ -146,6 +153,40 @@ pub(crate) fn complete_expr_keyword(acc: &mut Completions, ctx: &CompletionConte
     acc.add_all(complete_return(ctx, &fn_def, ctx.can_be_stmt));
 }
 
+/// Checks whether `expr` is inside an `async` function, block or closure.
+fn is_in_async_context(expr: &ast::Expr) -> bool {
+    for node in expr.syntax().ancestors() {
+        match_ast! {
+            match node {
+                ast::EffectExpr(it) => {
+                    if it.async_token().is_some() {
+                        return true;
+                    }
+                },
+                ast::ClosureExpr(it) => return it.async_token().is_some(),
+                ast::Fn(it) => return it.async_token().is_some(),
+                _ => (),
+            }
+        }
+    }
+    false
+}
+
+/// `super` can't be used in the crate root, as it has no parent module.
+fn is_in_crate_root(ctx: &CompletionContext) -> bool {
+    ctx.scope.module().map_or(false, |module| module.parent(ctx.db).is_none())
+}
+
+pub(crate) fn complete_infer_type_placeholder(acc: &mut Completions, ctx: &CompletionContext) {
+    if !ctx.is_infer_type_allowed || ctx.path_qual.is_some() {
+        return;
+    }
+    CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), "_")
+        .kind(CompletionItemKind::Keyword)
+        .detail("infer type")
+        .add_to(acc);
+}
+
 fn keyword(ctx: &CompletionContext, kw: &str, snippet: &str) -> CompletionItem {
     let res = CompletionItem::new(CompletionKind::Keyword, ctx.source_range(), kw)
         .kind(CompletionItemKind::Keyword);
@@ -194,6 +235,14 @@ mod tests {
     fn test_keywords_in_use_stmt() {
         check(
             r"use <|>",
+            expect![[r#"
+                kw crate::
+                kw self
+            "#]],
+        );
+
+        check(
+            r"mod foo { use <|> }",
             expect![[r#"
                 kw crate::
                 kw self
@@ -223,21 +272,9 @@ mod tests {
         check(
             r"m<|>",
             expect![[r#"
-                kw fn
-                kw use
                 kw impl
-                kw trait
                 kw enum
-                kw struct
-                kw union
                 kw mod
-                kw const
-                kw type
-                kw static
-                kw extern
-                kw unsafe
-                kw pub(crate)
-                kw pub
             "#]],
         );
     }
@@ -256,6 +293,8 @@ mod tests {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw let
                 kw mod
                 kw const
@@ -268,6 +307,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_path_keywords_in_expression() {
+        check_edit(
+            "super::",
+            r"mod foo { fn quux() { <|> } }",
+            r"mod foo { fn quux() { super:: } }",
+        );
+        check_edit("crate::", r"fn quux() { <|> }", r"fn quux() { crate:: }");
+    }
+
+    #[test]
+    fn no_super_in_crate_root() {
+        let completions = completion_list(r"fn quux() { <|> }", CompletionKind::Keyword);
+        assert!(!completions.contains("super::"));
+    }
+
     #[test]
     fn test_keywords_inside_block() {
         check(
@@ -282,6 +337,8 @@ mod tests {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw let
                 kw mod
                 kw const
@@ -308,6 +365,8 @@ mod tests {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw let
                 kw else
                 kw else if
@@ -341,6 +400,8 @@ fn quux() -> i32 {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw unsafe
                 kw return
             "#]],
@@ -389,6 +450,8 @@ fn quux() -> i32 {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw let
                 kw mod
                 kw const
@@ -501,7 +564,7 @@ Some multi-line comment<|>
 use std::future::*;
 struct A {}
 impl Future for A {}
-fn foo(a: A) { a.<|> }
+async fn foo(a: A) { a.<|> }
 
 //- /std/lib.rs crate:std
 pub mod future {
@@ -518,7 +581,7 @@ pub mod future {
             r#"
 //- /main.rs crate:main deps:std
 use std::future::*;
-fn foo() {
+async fn foo() {
     let a = async {};
     a.<|>
 }
@@ -537,6 +600,26 @@ pub mod future {
         )
     }
 
+    #[test]
+    fn no_await_outside_async_context() {
+        check(
+            r#"
+//- /main.rs crate:main deps:std
+use std::future::*;
+struct A {}
+impl Future for A {}
+fn foo(a: A) { a.<|> }
+
+//- /std/lib.rs crate:std
+pub mod future {
+    #[lang = "future_trait"]
+    pub trait Future {}
+}
+"#,
+            expect![[""]],
+        );
+    }
+
     #[test]
     fn after_let() {
         check(
@@ -547,6 +630,8 @@ pub mod future {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
                 kw return
             "#]],
         )
@@ -605,8 +690,56 @@ fn foo() {
                 kw loop
                 kw if
                 kw if let
+                kw crate::
+                kw self::
+                kw return
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_infer_type_placeholder_in_let_annotation() {
+        check(
+            r#"fn foo() { let x: <|> = 92; }"#,
+            expect![[r#"
+                kw return
+                kw _      infer type
+            "#]],
+        );
+        check(
+            r#"fn foo() { let x: Vec<<|>> = Vec::new(); }"#,
+            expect![[r#"
+                kw return
+                kw _      infer type
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_infer_type_placeholder_in_turbofish() {
+        check(
+            r#"fn foo() { let x = bar::<<|>>(); }"#,
+            expect![[r#"
+                kw return
+                kw _      infer type
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_infer_type_placeholder_in_signatures_and_bounds() {
+        check(
+            r#"fn foo<T: <|>>() {}"#,
+            expect![[r#"
+                kw return
+            "#]],
+        );
+        check(
+            r#"fn foo(x: <|>) {}"#,
+            expect![[r#"
                 kw return
             "#]],
         );
+        check(r#"struct S { f: Vec<<|>> }"#, expect![[r#""#]]);
     }
 }
@@ -0,0 +1,151 @@
+//! Completes labels of enclosing loops after `break` and `continue`, e.g.
+//! `'outer: loop { break '<|> }`.
+
+use std::iter::successors;
+
+use rustc_hash::FxHashSet;
+use syntax::{
+    ast::{self, LoopBodyOwner},
+    match_ast, AstNode, TextRange, T,
+};
+
+use crate::{CompletionContext, CompletionItem, CompletionItemKind, CompletionKind, Completions};
+
+pub(crate) fn complete_label(acc: &mut Completions, ctx: &CompletionContext) {
+    let token = &ctx.original_token;
+    let range = if token.kind().is_trivia() {
+        TextRange::empty(ctx.position.offset)
+    } else if token.text().starts_with('\'') {
+        token.text_range()
+    } else {
+        return;
+    };
+    let prev_token =
+        successors(token.prev_token(), |it| it.prev_token()).find(|it| !it.kind().is_trivia());
+    if !matches!(prev_token.map(|it| it.kind()), Some(T![break]) | Some(T![continue])) {
+        return;
+    }
+
+    let mut seen = FxHashSet::default();
+    let loops = token
+        .parent()
+        .ancestors()
+        .take_while(|it| !ast::Fn::can_cast(it.kind()) && !ast::ClosureExpr::can_cast(it.kind()));
+    for node in loops {
+        let label = match_ast! {
+            match node {
+                ast::LoopExpr(it) => it.label(),
+                ast::WhileExpr(it) => it.label(),
+                ast::ForExpr(it) => it.label(),
+                _ => None,
+            }
+        };
+        let lifetime = match label.and_then(|it| it.lifetime()) {
+            Some(it) => it.syntax().text().to_string(),
+            None => continue,
+        };
+        // Inner labels shadow outer ones with the same name.
+        if seen.insert(lifetime.clone()) {
+            CompletionItem::new(CompletionKind::Label, range, lifetime)
+                .kind(CompletionItemKind::Label)
+                .add_to(acc);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let actual = completion_list(ra_fixture, CompletionKind::Label);
+        expect.assert_eq(&actual);
+    }
+
+    #[test]
+    fn completes_labels_of_nested_loops() {
+        check(
+            r#"
+fn main() {
+    'outer: loop {
+        'inner: while true {
+            break <|>
+        }
+    }
+}
+"#,
+            expect![[r#"
+                lb 'inner
+                lb 'outer
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_labels_after_continue() {
+        check(
+            r#"
+fn main() {
+    'outer: for _ in 0..10 {
+        loop {
+            continue '<|>
+        }
+    }
+}
+"#,
+            expect![[r#"
+                lb 'outer
+            "#]],
+        );
+        check_edit(
+            "'outer",
+            r#"
+fn main() {
+    'outer: loop {
+        loop { break 'ou<|> }
+    }
+}
+"#,
+            r#"
+fn main() {
+    'outer: loop {
+        loop { break 'outer }
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn does_not_complete_labels_outside_of_break() {
+        check(
+            r#"
+fn main() {
+    'outer: loop {
+        let x = <|>
+    }
+}
+"#,
+            expect![[""]],
+        );
+    }
+
+    #[test]
+    fn does_not_complete_labels_across_closures() {
+        check(
+            r#"
+fn main() {
+    'outer: loop {
+        let f = || loop { break <|> };
+    }
+}
+"#,
+            expect![[""]],
+        );
+    }
+}
@@ -2,6 +2,7 @@
 
 mod format_like;
 
+use hir::Type;
 use ide_db::ty_filter::TryEnum;
 use syntax::{
     ast::{self, AstNode, AstToken},
@@ -86,6 +87,13 @@ pub(crate) fn complete_postfix(acc: &mut Completions, ctx: &CompletionContext) {
                 .add_to(acc);
             }
         }
+
+        let propagates = enclosing_fn_ret_type(ctx, &dot_receiver)
+            .and_then(|ret_ty| TryEnum::from_propagation(&ctx.sema, &receiver_ty, &ret_ty));
+        if propagates.is_some() {
+            postfix_snippet(ctx, cap, &dot_receiver, "?", "expr?", &format!("{}?", receiver_text))
+                .add_to(acc);
+        }
     } else if receiver_ty.is_bool() || receiver_ty.is_unknown() {
         postfix_snippet(
             ctx,
@@ -261,6 +269,16 @@ fn get_receiver_text(receiver: &ast::Expr, receiver_is_ambiguous_float_literal:
     }
 }
 
+/// Returns the return type of the function containing `expr`.
+/// The `?` operator inside a closure applies to the closure, so those are skipped.
+fn enclosing_fn_ret_type(ctx: &CompletionContext, expr: &ast::Expr) -> Option<Type> {
+    if expr.syntax().ancestors().any(|it| ast::ClosureExpr::can_cast(it.kind())) {
+        return None;
+    }
+    let func = ctx.sema.to_def(ctx.function_syntax.as_ref()?)?;
+    Some(func.ret_type(ctx.db))
+}
+
 fn include_references(initial_element: &ast::Expr) -> ast::Expr {
     let mut resulting_element = initial_element.clone();
     while let Some(parent_ref_element) =
@@ -288,6 +306,7 @@ fn postfix_snippet(
     CompletionItem::new(CompletionKind::Postfix, ctx.source_range(), label)
         .detail(detail)
         .kind(CompletionItemKind::Snippet)
+        .set_demoted(ctx.config.demote_snippets)
         .snippet_edit(cap, edit)
 }
 
@@ -398,17 +417,7 @@ fn main() {
 }
 "#,
             expect![[r#"
-                sn if    if expr {}
                 sn while while expr {}
-                sn not   !expr
-                sn ref   &expr
-                sn refm  &mut expr
-                sn match match expr {}
-                sn box   Box::new(expr)
-                sn ok    Ok(expr)
-                sn some  Some(expr)
-                sn dbg   dbg!(expr)
-                sn dbgr  dbg!(&expr)
                 sn call  function(expr)
                 sn let   let
                 sn letm  let mut
@@ -467,6 +476,120 @@ fn main() {
         );
     }
 
+    #[test]
+    fn try_operator_in_result_fn() {
+        check(
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+
+fn foo() -> Result<u32, ()> { Result::Ok(0) }
+
+fn main() -> Result<(), ()> {
+    foo().<|>
+}
+"#,
+            expect![[r#"
+                sn ifl   if let Ok {}
+                sn while while let Ok {}
+                sn ?     expr?
+                sn ref   &expr
+                sn refm  &mut expr
+                sn match match expr {}
+                sn box   Box::new(expr)
+                sn ok    Ok(expr)
+                sn some  Some(expr)
+                sn dbg   dbg!(expr)
+                sn dbgr  dbg!(&expr)
+                sn call  function(expr)
+                sn let   let
+                sn letm  let mut
+            "#]],
+        );
+        check_edit(
+            "?",
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+
+fn foo() -> Result<u32, ()> { Result::Ok(0) }
+
+fn main() -> Result<(), ()> {
+    let x = foo().<|>;
+}
+"#,
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+
+fn foo() -> Result<u32, ()> { Result::Ok(0) }
+
+fn main() -> Result<(), ()> {
+    let x = foo()?;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_try_operator_in_incompatible_fn() {
+        check(
+            r#"
+enum Option<T> { Some(T), None }
+
+fn foo() -> Option<u32> { Option::None }
+
+fn main() {
+    foo().<|>
+}
+"#,
+            expect![[r#"
+                sn ifl   if let Some {}
+                sn while while let Some {}
+                sn ref   &expr
+                sn refm  &mut expr
+                sn match match expr {}
+                sn box   Box::new(expr)
+                sn ok    Ok(expr)
+                sn some  Some(expr)
+                sn dbg   dbg!(expr)
+                sn dbgr  dbg!(&expr)
+                sn call  function(expr)
+                sn let   let
+                sn letm  let mut
+            "#]],
+        );
+    }
+
+    #[test]
+    fn no_try_operator_for_mismatched_error_type() {
+        check(
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+struct E1;
+struct E2;
+
+fn foo() -> Result<u32, E1> { Result::Ok(0) }
+
+fn main() -> Result<(), E2> {
+    foo().<|>
+}
+"#,
+            expect![[r#"
+                sn ifl   if let Ok {}
+                sn while while let Ok {}
+                sn ref   &expr
+                sn refm  &mut expr
+                sn match match expr {}
+                sn box   Box::new(expr)
+                sn ok    Ok(expr)
+                sn some  Some(expr)
+                sn dbg   dbg!(expr)
+                sn dbgr  dbg!(&expr)
+                sn call  function(expr)
+                sn let   let
+                sn letm  let mut
+            "#]],
+        );
+    }
+
     #[test]
     fn postfix_completion_works_for_ambiguous_float_literal() {
         check_edit("refm", r#"fn main() { 42.<|> }"#, r#"fn main() { &mut 42 }"#)
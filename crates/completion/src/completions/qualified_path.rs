@@ -32,7 +32,13 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
 
     match resolution {
         PathResolution::Def(hir::ModuleDef::Module(module)) => {
-            let module_scope = module.scope(ctx.db, context_module);
+            let visible_scope = module.scope(ctx.db, context_module);
+            let module_scope = if ctx.config.demote_private_items {
+                module.scope(ctx.db, None)
+            } else {
+                visible_scope.clone()
+            };
+            let visible_scope: FxHashSet<_> = visible_scope.into_iter().collect();
             for (name, def) in module_scope {
                 if ctx.use_item_syntax.is_some() {
                     if let ScopeDef::Unknown = def {
@@ -46,7 +52,11 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                     }
                 }
 
-                acc.add_resolution(ctx, name.to_string(), &def);
+                if visible_scope.contains(&(name.clone(), def)) {
+                    acc.add_resolution(ctx, name.to_string(), &def);
+                } else {
+                    acc.add_private_resolution(ctx, name.to_string(), &def);
+                }
             }
         }
         PathResolution::Def(def @ hir::ModuleDef::Adt(_))
@@ -95,6 +105,25 @@ pub(crate) fn complete_qualified_path(acc: &mut Completions, ctx: &CompletionCon
                 });
             }
         }
+        PathResolution::Def(hir::ModuleDef::BuiltinType(builtin)) => {
+            if let Some(krate) = ctx.krate {
+                let ty = hir::Type::from_builtin(ctx.db, krate, builtin);
+                let traits_in_scope = ctx.scope.traits_in_scope();
+                ty.iterate_path_candidates(ctx.db, krate, &traits_in_scope, None, |_ty, item| {
+                    if context_module.map_or(false, |m| !item.is_visible_from(ctx.db, m)) {
+                        return None;
+                    }
+                    match item {
+                        hir::AssocItem::Function(func) => {
+                            acc.add_function(ctx, func, None);
+                        }
+                        hir::AssocItem::Const(ct) => acc.add_const(ctx, ct),
+                        hir::AssocItem::TypeAlias(ty) => acc.add_type_alias(ctx, ty),
+                    }
+                    None::<()>
+                });
+            }
+        }
         PathResolution::Def(hir::ModuleDef::Trait(t)) => {
             // Handles `Trait::assoc` as well as `<Ty as Trait>::assoc`.
             for item in t.items(ctx.db) {
@@ -156,8 +185,8 @@ mod tests {
     use test_utils::mark;
 
     use crate::{
-        test_utils::{check_edit, completion_list},
-        CompletionKind,
+        test_utils::{check_edit, completion_list, get_all_items},
+        CompletionConfig, CompletionKind,
     };
 
     fn check(ra_fixture: &str, expect: Expect) {
@@ -181,7 +210,7 @@ mod tests {
         check(
             r#"
 mod foo { pub struct S; }
-use self::{foo::*, bar<|>};
+use self::{foo::*, <|>};
 "#,
             expect![[r#"
                 st S
@@ -283,6 +312,22 @@ mod foo;
 struct Spam;
 //- /foo.rs
 use crate::Sp<|>
+"#,
+            expect![[r#"
+                st Spam
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_crate_root_items_in_expression() {
+        check(
+            r#"
+//- /lib.rs
+mod foo;
+pub struct Spam;
+//- /foo.rs
+fn bar() { let _ = crate::<|> }
 "#,
             expect![[r#"
                 md foo
@@ -302,7 +347,6 @@ struct Spam;
 use crate::{Sp<|>};
 "#,
             expect![[r#"
-                md foo
                 st Spam
             "#]],
         );
@@ -367,6 +411,51 @@ fn foo() { let _ = S::<|> }
         );
     }
 
+    #[test]
+    fn completes_associated_functions_with_call_snippets() {
+        check(
+            r#"
+struct Vec<T>(T);
+
+impl<T> Vec<T> {
+    pub fn new() -> Vec<T> { loop {} }
+    pub fn with_capacity(capacity: usize) -> Vec<T> { loop {} }
+    pub fn len(&self) -> usize { 0 }
+}
+
+fn foo() { let _ = Vec::<|> }
+"#,
+            expect![[r#"
+                fn new()            pub fn new() -> Vec<T>
+                fn with_capacity(…) pub fn with_capacity(capacity: usize) -> Vec<T>
+                me len(…)           pub fn len(&self) -> usize
+            "#]],
+        );
+        check_edit(
+            "with_capacity",
+            r#"
+struct Vec<T>(T);
+
+impl<T> Vec<T> {
+    pub fn new() -> Vec<T> { loop {} }
+    pub fn with_capacity(capacity: usize) -> Vec<T> { loop {} }
+}
+
+fn foo() { let _ = Vec::<|> }
+"#,
+            r#"
+struct Vec<T>(T);
+
+impl<T> Vec<T> {
+    pub fn new() -> Vec<T> { loop {} }
+    pub fn with_capacity(capacity: usize) -> Vec<T> { loop {} }
+}
+
+fn foo() { let _ = Vec::with_capacity(${1:capacity})$0 }
+"#,
+        );
+    }
+
     #[test]
     fn associated_item_visibility() {
         check(
@@ -394,6 +483,50 @@ fn foo() { let _ = S::<|> }
         );
     }
 
+    #[test]
+    fn cfg_disabled_associated_items_are_not_completed() {
+        check(
+            r#"
+struct S;
+
+impl S {
+    fn a() {}
+    #[cfg(test)]
+    fn test_helper() {}
+    #[cfg(test)]
+    const TEST_CONST: i32 = 42;
+    #[cfg(test)]
+    type TestType = i32;
+}
+
+fn foo() { let _ = S::<|> }
+"#,
+            expect![[r#"
+                fn a() fn a()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_builtin_type_associated_items() {
+        check(
+            r#"
+//- /main.rs crate:main deps:core
+fn foo() { let _ = i32::<|> }
+//- /libcore.rs crate:core
+#[lang = "i32"]
+impl i32 {
+    pub const MAX: i32 = 2147483647;
+    pub fn max_value() -> i32 { Self::MAX }
+}
+"#,
+            expect![[r#"
+                ct MAX         pub const MAX: i32 = 2147483647;
+                fn max_value() pub fn max_value() -> i32
+            "#]],
+        );
+    }
+
     #[test]
     fn completes_enum_associated_method() {
         check(
@@ -681,8 +814,7 @@ fn main() { m!(self::f<|>); }
 fn foo() {}
 "#,
             expect![[r#"
-                fn main() fn main()
-                fn foo()  fn foo()
+                fn foo() fn foo()
             "#]],
         );
     }
@@ -753,7 +885,6 @@ fn main() {
 }
 "#,
             expect![[r#"
-                fn main() fn main()
                 fn foo(…) fn foo(a: i32, b: i32)
             "#]],
         );
@@ -781,4 +912,72 @@ impl Foo {
             "#]],
         );
     }
+
+    #[test]
+    fn completes_self_struct() {
+        check(
+            r#"
+struct Foo { x: u32 }
+
+impl Foo {
+    const ZERO: u32 = 0;
+    fn new() -> Self { Foo { x: 0 } }
+    fn get(&self) -> u32 { self.x }
+}
+
+impl Foo {
+    fn make() -> Self {
+        Self::<|>
+    }
+}
+"#,
+            expect![[r#"
+                ct ZERO   const ZERO: u32 = 0;
+                fn new()  fn new() -> Self
+                me get(…) fn get(&self) -> u32
+                fn make() fn make() -> Self
+            "#]],
+        );
+    }
+
+    #[test]
+    fn demotes_private_items_when_configured() {
+        let fixture = r#"
+mod foo {
+    pub struct Public;
+    struct Private;
+}
+fn main() { foo::<|> }
+"#;
+        check(
+            fixture,
+            expect![[r#"
+                st Public
+            "#]],
+        );
+
+        let config = CompletionConfig { demote_private_items: true, ..CompletionConfig::default() };
+        let completions = get_all_items(config, fixture);
+        let relevance =
+            |label: &str| completions.iter().find(|it| it.label() == label).unwrap().relevance();
+        assert!(relevance("Private") < relevance("Public"));
+    }
+
+    #[test]
+    fn private_prefix_matches_rank_below_visible_fuzzy_matches() {
+        let config = CompletionConfig { demote_private_items: true, ..CompletionConfig::default() };
+        let completions = get_all_items(
+            config,
+            r#"
+mod foo {
+    pub struct Sheep;
+    struct Helper;
+}
+fn main() { foo::He<|> }
+"#,
+        );
+        let relevance =
+            |label: &str| completions.iter().find(|it| it.label() == label).unwrap().relevance();
+        assert!(relevance("Helper") < relevance("Sheep"));
+    }
 }
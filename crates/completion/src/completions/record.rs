@@ -1,4 +1,5 @@
 //! Complete fields in record literals and patterns.
+use hir::HirDisplay;
 use ide_db::helpers::FamousDefs;
 use syntax::ast::Expr;
 
@@ -19,6 +20,18 @@ pub(crate) fn complete_record(acc: &mut Completions, ctx: &CompletionContext) ->
                 .unwrap_or(false);
 
             let missing_fields = ctx.sema.record_literal_missing_fields(record_lit);
+            // Locals named like a field can be used with the field init shorthand, `S { x }`.
+            for (field, _) in &missing_fields {
+                let name = field.name(ctx.db).to_string();
+                if let Some((_, local)) = ctx.locals.iter().find(|(it, _)| *it == name) {
+                    acc.add(
+                        CompletionItem::new(CompletionKind::Reference, ctx.source_range(), name)
+                            .kind(CompletionItemKind::Binding)
+                            .detail(local.ty(ctx.db).display(ctx.db).to_string())
+                            .build(),
+                    );
+                }
+            }
             if impl_default_trait && !missing_fields.is_empty() {
                 acc.add(
                     CompletionItem::new(
@@ -224,6 +237,23 @@ fn foo() {
         );
     }
 
+    #[test]
+    fn test_record_literal_field_shorthand() {
+        check(
+            r#"
+struct S { x: u32, y: u32 }
+fn foo(x: u32) {
+    let _ = S { <|> };
+}
+"#,
+            expect![[r#"
+                bn x u32
+                fd x u32
+                fd y u32
+            "#]],
+        );
+    }
+
     #[test]
     fn test_record_literal_enum_variant() {
         check(
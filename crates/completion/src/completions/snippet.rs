@@ -1,5 +1,10 @@
 //! This file provides snippet completions, like `pd` => `eprintln!(...)`.
 
+use hir::{known, AssocItem, Attrs, CfgAtom};
+use ide_db::helpers::FamousDefs;
+use stdx::to_lower_snake_case;
+use syntax::{ast, AstNode};
+
 use crate::{
     config::SnippetCap, item::Builder, CompletionContext, CompletionItem, CompletionItemKind,
     CompletionKind, Completions,
@@ -9,6 +14,7 @@ fn snippet(ctx: &CompletionContext, cap: SnippetCap, label: &str, snippet: &str)
     CompletionItem::new(CompletionKind::Snippet, ctx.source_range(), label)
         .insert_snippet(cap, snippet)
         .kind(CompletionItemKind::Snippet)
+        .set_demoted(ctx.config.demote_snippets)
 }
 
 pub(crate) fn complete_expr_snippet(acc: &mut Completions, ctx: &CompletionContext) {
@@ -22,6 +28,63 @@ pub(crate) fn complete_expr_snippet(acc: &mut Completions, ctx: &CompletionConte
 
     snippet(ctx, cap, "pd", "eprintln!(\"$0 = {:?}\", $0);").add_to(acc);
     snippet(ctx, cap, "ppd", "eprintln!(\"$0 = {:#?}\", $0);").add_to(acc);
+
+    if ctx.expects_bool {
+        snippet(ctx, cap, "matches!", "matches!(${1:expr}, ${2:pattern})").add_to(acc);
+    }
+
+    if let Some(param) = closure_param_name(ctx) {
+        snippet(ctx, cap, &format!("|{}|", param), &format!("|${{1:{}}}| ${{0}}", param))
+            .add_to(acc);
+    }
+}
+
+/// If the cursor is at an argument that takes a closure, returns a name for the closure's
+/// parameter. Iterator adapters get a name derived from the item type.
+fn closure_param_name(ctx: &CompletionContext) -> Option<String> {
+    let arg_list = ctx.token.ancestors().find_map(ast::ArgList::cast)?;
+    let offset = ctx.position.offset;
+    let current_arg =
+        arg_list.args().find(|it| it.syntax().text_range().contains_inclusive(offset));
+    if !matches!(current_arg, None | Some(ast::Expr::PathExpr(_))) {
+        return None;
+    }
+    let idx = arg_list.args().take_while(|it| it.syntax().text_range().end() < offset).count();
+
+    let call = arg_list.syntax().parent()?;
+    let (params, receiver) = if let Some(call) = ast::MethodCallExpr::cast(call.clone()) {
+        let func = ctx.sema.resolve_method_call(&call)?;
+        (func.method_params(ctx.db)?, call.receiver())
+    } else {
+        let call = ast::CallExpr::cast(call)?;
+        let path = match call.expr()? {
+            ast::Expr::PathExpr(it) => it.path()?,
+            _ => return None,
+        };
+        match ctx.sema.resolve_path(&path)? {
+            hir::PathResolution::Def(hir::ModuleDef::Function(func)) => {
+                (func.assoc_fn_params(ctx.db), None)
+            }
+            _ => return None,
+        }
+    };
+    let ty = params.get(idx)?.ty();
+    if !ty.impls_fnonce(ctx.db) && ty.as_callable(ctx.db).is_none() {
+        return None;
+    }
+
+    let item_name = receiver.and_then(|receiver| {
+        let receiver_ty = ctx.sema.type_of_expr(&receiver)?;
+        let iter_trait = FamousDefs(&ctx.sema, ctx.krate).core_iter_Iterator()?;
+        let item = iter_trait.items(ctx.db).into_iter().find_map(|it| match it {
+            AssocItem::TypeAlias(alias) if alias.name(ctx.db) == known::Item => Some(alias),
+            _ => None,
+        })?;
+        let item_ty = receiver_ty.normalize_trait_assoc_type(ctx.db, iter_trait, &[], item)?;
+        let adt = item_ty.remove_ref().unwrap_or(item_ty).as_adt()?;
+        Some(to_lower_snake_case(&adt.name(ctx.db).to_string()))
+    });
+    Some(item_name.unwrap_or_else(|| "x".to_string()))
 }
 
 pub(crate) fn complete_item_snippet(acc: &mut Completions, ctx: &CompletionContext) {
@@ -33,11 +96,12 @@ pub(crate) fn complete_item_snippet(acc: &mut Completions, ctx: &CompletionConte
         None => return,
     };
 
-    snippet(
-        ctx,
-        cap,
-        "tmod (Test module)",
-        "\
+    if !is_in_test_module(ctx) {
+        snippet(
+            ctx,
+            cap,
+            "tmod (Test module)",
+            "\
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -47,9 +111,10 @@ mod tests {
         $0
     }
 }",
-    )
-    .lookup_by("tmod")
-    .add_to(acc);
+        )
+        .lookup_by("tmod")
+        .add_to(acc);
+    }
 
     snippet(
         ctx,
@@ -57,7 +122,7 @@ mod tests {
         "tfn (Test function)",
         "\
 #[test]
-fn ${1:feature}() {
+fn ${1:name}() {
     $0
 }",
     )
@@ -67,11 +132,32 @@ fn ${1:feature}() {
     snippet(ctx, cap, "macro_rules", "macro_rules! $1 {\n\t($2) => {\n\t\t$0\n\t};\n}").add_to(acc);
 }
 
+/// Whether the cursor is inside a `mod` which only exists with `cfg(test)`.
+fn is_in_test_module(ctx: &CompletionContext) -> bool {
+    ctx.token.parent().ancestors().filter_map(ast::Module::cast).any(|module| {
+        let cfg = match Attrs::from_syntax(&module).cfg() {
+            Some(it) => it,
+            None => return false,
+        };
+        let enabled = |test: bool, others: bool| {
+            cfg.fold(&|atom| match atom {
+                CfgAtom::Flag(flag) if flag == "test" => test,
+                _ => others,
+            })
+        };
+        // Disabled without `test`, no matter what the other options are.
+        enabled(false, false) == Some(false) && enabled(false, true) == Some(false)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
 
-    use crate::{test_utils::completion_list, CompletionKind};
+    use crate::{
+        test_utils::{check_edit, completion_list},
+        CompletionKind,
+    };
 
     fn check(ra_fixture: &str, expect: Expect) {
         let actual = completion_list(ra_fixture, CompletionKind::Snippet);
@@ -89,6 +175,121 @@ mod tests {
         );
     }
 
+    #[test]
+    fn completes_matches_in_bool_positions() {
+        check(
+            r#"fn foo(x: Option<i32>) { if <|> {} }"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn matches!
+            "#]],
+        );
+        check(
+            r#"fn foo(x: Option<i32>) -> bool { true && !<|> }"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn matches!
+            "#]],
+        );
+        check_edit(
+            "matches!",
+            r#"fn foo(x: Option<i32>) { if <|> {} }"#,
+            r#"fn foo(x: Option<i32>) { if matches!(${1:expr}, ${2:pattern}) {} }"#,
+        );
+    }
+
+    #[test]
+    fn does_not_complete_matches_outside_bool_positions() {
+        check(
+            r#"fn foo(x: i32) { let y = <|>; }"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_closure_for_fn_arguments() {
+        let fixture = r#"
+//- /main.rs crate:main deps:core
+struct Point;
+struct Points;
+impl core::iter::Iterator for Points {
+    type Item = Point;
+    fn next(&mut self) -> Point { Point }
+}
+fn foo(points: Points) {
+    points.map(<|>);
+}
+//- /libcore.rs crate:core
+pub mod ops {
+    #[lang = "fn_once"]
+    pub trait FnOnce<Args> {
+        type Output;
+    }
+    #[lang = "fn_mut"]
+    pub trait FnMut<Args>: FnOnce<Args> {}
+}
+pub mod iter {
+    pub use self::traits::iterator::Iterator;
+    mod traits {
+        pub(crate) mod iterator {
+            pub trait Iterator {
+                type Item;
+                fn next(&mut self) -> Self::Item;
+                fn map<B, F: crate::ops::FnMut(Self::Item) -> B>(self, f: F) -> B { loop {} }
+            }
+        }
+    }
+}
+pub mod prelude {
+    pub use crate::iter::Iterator;
+}
+#[prelude_import]
+pub use prelude::*;
+"#;
+        check(
+            fixture,
+            expect![[r#"
+                sn pd
+                sn ppd
+                sn |point|
+            "#]],
+        );
+        check_edit(
+            "|point|",
+            fixture,
+            r#"
+struct Point;
+struct Points;
+impl core::iter::Iterator for Points {
+    type Item = Point;
+    fn next(&mut self) -> Point { Point }
+}
+fn foo(points: Points) {
+    points.map(|${1:point}| ${0});
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn no_closure_for_other_arguments() {
+        check(
+            r#"
+fn apply(n: i32) {}
+fn foo() { apply(<|>) }
+"#,
+            expect![[r#"
+                sn pd
+                sn ppd
+            "#]],
+        );
+    }
+
     #[test]
     fn should_not_complete_snippets_in_path() {
         check(r#"fn foo(x: i32) { ::foo<|> }"#, expect![[""]]);
@@ -99,16 +300,60 @@ mod tests {
     fn completes_snippets_in_items() {
         check(
             r#"
+mod foo {
+    <|>
+}
+"#,
+            expect![[r#"
+                sn tmod (Test module)
+                sn tfn (Test function)
+                sn macro_rules
+            "#]],
+        )
+    }
+
+    #[test]
+    fn completes_test_fn_snippet_in_test_module() {
+        check(
+            r#"
 #[cfg(test)]
 mod tests {
     <|>
 }
 "#,
             expect![[r#"
-                sn tmod (Test module)
                 sn tfn (Test function)
                 sn macro_rules
             "#]],
         )
     }
+
+    #[test]
+    fn detects_test_module_from_parsed_cfg() {
+        check(
+            r#"
+#[cfg(all(unix, test))]
+mod tests {
+    <|>
+}
+"#,
+            expect![[r#"
+                sn tfn (Test function)
+                sn macro_rules
+            "#]],
+        );
+        check(
+            r#"
+#[cfg(not(test))]
+mod tests {
+    <|>
+}
+"#,
+            expect![[r#"
+                sn tmod (Test module)
+                sn tfn (Test function)
+                sn macro_rules
+            "#]],
+        );
+    }
 }
@@ -34,7 +34,7 @@
 use hir::{self, HasAttrs, HasSource};
 use ide_db::traits::get_missing_assoc_items;
 use syntax::{
-    ast::{self, edit, Impl},
+    ast::{self, edit, Impl, TypeBoundsOwner},
     display::function_declaration,
     AstNode, SyntaxKind, SyntaxNode, TextRange, T,
 };
@@ -183,10 +183,17 @@ fn add_type_alias_impl(
 
     let range = TextRange::new(type_def_node.text_range().start(), ctx.source_range().end());
 
+    let detail = type_alias
+        .source(ctx.db)
+        .value
+        .type_bound_list()
+        .map(|bounds| format!("type {}: {}", alias_name, bounds));
+
     CompletionItem::new(CompletionKind::Magic, ctx.source_range(), snippet.clone())
         .text_edit(TextEdit::replace(range, snippet))
         .lookup_by(alias_name)
         .kind(CompletionItemKind::TypeAlias)
+        .set_detail(detail)
         .set_documentation(type_alias.docs(ctx.db))
         .add_to(acc);
 }
@@ -624,6 +631,24 @@ impl Test for () {
         );
     }
 
+    #[test]
+    fn associated_type_with_bounds() {
+        check(
+            r#"
+trait Test {
+    type Item: Clone + Default;
+}
+
+impl Test for () {
+    t<|>
+}
+"#,
+            expect![["
+ta type Item =  type Item: Clone + Default
+"]],
+        );
+    }
+
     #[test]
     fn associated_const() {
         check_edit(
@@ -6,10 +6,12 @@ use either::Either;
 use hir::{Adt, ModPath, ModuleDef, ScopeDef, Type};
 use ide_db::helpers::insert_use::ImportScope;
 use ide_db::imports_locator;
+use rustc_hash::FxHashSet;
 use syntax::AstNode;
 use test_utils::mark;
 
 use crate::{
+    matcher::{fuzzy_match, Match},
     render::{render_resolution_with_import, RenderContext},
     CompletionContext, Completions, ImportEdit,
 };
@@ -34,6 +36,9 @@ pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionC
         return;
     }
 
+    // The same definition can be reachable under the same name several times,
+    // e.g. through an explicit import and through the prelude.
+    let mut seen = FxHashSet::default();
     ctx.scope.process_all_names(&mut |name, res| {
         if ctx.use_item_syntax.is_some() {
             if let (ScopeDef::Unknown, Some(name_ref)) = (&res, &ctx.name_ref_syntax) {
@@ -43,6 +48,24 @@ pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionC
                 }
             }
         }
+        if ctx.is_const_context {
+            if let ScopeDef::ModuleDef(ModuleDef::Function(func)) = &res {
+                if !func.is_const(ctx.db) {
+                    return;
+                }
+            }
+        }
+        if ctx.is_turbofish && !is_type(&res) {
+            return;
+        }
+        if let ScopeDef::ModuleDef(def) = res {
+            if ctx.defined_item == Some(def) {
+                return;
+            }
+        }
+        if !seen.insert((name.to_string(), res)) {
+            return;
+        }
         acc.add_resolution(ctx, name.to_string(), &res)
     });
 
@@ -51,6 +74,20 @@ pub(crate) fn complete_unqualified_path(acc: &mut Completions, ctx: &CompletionC
     }
 }
 
+/// Whether `def` can be used as a type, or as the qualifier of one.
+fn is_type(def: &ScopeDef) -> bool {
+    matches!(
+        def,
+        ScopeDef::ModuleDef(ModuleDef::Adt(_))
+            | ScopeDef::ModuleDef(ModuleDef::TypeAlias(_))
+            | ScopeDef::ModuleDef(ModuleDef::BuiltinType(_))
+            | ScopeDef::ModuleDef(ModuleDef::Module(_))
+            | ScopeDef::GenericParam(_)
+            | ScopeDef::ImplSelfType(_)
+            | ScopeDef::AdtSelfType(_)
+    )
+}
+
 fn complete_enum_variants(acc: &mut Completions, ctx: &CompletionContext, ty: &Type) {
     if let Some(Adt::Enum(enum_data)) =
         iter::successors(Some(ty.clone()), |ty| ty.remove_ref()).last().and_then(|ty| ty.as_adt())
@@ -70,7 +107,8 @@ fn complete_enum_variants(acc: &mut Completions, ctx: &CompletionContext, ty: &T
                 // Variants with trivial paths are already added by the existing completion logic,
                 // so we should avoid adding these twice
                 if path.segments.len() > 1 {
-                    acc.add_qualified_enum_variant(ctx, variant, path);
+                    acc.add_qualified_enum_variant(ctx, variant, path.clone());
+                    acc.add_enum_variant_by_name(ctx, variant, path);
                 }
             }
         }
@@ -81,7 +119,7 @@ fn complete_enum_variants(acc: &mut Completions, ctx: &CompletionContext, ty: &T
 //
 // When completing names in the current scope, proposes additional imports from other modules or crates,
 // if they can be qualified in the scope and their name contains all symbols from the completion input
-// (case-insensitive, in any order or places).
+// (case-insensitive, in the same order), e.g. `fdb` proposes `find_db`.
 //
 // ```
 // fn main() {
@@ -148,14 +186,15 @@ fn fuzzy_completion(acc: &mut Completions, ctx: &CompletionContext) -> Option<()
         })
     })
     .filter(|(mod_path, _)| mod_path.len() > 1)
+    .filter_map(|(mod_path, definition)| {
+        let order_key = compute_fuzzy_completion_order_key(&mod_path, &potential_import_name)?;
+        Some((order_key, mod_path, definition))
+    })
     .collect::<Vec<_>>();
 
-    let user_input_lowercased = potential_import_name.to_lowercase();
-    all_mod_paths.sort_by_cached_key(|(mod_path, _)| {
-        compute_fuzzy_completion_order_key(mod_path, &user_input_lowercased)
-    });
+    all_mod_paths.sort_by_key(|(order_key, ..)| *order_key);
 
-    acc.add_all(all_mod_paths.into_iter().filter_map(|(import_path, definition)| {
+    acc.add_all(all_mod_paths.into_iter().filter_map(|(_, import_path, definition)| {
         render_resolution_with_import(
             RenderContext::new(ctx),
             ImportEdit { import_path, import_scope: import_scope.clone() },
@@ -167,17 +206,11 @@ fn fuzzy_completion(acc: &mut Completions, ctx: &CompletionContext) -> Option<()
 
 fn compute_fuzzy_completion_order_key(
     proposed_mod_path: &ModPath,
-    user_input_lowercased: &str,
-) -> usize {
+    user_input: &str,
+) -> Option<Match> {
     mark::hit!(certain_fuzzy_order_test);
-    let proposed_import_name = match proposed_mod_path.segments.last() {
-        Some(name) => name.to_string().to_lowercase(),
-        None => return usize::MAX,
-    };
-    match proposed_import_name.match_indices(user_input_lowercased).next() {
-        Some((first_matching_index, _)) => first_matching_index,
-        None => usize::MAX,
-    }
+    let proposed_import_name = proposed_mod_path.segments.last()?.to_string();
+    fuzzy_match(user_input, &proposed_import_name)
 }
 
 #[cfg(test)]
@@ -186,7 +219,9 @@ mod tests {
     use test_utils::mark;
 
     use crate::{
-        test_utils::{check_edit, check_edit_with_config, completion_list_with_config},
+        test_utils::{
+            check_edit, check_edit_with_config, completion_list_with_config, do_completion,
+        },
         CompletionConfig, CompletionKind,
     };
 
@@ -213,10 +248,10 @@ mod tests {
         check(
             r#"
 use foo<|>
-use std::collections;
+use std::foo_bar;
 "#,
             expect![[r#"
-                ?? collections
+                ?? foo_bar
             "#]],
         );
     }
@@ -311,6 +346,104 @@ fn quux() {
         );
     }
 
+    #[test]
+    fn hides_non_const_fns_in_const_initializer() {
+        check(
+            r#"
+const fn answer() -> i32 { 42 }
+fn random() -> i32 { 4 }
+const _: i32 = <|>;
+"#,
+            expect![[r#"
+                fn answer() const fn answer() -> i32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn hides_non_const_fns_in_const_fn() {
+        check(
+            r#"
+fn random() -> i32 { 4 }
+const fn answer() -> i32 { <|> }
+"#,
+            expect![[r#"
+                fn answer() const fn answer() -> i32
+            "#]],
+        );
+    }
+
+    #[test]
+    fn does_not_complete_static_in_its_own_initializer() {
+        check(
+            r#"
+static ANSWER: i32 = 42;
+static FOO_BAR: i32 = <|>;
+"#,
+            expect![[r#"
+                sc ANSWER
+            "#]],
+        );
+    }
+
+    #[test]
+    fn does_not_complete_const_in_its_own_initializer() {
+        check(
+            r#"
+const ANSWER: i32 = 42;
+const FOO_BAR: i32 = <|>;
+"#,
+            expect![[r#"
+                ct ANSWER
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_fn_in_its_own_body() {
+        check(
+            r#"fn foo_bar() { foo_<|> }"#,
+            expect![[r#"
+                fn foo_bar() fn foo_bar()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_types_in_turbofish() {
+        check(
+            r#"
+struct Vec;
+fn make<T>() {}
+fn main() {
+    let x = 92;
+    make::<<|>>();
+}
+"#,
+            expect![[r#"
+                st Vec
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_types_in_method_turbofish() {
+        check(
+            r#"
+struct Iter;
+impl Iter {
+    fn collect<B>(self) {}
+}
+fn main() {
+    Iter.collect::<<|>>();
+}
+"#,
+            expect![[r#"
+                st Iter
+            "#]],
+        );
+    }
+
     #[test]
     fn completes_bindings_from_for() {
         check(
@@ -516,6 +649,25 @@ mod prelude { struct Option; }
         );
     }
 
+    #[test]
+    fn does_not_duplicate_explicitly_imported_prelude_items() {
+        let completions = do_completion(
+            r#"
+//- /main.rs crate:main deps:std
+use std::prelude::Option;
+fn foo() { let x: <|> }
+
+//- /std/lib.rs crate:std
+#[prelude_import]
+use prelude::*;
+
+pub mod prelude { pub struct Option; }
+"#,
+            CompletionKind::Reference,
+        );
+        assert_eq!(completions.iter().filter(|it| it.label() == "Option").count(), 1);
+    }
+
     #[test]
     fn completes_std_prelude_if_core_is_defined() {
         check(
@@ -629,7 +781,6 @@ fn main() {
 "#,
             expect![[r#"
                 fn frobnicate() fn frobnicate()
-                fn main()       fn main()
             "#]],
         );
     }
@@ -664,10 +815,8 @@ fn quux(x: i32) {
 }
 ",
             expect![[r#"
-                bn y       i32
                 bn x       i32
                 fn quux(…) fn quux(x: i32)
-                ma m!(…)   macro_rules! m
             "#]],
         );
     }
@@ -683,10 +832,8 @@ fn quux(x: i32) {
 }
 "#,
             expect![[r#"
-                bn y       i32
                 bn x       i32
                 fn quux(…) fn quux(x: i32)
-                ma m!(…)   macro_rules! m
             "#]],
         );
     }
@@ -718,10 +865,8 @@ fn main() {
 }
 "#,
             expect![[r#"
-                ev Foo::Bar  ()
-                ev Foo::Baz  ()
                 ev Foo::Quux ()
-                en Foo
+                ev Quux      ()
             "#]],
         )
     }
@@ -738,10 +883,8 @@ fn main() {
 }
 "#,
             expect![[r#"
-                ev Foo::Bar  ()
-                ev Foo::Baz  ()
                 ev Foo::Quux ()
-                en Foo
+                ev Quux      ()
             "#]],
         )
     }
@@ -756,14 +899,50 @@ fn main() {
     let foo = Foo::Quux;
     if let Qu<|> = foo { }
 }
+"#,
+            expect![[r#"
+                ev Foo::Quux ()
+                ev Quux      ()
+            "#]],
+        )
+    }
+
+    #[test]
+    fn completes_enum_variant_in_empty_iflet_pattern() {
+        check(
+            r#"
+enum Foo { Bar, Baz, Quux }
+
+fn main() {
+    let foo = Foo::Quux;
+    while let <|> = foo { }
+}
 "#,
             expect![[r#"
                 ev Foo::Bar  ()
+                ev Bar       ()
                 ev Foo::Baz  ()
+                ev Baz       ()
                 ev Foo::Quux ()
+                ev Quux      ()
                 en Foo
             "#]],
-        )
+        );
+        check_edit(
+            "Some",
+            r#"
+enum Option<T> { None, Some(T) }
+fn main(x: Option<i32>) {
+    if let <|> = x {}
+}
+"#,
+            r#"
+enum Option<T> { None, Some(T) }
+fn main(x: Option<i32>) {
+    if let Option::Some($0) = x {}
+}
+"#,
+        );
     }
 
     #[test]
@@ -774,11 +953,8 @@ enum Foo { Bar, Baz, Quux }
 fn main() { let foo: Foo = Q<|> }
 "#,
             expect![[r#"
-                ev Foo::Bar  ()
-                ev Foo::Baz  ()
                 ev Foo::Quux ()
-                en Foo
-                fn main()    fn main()
+                ev Quux      ()
             "#]],
         )
     }
@@ -792,12 +968,28 @@ fn f() -> m::E { V<|> }
 "#,
             expect![[r#"
                 ev m::E::V ()
-                md m
-                fn f()     fn f() -> m::E
+                ev V       ()
             "#]],
         )
     }
 
+    #[test]
+    fn completes_enum_variant_in_arg_position() {
+        check_edit(
+            "South",
+            r#"
+enum Direction { North, South }
+fn go(direction: Direction) {}
+fn main() { go(So<|>) }
+"#,
+            r#"
+enum Direction { North, South }
+fn go(direction: Direction) {}
+fn main() { go(Direction::South) }
+"#,
+        );
+    }
+
     #[test]
     fn dont_complete_attr() {
         check(
@@ -820,7 +1012,6 @@ struct MyStruct {}
 impl My<|>
 "#,
             expect![[r#"
-                tp Self
                 tt MyTrait
                 st MyStruct
             "#]],
@@ -911,6 +1102,60 @@ fn main() {
         );
     }
 
+    #[test]
+    fn does_not_complete_doc_hidden_items_without_prefix() {
+        mark::check!(doc_hidden_item_not_completed);
+        check(
+            r#"
+#[doc(hidden)]
+fn hidden_fn() {}
+
+fn main() { <|> }
+"#,
+            expect![[r#"
+                fn main() fn main()
+            "#]],
+        );
+    }
+
+    #[test]
+    fn completes_doc_hidden_items_on_typed_prefix() {
+        check_edit(
+            "hidden_fn",
+            r#"
+#[doc(hidden)]
+fn hidden_fn() {}
+
+fn main() { hidden_<|> }
+"#,
+            r#"
+#[doc(hidden)]
+fn hidden_fn() {}
+
+fn main() { hidden_fn()$0 }
+"#,
+        );
+    }
+
+    #[test]
+    fn completes_doc_hidden_items_on_typed_subsequence() {
+        check_edit(
+            "hidden_fn",
+            r#"
+#[doc(hidden)]
+fn hidden_fn() {}
+
+fn main() { hfn<|> }
+"#,
+            r#"
+#[doc(hidden)]
+fn hidden_fn() {}
+
+fn main() { hidden_fn()$0 }
+"#,
+        );
+    }
+
     #[test]
     fn fuzzy_completions_come_in_specific_order() {
         mark::check!(certain_fuzzy_order_test);
@@ -940,14 +1185,34 @@ fn main() {
 }
 "#,
             expect![[r#"
-                fn main()           fn main()
-                st SecondStruct
-                st FirstStruct
-                md dep
                 st dep::some_module::ThirdStruct
                 st dep::some_module::AfterThirdStruct
                 st dep::some_module::ThiiiiiirdStruct
             "#]],
         );
     }
+
+    #[test]
+    fn fuzzy_completions_match_word_starts() {
+        check_with_config(
+            fuzzy_completion_config(),
+            r#"
+//- /lib.rs crate:dep
+pub mod storage {
+    pub fn find_db() {}
+    pub fn drop_fd_buffer() {}
+    pub fn unrelated() {}
+}
+
+//- /main.rs crate:main deps:dep
+fn main() {
+    fdb<|>
+}
+"#,
+            expect![[r#"
+                fn dep::storage::find_db() pub fn find_db()
+                fn dep::storage::drop_fd_buffer() pub fn drop_fd_buffer()
+            "#]],
+        );
+    }
 }
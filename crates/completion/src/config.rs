@@ -13,6 +13,12 @@ pub struct CompletionConfig {
     pub enable_autoimport_completions: bool,
     pub add_call_parenthesis: bool,
     pub add_call_argument_snippets: bool,
+    /// Whether items which are not visible from the current module are shown
+    /// with a lower relevance instead of being hidden.
+    pub demote_private_items: bool,
+    /// Whether snippet completions, like `pd` or postfix ones, are ranked
+    /// below all other items.
+    pub demote_snippets: bool,
     pub snippet_cap: Option<SnippetCap>,
     pub merge: Option<MergeBehavior>,
     /// A set of capabilities, enabled on the client and supported on the server.
@@ -55,6 +61,8 @@ impl Default for CompletionConfig {
             enable_autoimport_completions: true,
             add_call_parenthesis: true,
             add_call_argument_snippets: true,
+            demote_private_items: false,
+            demote_snippets: true,
             snippet_cap: Some(SnippetCap { _private: () }),
             merge: Some(MergeBehavior::Full),
             active_resolve_capabilities: FxHashSet::default(),
@@ -1,10 +1,10 @@
 //! See `CompletionContext` structure.
 
-use hir::{Local, ScopeDef, Semantics, SemanticsScope, Type};
+use hir::{Local, ModuleDef, ScopeDef, Semantics, SemanticsScope, Type};
 use ide_db::base_db::{FilePosition, SourceDatabase};
 use ide_db::{call_info::ActiveParameter, RootDatabase};
 use syntax::{
-    algo::{find_covering_element, find_node_at_offset},
+    algo::{find_covering_element, find_node_at_offset, find_node_at_range},
     ast, match_ast, AstNode, NodeOrToken,
     SyntaxKind::*,
     SyntaxNode, SyntaxToken, TextRange, TextSize,
@@ -23,6 +23,8 @@ use crate::{
     CompletionConfig,
 };
 
+const FAKE_IDENT: &str = "intellijRulezz";
+
 /// `CompletionContext` is created early during completion to figure out, where
 /// exactly is the cursor, syntax-wise.
 #[derive(Debug)]
@@ -44,6 +46,8 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) record_lit_syntax: Option<ast::RecordExpr>,
     pub(super) record_pat_syntax: Option<ast::RecordPat>,
     pub(super) record_field_syntax: Option<ast::RecordExprField>,
+    /// The record literal whose base expression, as in `Foo { ..base }`, is completed.
+    pub(super) record_spread_syntax: Option<ast::RecordExpr>,
     pub(super) impl_def: Option<ast::Impl>,
     /// FIXME: `ActiveParameter` is string-based, which is very very wrong
     pub(super) active_parameter: Option<ActiveParameter>,
@@ -61,8 +65,15 @@ pub(crate) struct CompletionContext<'a> {
     pub(super) can_be_stmt: bool,
     /// `true` if we expect an expression at the cursor position.
     pub(super) is_expr: bool,
+    /// `true` if the expression at the cursor position must be a `bool`, as in an `if` condition.
+    pub(super) expects_bool: bool,
     /// Something is typed at the "top" level, in module or impl/trait.
     pub(super) is_new_item: bool,
+    /// `true` if we are inside a `const fn` body or a `const`/`static` initializer.
+    pub(super) is_const_context: bool,
+    /// The const, static or type alias whose definition we are in. It can't
+    /// refer to itself, so it is not offered as a completion.
+    pub(super) defined_item: Option<ModuleDef>,
     /// The receiver if this is a field or method access, i.e. writing something.<|>
     pub(super) dot_receiver: Option<ast::Expr>,
     pub(super) dot_receiver_is_ambiguous_float_literal: bool,
@@ -73,6 +84,11 @@ pub(crate) struct CompletionContext<'a> {
     /// If this is a macro call, i.e. the () are already there.
     pub(super) is_macro_call: bool,
     pub(super) is_path_type: bool,
+    /// `true` if we are in the generic arguments of a turbofish, e.g. `collect::<<|>>()`.
+    pub(super) is_turbofish: bool,
+    /// `true` if the type under caret may be replaced with the `_` placeholder,
+    /// e.g. `let x: Vec<<|>>` or `as <|>`, but not `T: <|>`.
+    pub(super) is_infer_type_allowed: bool,
     pub(super) has_type_args: bool,
     pub(super) attribute_under_caret: Option<ast::Attr>,
     pub(super) mod_declaration_under_caret: Option<ast::Module>,
@@ -110,7 +126,7 @@ impl<'a> CompletionContext<'a> {
         // actual completion.
         let file_with_fake_ident = {
             let parse = db.parse(position.file_id);
-            let edit = Indel::insert(position.offset, "intellijRulezz".to_string());
+            let edit = Indel::insert(position.offset, FAKE_IDENT.to_string());
             parse.reparse(&edit).tree()
         };
         let fake_ident_token =
@@ -143,6 +159,7 @@ impl<'a> CompletionContext<'a> {
             record_lit_syntax: None,
             record_pat_syntax: None,
             record_field_syntax: None,
+            record_spread_syntax: None,
             impl_def: None,
             active_parameter: ActiveParameter::at(db, position),
             is_param: false,
@@ -153,12 +170,17 @@ impl<'a> CompletionContext<'a> {
             after_if: false,
             can_be_stmt: false,
             is_expr: false,
+            expects_bool: false,
             is_new_item: false,
+            is_const_context: false,
+            defined_item: None,
             dot_receiver: None,
             is_call: false,
             is_pattern_call: false,
             is_macro_call: false,
             is_path_type: false,
+            is_turbofish: false,
+            is_infer_type_allowed: false,
             has_type_args: false,
             dot_receiver_is_ambiguous_float_literal: false,
             attribute_under_caret: None,
@@ -247,6 +269,16 @@ impl<'a> CompletionContext<'a> {
         }
     }
 
+    /// The part of the identifier under the cursor the user has already typed.
+    pub(crate) fn typed_prefix(&self) -> Option<String> {
+        if self.original_token.kind() != IDENT {
+            return None;
+        }
+        let range = self.original_token.text_range();
+        let len = self.position.offset.checked_sub(range.start())?;
+        Some(self.original_token.text()[..usize::from(len)].to_string())
+    }
+
     fn fill_keyword_patterns(&mut self, file_with_fake_ident: &SyntaxNode, offset: TextSize) {
         let fake_ident_token = file_with_fake_ident.token_at_offset(offset).right_biased().unwrap();
         let syntax_element = NodeOrToken::Token(fake_ident_token);
@@ -341,6 +373,9 @@ impl<'a> CompletionContext<'a> {
                         self.is_irrefutable_pat_binding = true;
                     }
                 }
+                if let Some(ty) = self.scrutinee_type(original_file, &bind_pat) {
+                    self.expected_type = Some(ty);
+                }
             }
             if is_node::<ast::Param>(name.syntax()) {
                 self.is_param = true;
@@ -354,6 +389,17 @@ impl<'a> CompletionContext<'a> {
         }
     }
 
+    /// For the whole pattern of an `if let` or `while let`, returns the type of the matched
+    /// expression. An empty pattern has no type of its own to look at.
+    fn scrutinee_type(&self, original_file: &SyntaxNode, bind_pat: &ast::IdentPat) -> Option<Type> {
+        let condition = bind_pat.syntax().parent().and_then(ast::Condition::cast)?;
+        let expr = condition.expr()?;
+        // The expression comes after the fake ident, so it starts earlier in the original file.
+        let range = expr.syntax().text_range() - TextSize::of(FAKE_IDENT);
+        let expr = find_node_at_range::<ast::Expr>(original_file, range)?;
+        self.sema.type_of_expr(&expr)
+    }
+
     fn classify_name_ref(
         &mut self,
         original_file: &SyntaxNode,
@@ -367,6 +413,10 @@ impl<'a> CompletionContext<'a> {
             self.record_lit_syntax =
                 self.sema.find_node_at_offset_with_macros(&original_file, offset);
         }
+        if is_record_spread(&name_ref) {
+            self.record_spread_syntax =
+                self.sema.find_node_at_offset_with_macros(&original_file, offset);
+        }
 
         self.impl_def = self
             .sema
@@ -389,8 +439,13 @@ impl<'a> CompletionContext<'a> {
             _ => (),
         }
 
-        self.use_item_syntax =
-            self.sema.ancestors_with_macros(self.token.parent()).find_map(ast::Use::cast);
+        // In `mod foo { use <|> }` the cursor is past the end of the (empty) original
+        // use item, so only the one with the fake ident contains it.
+        self.use_item_syntax = self
+            .sema
+            .ancestors_with_macros(self.token.parent())
+            .find_map(ast::Use::cast)
+            .or_else(|| name_ref.syntax().ancestors().find_map(ast::Use::cast));
 
         self.function_syntax = self
             .sema
@@ -398,6 +453,41 @@ impl<'a> CompletionContext<'a> {
             .take_while(|it| it.kind() != SOURCE_FILE && it.kind() != MODULE)
             .find_map(ast::Fn::cast);
 
+        self.is_const_context = self
+            .sema
+            .ancestors_with_macros(self.token.parent())
+            .take_while(|it| it.kind() != SOURCE_FILE && it.kind() != MODULE)
+            .find_map(|node| {
+                match_ast! {
+                    match node {
+                        ast::Fn(it) => Some(it.const_token().is_some()),
+                        ast::Const(_it) => Some(true),
+                        ast::Static(_it) => Some(true),
+                        _ => None,
+                    }
+                }
+            })
+            .unwrap_or(false);
+
+        self.defined_item = self
+            .sema
+            .ancestors_with_macros(self.token.parent())
+            .take_while(|it| it.kind() != SOURCE_FILE && it.kind() != MODULE)
+            .find_map(|node| {
+                match_ast! {
+                    match node {
+                        // Functions may refer to themselves anywhere, e.g. in
+                        // recursive calls, so they are left alone.
+                        ast::Fn(_it) => Some(None),
+                        ast::Const(it) => Some(self.sema.to_def(&it).map(ModuleDef::from)),
+                        ast::Static(it) => Some(self.sema.to_def(&it).map(ModuleDef::from)),
+                        ast::TypeAlias(it) => Some(self.sema.to_def(&it).map(ModuleDef::from)),
+                        _ => None,
+                    }
+                }
+            })
+            .flatten();
+
         self.record_field_syntax = self
             .sema
             .ancestors_with_macros(self.token.parent())
@@ -424,6 +514,15 @@ impl<'a> CompletionContext<'a> {
                 path.syntax().parent().and_then(ast::TupleStructPat::cast).is_some();
 
             self.is_path_type = path.syntax().parent().and_then(ast::PathType::cast).is_some();
+            self.is_turbofish = path
+                .syntax()
+                .ancestors()
+                .skip_while(|it| it.kind() != TYPE_ARG)
+                .nth(1)
+                .and_then(ast::GenericArgList::cast)
+                .map_or(false, |it| it.coloncolon_token().is_some());
+            self.is_infer_type_allowed =
+                self.is_path_type && is_in_inferable_type_position(path.syntax());
             self.has_type_args = segment.generic_arg_list().is_some();
 
             if let Some(path) = path_or_use_tree_qualifier(&path) {
@@ -466,6 +565,8 @@ impl<'a> CompletionContext<'a> {
                 })
                 .unwrap_or(false);
             self.is_expr = path.syntax().parent().and_then(ast::PathExpr::cast).is_some();
+            self.expects_bool =
+                self.is_expr && path.syntax().parent().map_or(false, |it| is_in_bool_position(&it));
 
             if let Some(off) = name_ref.syntax().text_range().start().checked_sub(2.into()) {
                 if let Some(if_expr) =
@@ -506,6 +607,38 @@ impl<'a> CompletionContext<'a> {
     }
 }
 
+fn is_in_bool_position(expr: &SyntaxNode) -> bool {
+    let parent = match expr.parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    match_ast! {
+        match parent {
+            ast::Condition(it) => it.pat().is_none(),
+            ast::BinExpr(it) => {
+                matches!(it.op_kind(), Some(ast::BinOp::BooleanAnd) | Some(ast::BinOp::BooleanOr))
+            },
+            ast::PrefixExpr(it) => it.op_kind() == Some(ast::PrefixOp::Not),
+            _ => false,
+        }
+    }
+}
+
+/// Walks up from a type path to the item or expression owning the type, to
+/// decide whether an inference placeholder is accepted there.
+fn is_in_inferable_type_position(path: &SyntaxNode) -> bool {
+    for node in path.ancestors().skip(1) {
+        match node.kind() {
+            kind if ast::Type::can_cast(kind) => continue,
+            TYPE_ARG | GENERIC_ARG_LIST | PATH_SEGMENT | PATH => continue,
+            LET_STMT | CAST_EXPR | PATH_EXPR | METHOD_CALL_EXPR => return true,
+            PARAM => return node.ancestors().nth(2).map_or(false, |it| it.kind() == CLOSURE_EXPR),
+            _ => return false,
+        }
+    }
+    false
+}
+
 fn find_node_with_range<N: AstNode>(syntax: &SyntaxNode, range: TextRange) -> Option<N> {
     find_covering_element(syntax, range).ancestors().find_map(N::cast)
 }
@@ -525,3 +658,16 @@ fn path_or_use_tree_qualifier(path: &ast::Path) -> Option<ast::Path> {
     let use_tree = use_tree_list.syntax().parent().and_then(ast::UseTree::cast)?;
     use_tree.path()
 }
+
+/// Checks whether `name_ref` is the whole base expression of a record literal, i.e. `..name_ref`.
+fn is_record_spread(name_ref: &ast::NameRef) -> bool {
+    let path_expr = match name_ref.syntax().ancestors().find_map(ast::PathExpr::cast) {
+        Some(it) => it,
+        None => return false,
+    };
+    let field_list = match path_expr.syntax().parent().and_then(ast::RecordExprFieldList::cast) {
+        Some(it) => it,
+        None => return false,
+    };
+    field_list.spread().map_or(false, |spread| spread.syntax() == path_expr.syntax())
+}
@@ -10,7 +10,7 @@ use ide_db::helpers::{
 use syntax::{algo, TextRange};
 use text_edit::TextEdit;
 
-use crate::config::SnippetCap;
+use crate::{config::SnippetCap, matcher::MatchKind};
 
 /// `CompletionItem` describes a single completion variant in the editor pop-up.
 /// It is basically a POD with various properties. To construct a
@@ -67,6 +67,18 @@ pub struct CompletionItem {
     /// possible match.
     ref_match: Option<(Mutability, CompletionScore)>,
 
+    /// Whether the completed item is not visible from the current module, and
+    /// is only offered with a lowered relevance.
+    is_private: bool,
+
+    /// Whether the item is ranked below regular items, like snippets are when
+    /// `CompletionConfig::demote_snippets` is set.
+    is_demoted: bool,
+
+    /// How the identifier typed so far matched the item's lookup, if anything
+    /// was typed.
+    match_kind: Option<MatchKind>,
+
     /// The import data to add to completion's edits.
     import_to_add: Option<ImportEdit>,
 }
@@ -101,6 +113,15 @@ impl fmt::Debug for CompletionItem {
         if let Some(score) = &self.score {
             s.field("score", score);
         }
+        if self.is_private {
+            s.field("is_private", &true);
+        }
+        if self.is_demoted {
+            s.field("is_demoted", &true);
+        }
+        if let Some(match_kind) = self.match_kind.filter(|&it| it != MatchKind::Prefix) {
+            s.field("match_kind", &match_kind);
+        }
         if self.trigger_call_info {
             s.field("trigger_call_info", &true);
         }
@@ -110,6 +131,10 @@ impl fmt::Debug for CompletionItem {
 
 #[derive(Debug, Clone, Copy, Ord, PartialOrd, Eq, PartialEq)]
 pub enum CompletionScore {
+    /// If this is a macro commonly used to stub out code, like `todo!`
+    PlaceholderMacro,
+    /// If this is a method of a builder which returns the builder itself
+    ChainableMethod,
     /// If only type match
     TypeMatch,
     /// If type and name match
@@ -136,6 +161,7 @@ pub enum CompletionItemKind {
     TypeParam,
     Macro,
     Attribute,
+    Label,
     UnresolvedReference,
 }
 
@@ -152,6 +178,7 @@ impl CompletionItemKind {
             CompletionItemKind::Field => "fd",
             CompletionItemKind::Function => "fn",
             CompletionItemKind::Keyword => "kw",
+            CompletionItemKind::Label => "lb",
             CompletionItemKind::Macro => "ma",
             CompletionItemKind::Method => "me",
             CompletionItemKind::Module => "md",
@@ -178,6 +205,7 @@ pub(crate) enum CompletionKind {
     Postfix,
     BuiltinType,
     Attribute,
+    Label,
 }
 
 #[derive(Debug, PartialEq, Eq, Copy, Clone)]
@@ -208,6 +236,7 @@ impl CompletionItem {
             trigger_call_info: None,
             score: None,
             ref_match: None,
+            is_demoted: None,
             import_to_add: None,
         }
     }
@@ -253,6 +282,63 @@ impl CompletionItem {
         self.score
     }
 
+    /// A relevance score which clients can use to rank items, higher is better.
+    ///
+    /// The score is a sum of independent parts: visible items get `64`, items
+    /// which aren't demoted get `32`, the way the typed identifier matched adds
+    /// up to `24`, so that `find_db` ranks below `fdb_cache` when completing
+    /// `fdb`, and `score` adds up to `4` on top. This way private and demoted
+    /// items rank below all other ones, and new parts can be added without
+    /// changing the existing ones.
+    pub fn relevance(&self) -> u8 {
+        let mut relevance = 0;
+        if !self.is_private {
+            relevance += 64;
+        }
+        if !self.is_demoted {
+            relevance += 32;
+        }
+        relevance += 8 * match self.match_kind {
+            None | Some(MatchKind::Prefix) => 3,
+            Some(MatchKind::Substring) => 2,
+            Some(MatchKind::WordStarts) => 1,
+            Some(MatchKind::Subsequence) => 0,
+        };
+        relevance += match self.score {
+            None => 0,
+            Some(CompletionScore::PlaceholderMacro) => 1,
+            Some(CompletionScore::ChainableMethod) => 2,
+            Some(CompletionScore::TypeMatch) => 3,
+            Some(CompletionScore::TypeAndNameMatch) => 4,
+        };
+        relevance
+    }
+
+    /// Whether the completed item is not visible from the current module.
+    pub fn is_private(&self) -> bool {
+        self.is_private
+    }
+
+    pub(crate) fn into_private(mut self) -> CompletionItem {
+        self.is_private = true;
+        self
+    }
+
+    /// Whether the item should be ranked below regular items.
+    pub fn is_demoted(&self) -> bool {
+        self.is_demoted
+    }
+
+    /// How the identifier typed so far matched this item, if anything was typed.
+    pub fn match_kind(&self) -> Option<MatchKind> {
+        self.match_kind
+    }
+
+    pub(crate) fn with_match_kind(mut self, match_kind: MatchKind) -> CompletionItem {
+        self.match_kind = Some(match_kind);
+        self
+    }
+
     pub fn trigger_call_info(&self) -> bool {
         self.trigger_call_info
     }
@@ -311,6 +397,7 @@ pub(crate) struct Builder {
     trigger_call_info: Option<bool>,
     score: Option<CompletionScore>,
     ref_match: Option<(Mutability, CompletionScore)>,
+    is_demoted: Option<bool>,
 }
 
 impl Builder {
@@ -321,7 +408,9 @@ impl Builder {
         let mut lookup = self.lookup;
         let mut insert_text = self.insert_text;
 
-        if let Some(import_to_add) = self.import_to_add.as_ref() {
+        // Importing a method brings its trait into scope, the method itself isn't qualified.
+        let import_qualifies_item = self.kind != Some(CompletionItemKind::Method);
+        if let Some(import_to_add) = self.import_to_add.as_ref().filter(|_| import_qualifies_item) {
             let mut import_path_without_last_segment = import_to_add.import_path.to_owned();
             let _ = import_path_without_last_segment.segments.pop();
 
@@ -357,6 +446,9 @@ impl Builder {
             trigger_call_info: self.trigger_call_info.unwrap_or(false),
             score: self.score,
             ref_match: self.ref_match,
+            is_private: false,
+            is_demoted: self.is_demoted.unwrap_or(false),
+            match_kind: None,
             import_to_add: self.import_to_add,
         }
     }
@@ -416,6 +508,10 @@ impl Builder {
         self.score = Some(score);
         self
     }
+    pub(crate) fn set_demoted(mut self, demoted: bool) -> Builder {
+        self.is_demoted = Some(demoted);
+        self
+    }
     pub(crate) fn trigger_call_info(mut self) -> Builder {
         self.trigger_call_info = Some(true);
         self
@@ -3,6 +3,7 @@
 mod config;
 mod item;
 mod context;
+mod matcher;
 mod patterns;
 mod generated_lint_completions;
 #[cfg(test)]
@@ -22,6 +23,7 @@ use crate::{completions::Completions, context::CompletionContext, item::Completi
 pub use crate::{
     config::{CompletionConfig, CompletionResolveCapability},
     item::{CompletionItem, CompletionItemKind, CompletionScore, ImportEdit, InsertTextFormat},
+    matcher::MatchKind,
 };
 
 //FIXME: split the following feature into fine-grained features.
@@ -116,6 +118,7 @@ pub fn completions(
     completions::fn_param::complete_fn_param(&mut acc, &ctx);
     completions::keyword::complete_expr_keyword(&mut acc, &ctx);
     completions::keyword::complete_use_tree_keyword(&mut acc, &ctx);
+    completions::keyword::complete_infer_type_placeholder(&mut acc, &ctx);
     completions::snippet::complete_expr_snippet(&mut acc, &ctx);
     completions::snippet::complete_item_snippet(&mut acc, &ctx);
     completions::qualified_path::complete_qualified_path(&mut acc, &ctx);
@@ -127,6 +130,13 @@ pub fn completions(
     completions::macro_in_item_position::complete_macro_in_item_position(&mut acc, &ctx);
     completions::trait_impl::complete_trait_impl(&mut acc, &ctx);
     completions::mod_::complete_mod(&mut acc, &ctx);
+    completions::format_string::complete_format_string(&mut acc, &ctx);
+    completions::label::complete_label(&mut acc, &ctx);
+    completions::doc_link::complete_intra_doc_link(&mut acc, &ctx);
+
+    if let Some(typed) = ctx.typed_prefix() {
+        acc.retain_matching(&typed);
+    }
 
     Some(acc)
 }
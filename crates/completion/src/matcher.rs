@@ -0,0 +1,104 @@
+//! Matches the identifier typed so far against completion candidates.
+//!
+//! Besides plain prefixes, `fdb` matches `find_db` and `FindDb`, as a
+//! subsequence of the candidate's word starts.
+
+/// How well a candidate matched. Better matches compare as smaller.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum MatchKind {
+    /// The candidate starts with the pattern.
+    Prefix,
+    /// The candidate contains the pattern.
+    Substring,
+    /// The pattern consists of pieces of the candidate's words, e.g. `fdb` for `find_db`.
+    WordStarts,
+    /// The candidate contains all of the pattern's characters in order.
+    Subsequence,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Match {
+    pub(crate) kind: MatchKind,
+    /// Index of the first matched character; earlier is better.
+    pub(crate) start: usize,
+}
+
+/// Matches `pattern` against `candidate`, ignoring ASCII case.
+pub(crate) fn fuzzy_match(pattern: &str, candidate: &str) -> Option<Match> {
+    let pattern = pattern.to_ascii_lowercase();
+    let lowercased = candidate.to_ascii_lowercase();
+    if lowercased.starts_with(&pattern) {
+        return Some(Match { kind: MatchKind::Prefix, start: 0 });
+    }
+    if let Some(start) = lowercased.find(&pattern) {
+        let start = candidate[..start].chars().count();
+        return Some(Match { kind: MatchKind::Substring, start });
+    }
+
+    let pattern: Vec<char> = pattern.chars().collect();
+    let candidate: Vec<char> = candidate.chars().collect();
+    if let Some(start) = match_subsequence(&pattern, &candidate, true) {
+        return Some(Match { kind: MatchKind::WordStarts, start });
+    }
+    let start = match_subsequence(&pattern, &candidate, false)?;
+    Some(Match { kind: MatchKind::Subsequence, start })
+}
+
+/// Greedily matches the characters of `pattern` in order. With `word_starts`,
+/// each character must either start a word or directly follow the previous match.
+fn match_subsequence(pattern: &[char], candidate: &[char], word_starts: bool) -> Option<usize> {
+    let mut start = None;
+    let mut prev: Option<usize> = None;
+    let mut idx = 0;
+    for &p in pattern {
+        loop {
+            let c = *candidate.get(idx)?;
+            let allowed = !word_starts
+                || prev.map_or(false, |prev| prev + 1 == idx)
+                || is_word_start(candidate, idx);
+            idx += 1;
+            if allowed && c.eq_ignore_ascii_case(&p) {
+                start.get_or_insert(idx - 1);
+                prev = Some(idx - 1);
+                break;
+            }
+        }
+    }
+    start
+}
+
+fn is_word_start(chars: &[char], idx: usize) -> bool {
+    let c = chars[idx];
+    let prev = match idx.checked_sub(1) {
+        Some(prev) => chars[prev],
+        None => return true,
+    };
+    (prev == '_' && c != '_') || (c.is_uppercase() && !prev.is_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kind(pattern: &str, candidate: &str) -> Option<MatchKind> {
+        fuzzy_match(pattern, candidate).map(|it| it.kind)
+    }
+
+    #[test]
+    fn matches_subsequences_of_word_starts() {
+        assert_eq!(kind("fdb", "find_db"), Some(MatchKind::WordStarts));
+        assert_eq!(kind("fdb", "FindDb"), Some(MatchKind::WordStarts));
+        assert_eq!(kind("fidb", "find_db"), Some(MatchKind::WordStarts));
+        assert_eq!(kind("fnd", "find_db"), Some(MatchKind::Subsequence));
+        assert_eq!(kind("dbf", "find_db"), None);
+    }
+
+    #[test]
+    fn prefix_matches_rank_first() {
+        assert_eq!(kind("fdb", "fdb_cache"), Some(MatchKind::Prefix));
+        assert_eq!(kind("FIN", "find_db"), Some(MatchKind::Prefix));
+        assert!(fuzzy_match("fdb", "fdb_cache") < fuzzy_match("fdb", "find_db"));
+        assert!(fuzzy_match("db", "find_db") < fuzzy_match("db", "do_build"));
+        assert!(fuzzy_match("hir", "ThirdStruct") < fuzzy_match("hir", "AfterThirdStruct"));
+    }
+}
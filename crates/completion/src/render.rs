@@ -10,14 +10,14 @@ pub(crate) mod type_alias;
 
 mod builder_ext;
 
-use hir::{Documentation, HasAttrs, HirDisplay, Mutability, ScopeDef, Type};
+use hir::{Attrs, Documentation, HasAttrs, HirDisplay, Mutability, ScopeDef, Type};
 use ide_db::RootDatabase;
 use syntax::TextRange;
 use test_utils::mark;
 
 use crate::{
-    config::SnippetCap, item::ImportEdit, CompletionContext, CompletionItem, CompletionItemKind,
-    CompletionKind, CompletionScore,
+    config::SnippetCap, item::ImportEdit, matcher::fuzzy_match, CompletionContext, CompletionItem,
+    CompletionItemKind, CompletionKind, CompletionScore,
 };
 
 use crate::render::{enum_variant::render_variant, function::render_fn, macro_::render_macro};
@@ -82,15 +82,20 @@ impl<'a> RenderContext<'a> {
     }
 
     fn is_deprecated(&self, node: impl HasAttrs) -> bool {
-        node.attrs(self.db()).by_key("deprecated").exists()
+        is_deprecated(&node.attrs(self.db()))
     }
 
     fn docs(&self, node: impl HasAttrs) -> Option<Documentation> {
-        node.docs(self.db())
+        docs(&node.attrs(self.db()))
     }
 
     fn active_name_and_type(&self) -> Option<(String, Type)> {
-        if let Some(record_field) = &self.completion.record_field_syntax {
+        if let Some(record_lit) = &self.completion.record_spread_syntax {
+            mark::hit!(record_spread_type_match);
+            // Any value of the literal's type works as a base, regardless of its name.
+            let ty = self.completion.sema.type_of_expr(&record_lit.clone().into())?;
+            Some((String::new(), ty))
+        } else if let Some(record_field) = &self.completion.record_field_syntax {
             mark::hit!(record_field_type_match);
             let (struct_field, _local) = self.completion.sema.resolve_record_field(record_field)?;
             Some((struct_field.name(self.db()).to_string(), struct_field.signature_ty(self.db())))
@@ -124,7 +129,7 @@ impl<'a> Render<'a> {
         )
         .kind(CompletionItemKind::Field)
         .detail(ty.display(self.ctx.db()).to_string())
-        .set_documentation(field.docs(self.ctx.db()))
+        .set_documentation(self.ctx.docs(field))
         .set_deprecated(is_deprecated);
 
         if let Some(score) = compute_score(&self.ctx, &ty, &name.to_string()) {
@@ -150,6 +155,20 @@ impl<'a> Render<'a> {
         let _p = profile::span("render_resolution");
         use hir::ModuleDef::*;
 
+        let attrs = self.attrs(resolution);
+
+        // `#[doc(hidden)]` items are only offered once the user starts typing their name.
+        if attrs.as_ref().map_or(false, is_doc_hidden) {
+            match self.ctx.completion.typed_prefix() {
+                Some(prefix)
+                    if !prefix.is_empty() && fuzzy_match(&prefix, &local_name).is_some() => {}
+                _ => {
+                    mark::hit!(doc_hidden_item_not_completed);
+                    return None;
+                }
+            }
+        }
+
         let completion_kind = match resolution {
             ScopeDef::ModuleDef(BuiltinType(..)) => CompletionKind::BuiltinType,
             _ => CompletionKind::Reference,
@@ -202,7 +221,8 @@ impl<'a> Render<'a> {
             }
         };
 
-        let docs = self.docs(resolution);
+        let docs = attrs.as_ref().and_then(docs);
+        let is_deprecated = attrs.as_ref().map_or(false, is_deprecated);
 
         let mut item =
             CompletionItem::new(completion_kind, self.ctx.source_range(), local_name.clone());
@@ -253,26 +273,74 @@ impl<'a> Render<'a> {
             .kind(kind)
             .add_import(import_to_add)
             .set_documentation(docs)
+            .set_deprecated(is_deprecated)
             .set_ref_match(ref_match)
             .build();
         Some(item)
     }
 
-    fn docs(&self, resolution: &ScopeDef) -> Option<Documentation> {
+    fn attrs(&self, resolution: &ScopeDef) -> Option<Attrs> {
         use hir::ModuleDef::*;
-        match resolution {
-            ScopeDef::ModuleDef(Module(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Adt(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Variant(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Const(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Static(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(Trait(it)) => it.docs(self.ctx.db()),
-            ScopeDef::ModuleDef(TypeAlias(it)) => it.docs(self.ctx.db()),
-            _ => None,
-        }
+        let db = self.ctx.db();
+        let attrs = match resolution {
+            ScopeDef::ModuleDef(Module(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Function(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Adt(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Variant(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Const(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Static(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(Trait(it)) => it.attrs(db),
+            ScopeDef::ModuleDef(TypeAlias(it)) => it.attrs(db),
+            ScopeDef::MacroDef(it) => it.attrs(db),
+            _ => return None,
+        };
+        Some(attrs)
     }
 }
 
+fn is_deprecated(attrs: &Attrs) -> bool {
+    attrs.by_key("deprecated").exists()
+}
+
+fn is_doc_hidden(attrs: &Attrs) -> bool {
+    attrs
+        .by_key("doc")
+        .tt_values()
+        .any(|tt| tt.token_trees.iter().any(|it| it.to_string() == "hidden"))
+}
+
+/// Item documentation, followed by the deprecation note if there is one.
+fn docs(attrs: &Attrs) -> Option<Documentation> {
+    let docs = attrs.docs();
+    let note = match deprecation_note(attrs) {
+        Some(it) => it,
+        None => return docs,
+    };
+    let note = format!("Deprecated: {}", note);
+    let text = match docs {
+        Some(docs) => format!("{}\n\n{}", docs.as_str(), note),
+        None => note,
+    };
+    Some(Documentation::new(&text))
+}
+
+/// Extracts the note from `#[deprecated = "note"]` or `#[deprecated(note = "note")]`.
+fn deprecation_note(attrs: &Attrs) -> Option<String> {
+    let deprecated = attrs.by_key("deprecated");
+    if let Some(note) = deprecated.string_value() {
+        return Some(note.to_string());
+    }
+    deprecated.tt_values().find_map(|tt| {
+        let trees = tt.token_trees.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+        trees.windows(3).find_map(|w| match w {
+            [key, eq, value] if key == "note" && eq == "=" => {
+                Some(value.trim_matches('"').to_string())
+            }
+            _ => None,
+        })
+    })
+}
+
 fn compute_score_from_active(
     active_type: &Type,
     active_name: &str,
@@ -318,6 +386,7 @@ mod tests {
     use std::cmp::Reverse;
 
     use expect_test::{expect, Expect};
+    use ide_db::helpers::FamousDefs;
     use test_utils::mark;
 
     use crate::{
@@ -333,6 +402,8 @@ mod tests {
     fn check_scores(ra_fixture: &str, expect: Expect) {
         fn display_score(score: Option<CompletionScore>) -> &'static str {
             match score {
+                Some(CompletionScore::PlaceholderMacro) => "[placeholder]",
+                Some(CompletionScore::ChainableMethod) => "[chain]",
                 Some(CompletionScore::TypeMatch) => "[type]",
                 Some(CompletionScore::TypeAndNameMatch) => "[type+name]",
                 None => "[]".into(),
@@ -364,11 +435,12 @@ fn main() { Foo::Fo<|> }
             expect![[r#"
                 [
                     CompletionItem {
-                        label: "Foo",
+                        label: "Foo {…}",
                         source_range: 54..56,
                         delete: 54..56,
-                        insert: "Foo",
+                        insert: "Foo { $0 }",
                         kind: EnumVariant,
+                        lookup: "Foo",
                         detail: "{ x: i32, y: i32 }",
                     },
                 ]
@@ -445,13 +517,6 @@ fn main() { let _: m::Spam = S<|> }
                         detail: "(i32)",
                         trigger_call_info: true,
                     },
-                    CompletionItem {
-                        label: "m",
-                        source_range: 75..76,
-                        delete: 75..76,
-                        insert: "m",
-                        kind: Module,
-                    },
                     CompletionItem {
                         label: "m::Spam::Foo",
                         source_range: 75..76,
@@ -461,15 +526,6 @@ fn main() { let _: m::Spam = S<|> }
                         lookup: "Spam::Foo",
                         detail: "()",
                     },
-                    CompletionItem {
-                        label: "main()",
-                        source_range: 75..76,
-                        delete: 75..76,
-                        insert: "main()$0",
-                        kind: Function,
-                        lookup: "main",
-                        detail: "fn main()",
-                    },
                 ]
             "#]],
         )
@@ -488,15 +544,6 @@ fn main() { som<|> }
 "#,
             expect![[r#"
                 [
-                    CompletionItem {
-                        label: "main()",
-                        source_range: 121..124,
-                        delete: 121..124,
-                        insert: "main()$0",
-                        kind: Function,
-                        lookup: "main",
-                        detail: "fn main()",
-                    },
                     CompletionItem {
                         label: "something_deprecated()",
                         source_range: 121..124,
@@ -542,6 +589,34 @@ fn foo() { A { the<|> } }
         );
     }
 
+    #[test]
+    fn renders_deprecation_note_in_docs() {
+        check(
+            r#"
+/// A point.
+#[deprecated(note = "use `Vec2` instead")]
+struct Point;
+
+fn main() { Poi<|> }
+"#,
+            expect![[r#"
+                [
+                    CompletionItem {
+                        label: "Point",
+                        source_range: 83..86,
+                        delete: 83..86,
+                        insert: "Point",
+                        kind: Struct,
+                        documentation: Documentation(
+                            "A point.\n\nDeprecated: use `Vec2` instead",
+                        ),
+                        deprecated: true,
+                    },
+                ]
+            "#]],
+        );
+    }
+
     #[test]
     fn renders_docs() {
         check(
@@ -585,7 +660,7 @@ impl S {
 
         check(
             r#"
-use self::my<|>;
+use self::<|>;
 
 /// mod docs
 mod my { }
@@ -601,8 +676,8 @@ use self::E::*;
                 [
                     CompletionItem {
                         label: "E",
-                        source_range: 10..12,
-                        delete: 10..12,
+                        source_range: 10..10,
+                        delete: 10..10,
                         insert: "E",
                         kind: Enum,
                         documentation: Documentation(
@@ -611,8 +686,8 @@ use self::E::*;
                     },
                     CompletionItem {
                         label: "V",
-                        source_range: 10..12,
-                        delete: 10..12,
+                        source_range: 10..10,
+                        delete: 10..10,
                         insert: "V",
                         kind: EnumVariant,
                         detail: "()",
@@ -622,8 +697,8 @@ use self::E::*;
                     },
                     CompletionItem {
                         label: "my",
-                        source_range: 10..12,
-                        delete: 10..12,
+                        source_range: 10..10,
+                        delete: 10..10,
                         insert: "my",
                         kind: Module,
                         documentation: Documentation(
@@ -796,6 +871,157 @@ fn foo(s: S) { test(s.<|>) }
         );
     }
 
+    #[test]
+    fn builder_methods_scores() {
+        check_scores(
+            r#"
+struct Foo;
+struct FooBuilder;
+impl FooBuilder {
+    fn verbose(self) -> Self { self }
+    fn quiet(self) -> FooBuilder { self }
+    fn is_valid(&self) -> bool { true }
+    fn build(self) -> Foo { Foo }
+}
+fn main() {
+    let builder = FooBuilder;
+    builder.<|>
+}
+"#,
+            expect![[r#"
+                me quiet() [chain]
+                me verbose() [chain]
+                me build() []
+                me is_valid() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn iterator_conversion_scores_in_for_loop() {
+        let fixture = r#"
+//- /main.rs crate:main deps:core
+struct Vec;
+struct Iter;
+impl Vec {
+    fn iter(&self) -> Iter { Iter }
+    fn iter_mut(&mut self) -> Iter { Iter }
+    fn len(&self) -> usize { 0 }
+}
+impl IntoIterator for Vec {
+    type Item = i32;
+    type IntoIter = Iter;
+    fn into_iter(self) -> Iter { Iter }
+}
+fn main() {
+    let v = Vec;
+    for x in v.<|> {}
+}
+"#;
+        check_scores(
+            &format!("{}{}", fixture, FamousDefs::FIXTURE),
+            expect![[r#"
+                me into_iter() [type]
+                me iter() [type]
+                me iter_mut() [type]
+                me len() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn placeholder_macros_scores() {
+        check_scores(
+            r#"
+macro_rules! todo { () => {} }
+macro_rules! unimplemented { () => {} }
+macro_rules! foo { () => {} }
+fn main() { <|> }
+"#,
+            expect![[r#"
+                ma todo!(…) [placeholder]
+                ma unimplemented!(…) [placeholder]
+                ma foo!(…) []
+                fn main() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn builder_call_methods_scores() {
+        check_scores(
+            r#"
+struct Config;
+impl Config {
+    fn builder() -> Config { Config }
+    fn verbose(self) -> Self { self }
+    fn is_valid(&self) -> bool { true }
+}
+fn main() { Config::builder().<|> }
+"#,
+            expect![[r#"
+                me verbose() [chain]
+                me is_valid() []
+            "#]],
+        );
+    }
+
+    #[test]
+    fn relevance_is_comparable_across_items() {
+        let completions = get_all_items(
+            CompletionConfig::default(),
+            r#"
+struct S { foo: i64, bar: u32, baz: u32 }
+fn test(bar: u32) { }
+fn foo(s: S) { test(s.<|>) }
+"#,
+        );
+        let relevance =
+            |label: &str| completions.iter().find(|it| it.label() == label).unwrap().relevance();
+        assert!(relevance("bar") > relevance("baz"));
+        assert!(relevance("baz") > relevance("foo"));
+    }
+
+    #[test]
+    fn fuzzy_matches_rank_below_prefix_matches() {
+        let completions = get_all_items(
+            CompletionConfig::default(),
+            r#"
+fn find_db() {}
+fn fdb_cache() {}
+fn unrelated() {}
+fn main() { fdb<|> }
+"#,
+        );
+        let relevance = |label: &str| {
+            completions.iter().find(|it| it.label() == label).map(|it| it.relevance())
+        };
+        assert!(relevance("find_db()").is_some());
+        assert!(relevance("unrelated()").is_none());
+        assert!(relevance("find_db()") < relevance("fdb_cache()"));
+    }
+
+    #[test]
+    fn record_spread_scores() {
+        mark::check!(record_spread_type_match);
+        check_scores(
+            r#"
+struct Foo { a: u32, b: u32 }
+fn main() {
+    let base = Foo { a: 0, b: 0 };
+    let other = 92u32;
+    let foo = Foo { a: 1, ..<|> };
+}
+"#,
+            expect![[r#"
+                bn base [type]
+                st Foo []
+                fn main() []
+                bn other []
+            "#]],
+        );
+    }
+
     #[test]
     fn record_field_scores() {
         mark::check!(record_field_type_match);
@@ -853,7 +1079,6 @@ fn go(world: &WorldSnapshot) { go(w<|>) }
             expect![[r#"
                 bn world [type+name]
                 st WorldSnapshot []
-                fn go(…) []
             "#]],
         );
     }
@@ -863,7 +1088,7 @@ fn go(world: &WorldSnapshot) { go(w<|>) }
         check_scores(
             r#"
 struct Foo;
-fn f(foo: &Foo) { f(foo, w<|>) }
+fn f(foo: &Foo) { f(foo, <|>) }
 "#,
             expect![[r#"
                 st Foo []
@@ -91,4 +91,17 @@ impl Builder {
         };
         self.lookup_by(name).label(label).insert_snippet(cap, snippet)
     }
+
+    pub(super) fn add_record_braces(self, ctx: &CompletionContext, name: String) -> Builder {
+        if !self.should_add_parens(ctx) {
+            return self;
+        }
+        let cap = match ctx.config.snippet_cap {
+            Some(it) => it,
+            None => return self,
+        };
+        mark::hit!(inserts_braces_for_record_variants);
+        let snippet = format!("{} {{ $0 }}", name);
+        self.lookup_by(name.clone()).label(format!("{} {{…}}", name)).insert_snippet(cap, snippet)
+    }
 }
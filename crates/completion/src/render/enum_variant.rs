@@ -1,6 +1,6 @@
 //! Renderer for `enum` variants.
 
-use hir::{HasAttrs, HirDisplay, ModPath, StructKind};
+use hir::{HirDisplay, ModPath, StructKind};
 use itertools::Itertools;
 use test_utils::mark;
 
@@ -20,6 +20,17 @@ pub(crate) fn render_variant<'a>(
     EnumRender::new(ctx, local_name, variant, path).render(import_to_add)
 }
 
+/// Renders a variant that needs to be qualified at the completion site under its
+/// bare name, so that `Qu` finds `Foo::Quux`. The qualified path is still inserted.
+pub(crate) fn render_variant_by_name<'a>(
+    ctx: RenderContext<'a>,
+    variant: hir::Variant,
+    path: ModPath,
+) -> CompletionItem {
+    let _p = profile::span("render_enum_variant_by_name");
+    EnumRender::new(ctx, None, variant, Some(path)).render_by_name()
+}
+
 #[derive(Debug)]
 struct EnumRender<'a> {
     ctx: RenderContext<'a>,
@@ -61,7 +72,7 @@ impl<'a> EnumRender<'a> {
             self.qualified_name.clone(),
         )
         .kind(CompletionItemKind::EnumVariant)
-        .set_documentation(self.variant.docs(self.ctx.db()))
+        .set_documentation(self.ctx.docs(self.variant))
         .set_deprecated(self.ctx.is_deprecated(self.variant))
         .add_import(import_to_add)
         .detail(self.detail());
@@ -71,6 +82,8 @@ impl<'a> EnumRender<'a> {
             let params = Params::Anonymous(self.variant.fields(self.ctx.db()).len());
             builder =
                 builder.add_call_parens(self.ctx.completion, self.short_qualified_name, params);
+        } else if self.variant_kind == StructKind::Record {
+            builder = builder.add_record_braces(self.ctx.completion, self.short_qualified_name);
         } else if self.path.is_some() {
             builder = builder.lookup_by(self.short_qualified_name);
         }
@@ -78,6 +91,26 @@ impl<'a> EnumRender<'a> {
         builder.build()
     }
 
+    fn render_by_name(self) -> CompletionItem {
+        let mut builder =
+            CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), &self.name)
+                .kind(CompletionItemKind::EnumVariant)
+                .set_documentation(self.ctx.docs(self.variant))
+                .set_deprecated(self.ctx.is_deprecated(self.variant))
+                .detail(self.detail())
+                .insert_text(self.qualified_name.clone());
+
+        if self.variant_kind == StructKind::Tuple {
+            let params = Params::Anonymous(self.variant.fields(self.ctx.db()).len());
+            builder =
+                builder.add_call_parens(self.ctx.completion, self.qualified_name.clone(), params);
+        } else if self.variant_kind == StructKind::Record {
+            builder = builder.add_record_braces(self.ctx.completion, self.qualified_name.clone());
+        }
+
+        builder.lookup_by(self.name.clone()).label(self.name).build()
+    }
+
     fn detail(&self) -> String {
         let detail_types = self
             .variant
@@ -124,6 +157,49 @@ use Option::*;
 fn main() -> Option<i32> {
     Some($0)
 }
+"#,
+        );
+    }
+
+    #[test]
+    fn inserts_braces_for_record_variants() {
+        mark::check!(inserts_braces_for_record_variants);
+        check_edit(
+            "Move",
+            r#"
+enum Message { Quit, Move { x: i32, y: i32 } }
+use Message::*;
+fn main() -> Message {
+    Mov<|>
+}
+"#,
+            r#"
+enum Message { Quit, Move { x: i32, y: i32 } }
+use Message::*;
+fn main() -> Message {
+    Move { $0 }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn inserts_unit_variants_bare() {
+        check_edit(
+            "Quit",
+            r#"
+enum Message { Quit, Move { x: i32, y: i32 } }
+use Message::*;
+fn main() -> Message {
+    Qu<|>
+}
+"#,
+            r#"
+enum Message { Quit, Move { x: i32, y: i32 } }
+use Message::*;
+fn main() -> Message {
+    Quit
+}
 "#,
         );
     }
@@ -1,11 +1,17 @@
 //! Renderer for function calls.
 
-use hir::{HasSource, Type};
-use syntax::{ast::Fn, display::function_declaration};
+use hir::{AsAssocItem, AssocItemContainer, HasSource, Type};
+use ide_db::helpers::FamousDefs;
+use stdx::format_to;
+use syntax::{
+    ast::{self, Fn},
+    display::function_declaration,
+    AstNode,
+};
 use test_utils::mark;
 
 use crate::{
-    item::{CompletionItem, CompletionItemKind, CompletionKind, ImportEdit},
+    item::{CompletionItem, CompletionItemKind, CompletionKind, CompletionScore, ImportEdit},
     render::{builder_ext::Params, RenderContext},
 };
 
@@ -19,6 +25,16 @@ pub(crate) fn render_fn<'a>(
     FunctionRender::new(ctx, local_name, fn_).render(import_to_add)
 }
 
+/// Renders a call to a method returning `Option<T>` or `Result<T, E>` followed by `?`, for
+/// positions which expect a `T` inside a function returning the same wrapper.
+pub(crate) fn render_fn_with_try<'a>(
+    ctx: RenderContext<'a>,
+    fn_: hir::Function,
+) -> Option<CompletionItem> {
+    let _p = profile::span("render_fn_with_try");
+    FunctionRender::new(ctx, None, fn_).render_with_try()
+}
+
 #[derive(Debug)]
 struct FunctionRender<'a> {
     ctx: RenderContext<'a>,
@@ -41,18 +57,160 @@ impl<'a> FunctionRender<'a> {
 
     fn render(self, import_to_add: Option<ImportEdit>) -> CompletionItem {
         let params = self.params();
-        CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), self.name.clone())
-            .kind(self.kind())
+        let mut item = CompletionItem::new(
+            CompletionKind::Reference,
+            self.ctx.source_range(),
+            self.name.clone(),
+        );
+        if self.is_chainable_builder_method() {
+            item = item.set_score(CompletionScore::ChainableMethod);
+        } else if self.is_iterator_conversion_where_iterator_expected() {
+            item = item.set_score(CompletionScore::TypeMatch);
+        }
+        item.kind(self.kind())
             .set_documentation(self.ctx.docs(self.func))
             .set_deprecated(self.ctx.is_deprecated(self.func))
-            .detail(self.detail())
+            .detail(self.detail(import_to_add.as_ref()))
             .add_call_parens(self.ctx.completion, self.name, params)
             .add_import(import_to_add)
             .build()
     }
 
-    fn detail(&self) -> String {
-        function_declaration(&self.ast_node)
+    fn render_with_try(self) -> Option<CompletionItem> {
+        let completion = self.ctx.completion;
+        let db = self.ctx.db();
+        let cap = self.ctx.snippet_cap()?;
+        if !completion.config.add_call_parenthesis || completion.is_call {
+            return None;
+        }
+        let expected_ty = completion.expected_type.as_ref()?;
+
+        let ret_ty = self.func.ret_type(db);
+        let wrapper = ret_ty.as_adt()?;
+        let famous_defs = FamousDefs(&completion.sema, completion.krate);
+        let is_try_wrapper = [
+            famous_defs.core_option_Option().map(hir::Adt::from),
+            famous_defs.core_result_Result().map(hir::Adt::from),
+        ]
+        .contains(&Some(wrapper));
+        if !is_try_wrapper || ret_ty.type_arguments().next().as_ref() != Some(expected_ty) {
+            return None;
+        }
+
+        // `?` propagates to the innermost function or closure.
+        let enclosing_fn = completion
+            .original_token
+            .ancestors()
+            .find(|it| ast::Fn::can_cast(it.kind()) || ast::ClosureExpr::can_cast(it.kind()))
+            .and_then(ast::Fn::cast)?;
+        let enclosing_ret_ty = completion.sema.to_def(&enclosing_fn)?.ret_type(db);
+        if enclosing_ret_ty.as_adt() != Some(wrapper) {
+            return None;
+        }
+
+        let (label, snippet) = if self.params().is_empty() {
+            (format!("{}()?", self.name), format!("{}()?$0", self.name))
+        } else {
+            (format!("{}(…)?", self.name), format!("{}($0)?", self.name))
+        };
+        let item = CompletionItem::new(CompletionKind::Reference, self.ctx.source_range(), label)
+            .kind(self.kind())
+            .set_documentation(self.ctx.docs(self.func))
+            .set_deprecated(self.ctx.is_deprecated(self.func))
+            .detail(self.detail(None))
+            .set_score(CompletionScore::TypeMatch)
+            .insert_snippet(cap, snippet)
+            .build();
+        Some(item)
+    }
+
+    /// Whether this is a method of a builder, like `FooBuilder` or the result of `Foo::builder()`,
+    /// which returns the builder itself and so can be chained.
+    fn is_chainable_builder_method(&self) -> bool {
+        let completion = self.ctx.completion;
+        let db = self.ctx.db();
+        let receiver = match &completion.dot_receiver {
+            Some(it) => it,
+            None => return false,
+        };
+        let receiver_adt = match completion.sema.type_of_expr(receiver) {
+            Some(ty) => match ty.remove_ref().unwrap_or(ty).as_adt() {
+                Some(it) => it,
+                None => return false,
+            },
+            None => return false,
+        };
+
+        let is_builder_call = match receiver {
+            ast::Expr::CallExpr(call) => match call.expr() {
+                Some(ast::Expr::PathExpr(path_expr)) => path_expr
+                    .path()
+                    .and_then(|path| path.segment())
+                    .and_then(|segment| segment.name_ref())
+                    .map_or(false, |name_ref| name_ref.text() == "builder"),
+                _ => false,
+            },
+            _ => false,
+        };
+        if !is_builder_call && !receiver_adt.name(db).to_string().ends_with("Builder") {
+            return false;
+        }
+
+        let ret_ty = self.func.ret_type(db);
+        self.func.self_param(db).is_some()
+            && ret_ty.remove_ref().unwrap_or(ret_ty).as_adt() == Some(receiver_adt)
+    }
+
+    /// Whether this is `iter`, `iter_mut` or `into_iter` of a collection in a position which
+    /// expects an iterator, like the head of a `for` loop.
+    fn is_iterator_conversion_where_iterator_expected(&self) -> bool {
+        if !matches!(self.name.as_str(), "iter" | "iter_mut" | "into_iter") {
+            return false;
+        }
+        let completion = self.ctx.completion;
+        let db = self.ctx.db();
+        let receiver = match &completion.dot_receiver {
+            Some(it) => it,
+            None => return false,
+        };
+        let into_iterator =
+            match FamousDefs(&completion.sema, completion.krate).core_iter_IntoIterator() {
+                Some(it) => it,
+                None => return false,
+            };
+
+        let in_for_loop_head = receiver
+            .syntax()
+            .parent()
+            .and_then(|access| {
+                let for_expr = ast::ForExpr::cast(access.parent()?)?;
+                Some(for_expr.iterable()?.syntax() == &access)
+            })
+            .unwrap_or(false);
+        let in_into_iterator_arg = completion.active_parameter.as_ref().map_or(false, |param| {
+            param.ty.as_impl_traits(db).map_or(false, |traits| traits.contains(&into_iterator))
+        });
+        if !in_for_loop_head && !in_into_iterator_arg {
+            return false;
+        }
+
+        match completion.sema.type_of_expr(receiver) {
+            Some(ty) => ty.autoderef(db).any(|ty| ty.impls_trait(db, into_iterator, &[])),
+            None => false,
+        }
+    }
+
+    fn detail(&self, import_to_add: Option<&ImportEdit>) -> String {
+        let mut detail = function_declaration(&self.ast_node);
+        // Methods of traits which are not in scope yet are ambiguous without their trait.
+        let is_trait_item = matches!(
+            self.func.as_assoc_item(self.ctx.db()).map(|it| it.container(self.ctx.db())),
+            Some(AssocItemContainer::Trait(_))
+        );
+        if let Some(import) = import_to_add.filter(|_| is_trait_item) {
+            format_to!(detail, " (as {})", import.import_path);
+        }
+        detail
     }
 
     fn add_arg(&self, arg: &str, ty: &Type) -> String {
@@ -110,6 +268,7 @@ impl<'a> FunctionRender<'a> {
 
 #[cfg(test)]
 mod tests {
+    use ide_db::helpers::FamousDefs;
     use test_utils::mark;
 
     use crate::{
@@ -185,6 +344,37 @@ fn bar(s: &S) {
         );
     }
 
+    #[test]
+    fn offers_try_for_option_returning_methods() {
+        check_edit(
+            "port()?",
+            &format!(
+                "//- /main.rs crate:main deps:core{}{}",
+                r#"
+struct Config;
+impl Config {
+    fn port(&self) -> Option<u16> { None }
+}
+fn parse(config: &Config) -> Option<u32> {
+    let port: u16 = config.p<|>;
+    Some(port as u32)
+}
+"#,
+                FamousDefs::FIXTURE
+            ),
+            r#"
+struct Config;
+impl Config {
+    fn port(&self) -> Option<u16> { None }
+}
+fn parse(config: &Config) -> Option<u32> {
+    let port: u16 = config.port()?$0;
+    Some(port as u32)
+}
+"#,
+        );
+    }
+
     #[test]
     fn parens_for_method_call_as_assoc_fn() {
         mark::check!(parens_for_method_call_as_assoc_fn);
@@ -5,7 +5,7 @@ use syntax::display::macro_label;
 use test_utils::mark;
 
 use crate::{
-    item::{CompletionItem, CompletionItemKind, CompletionKind, ImportEdit},
+    item::{CompletionItem, CompletionItemKind, CompletionKind, CompletionScore, ImportEdit},
     render::RenderContext,
 };
 
@@ -53,6 +53,9 @@ impl<'a> MacroRender<'a> {
                 .set_deprecated(self.ctx.is_deprecated(self.macro_))
                 .add_import(import_to_add)
                 .detail(self.detail());
+        if self.is_placeholder() {
+            builder = builder.set_score(CompletionScore::PlaceholderMacro);
+        }
 
         let needs_bang = self.needs_bang();
         builder = match self.ctx.snippet_cap() {
@@ -75,6 +78,13 @@ impl<'a> MacroRender<'a> {
         self.ctx.completion.use_item_syntax.is_none() && !self.ctx.completion.is_macro_call
     }
 
+    /// Macros like `todo!()` are what one usually writes first in a stubbed out body.
+    fn is_placeholder(&self) -> bool {
+        self.ctx.completion.is_expr
+            && self.needs_bang()
+            && matches!(self.name.as_str(), "todo" | "unimplemented" | "unreachable")
+    }
+
     fn label(&self) -> String {
         if self.needs_bang() && self.ctx.snippet_cap().is_some() {
             format!("{}!{}…{}", self.name, self.bra, self.ket)
@@ -165,6 +175,21 @@ fn main() { frobnicate!(); }
         );
     }
 
+    #[test]
+    fn inserts_parens_for_todo() {
+        check_edit(
+            "todo!",
+            r#"
+macro_rules! todo { () => {} }
+fn foo() -> u32 { <|> }
+"#,
+            r#"
+macro_rules! todo { () => {} }
+fn foo() -> u32 { todo!($0) }
+"#,
+        );
+    }
+
     #[test]
     fn guesses_macro_braces() {
         check_edit(
@@ -10,6 +10,7 @@ use hir_def::{
     adt::VariantData,
     builtin_type::BuiltinType,
     expr::{BindingAnnotation, Pat, PatId},
+    generics::{WherePredicate, WherePredicateTypeTarget},
     import_map,
     item_tree::ItemTreeNode,
     lang_item::LangItemTarget,
@@ -17,7 +18,7 @@ use hir_def::{
     per_ns::PerNs,
     resolver::{HasResolver, Resolver},
     src::HasSource as _,
-    type_ref::{Mutability, TypeRef},
+    type_ref::{Mutability, TypeBound, TypeRef},
     AdtId, AssocContainerId, AssocItemId, AssocItemLoc, AttrDefId, ConstId, DefWithBodyId, EnumId,
     FunctionId, GenericDefId, HasModule, ImplId, LifetimeParamId, LocalEnumVariantId, LocalFieldId,
     LocalModuleId, Lookup, ModuleId, StaticId, StructId, TraitId, TypeAliasId, TypeParamId,
@@ -33,6 +34,7 @@ use hir_ty::{
     autoderef,
     display::{HirDisplayError, HirFormatter},
     method_resolution,
+    primitive::{IntBitness, Signedness},
     traits::{FnTrait, Solution, SolutionVariables},
     ApplicationTy, BoundVar, CallableDefId, Canonical, DebruijnIndex, FnSig, GenericPredicate,
     InEnvironment, Obligation, ProjectionPredicate, ProjectionTy, Substs, TraitEnvironment, Ty,
@@ -752,6 +754,21 @@ impl Function {
         Some(SelfParam { func: self.id })
     }
 
+    /// Whether the function has a `where Self: Sized` bound, which makes it uncallable on trait
+    /// objects.
+    pub fn has_sized_self_bound(self, db: &dyn HirDatabase) -> bool {
+        db.generic_params(self.id.into()).where_predicates.iter().any(|pred| match pred {
+            WherePredicate::TypeBound {
+                target: WherePredicateTypeTarget::TypeRef(TypeRef::Path(target)),
+                bound: TypeBound::Path(bound),
+            } => {
+                target.mod_path().as_ident() == Some(&name![Self])
+                    && bound.mod_path().segments.last() == Some(&name![Sized])
+            }
+            _ => false,
+        })
+    }
+
     pub fn assoc_fn_params(self, db: &dyn HirDatabase) -> Vec<Param> {
         let resolver = self.id.resolver(db.upcast());
         let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
@@ -771,6 +788,14 @@ impl Function {
             })
             .collect()
     }
+    pub fn ret_type(self, db: &dyn HirDatabase) -> Type {
+        let resolver = self.id.resolver(db.upcast());
+        let krate = self.id.lookup(db.upcast()).container.module(db.upcast()).krate;
+        let ret_type = &db.function_data(self.id).ret_type;
+        let ctx = hir_ty::TyLoweringContext::new(db, &resolver);
+        let environment = TraitEnvironment::lower(db, &resolver);
+        Type { krate, ty: InEnvironment { value: Ty::from_hir_ext(&ctx, ret_type).0, environment } }
+    }
     pub fn method_params(self, db: &dyn HirDatabase) -> Option<Vec<Param>> {
         if self.self_param(db).is_none() {
             return None;
@@ -784,6 +809,10 @@ impl Function {
         db.function_data(self.id).is_unsafe
     }
 
+    pub fn is_const(self, db: &dyn HirDatabase) -> bool {
+        db.function_data(self.id).is_const
+    }
+
     pub fn diagnostics(self, db: &dyn HirDatabase, sink: &mut DiagnosticSink) {
         let krate = self.module(db).id.krate;
         hir_def::diagnostics::validate_body(db.upcast(), self.id.into(), sink);
@@ -924,6 +953,12 @@ impl Trait {
     pub fn is_auto(self, db: &dyn HirDatabase) -> bool {
         db.trait_data(self.id).auto
     }
+
+    /// Checks whether this is the `Sized` lang item.
+    pub fn is_sized(self, db: &dyn HirDatabase) -> bool {
+        let krate = self.module(db).krate();
+        db.lang_item(krate.id, SmolStr::new("sized")).and_then(|it| it.as_trait()) == Some(self.id)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1387,6 +1422,12 @@ impl Type {
         Type::new(db, krate, def, ty)
     }
 
+    /// Returns the type of a builtin like `i32`, as seen from `krate`.
+    pub fn from_builtin(db: &dyn HirDatabase, krate: Crate, builtin: BuiltinType) -> Type {
+        let ty = db.ty(TyDefId::BuiltinType(builtin)).value;
+        Type::new(db, krate.id, krate.root_module(db).id, ty)
+    }
+
     pub fn is_unit(&self) -> bool {
         matches!(
             self.ty.value,
@@ -1397,6 +1438,45 @@ impl Type {
         matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Bool, .. }))
     }
 
+    pub fn is_str(&self) -> bool {
+        matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Str, .. }))
+    }
+
+    pub fn is_float(&self) -> bool {
+        matches!(self.ty.value, Ty::Apply(ApplicationTy { ctor: TypeCtor::Float(_), .. }))
+    }
+
+    /// Returns the smallest and largest value of this type if it's a fixed-size integer type.
+    pub fn int_bounds(&self) -> Option<(i128, u128)> {
+        let int_ty = match self.ty.value {
+            Ty::Apply(ApplicationTy { ctor: TypeCtor::Int(it), .. }) => it,
+            _ => return None,
+        };
+        let bits = match int_ty.bitness {
+            IntBitness::X8 => 8,
+            IntBitness::X16 => 16,
+            IntBitness::X32 => 32,
+            IntBitness::X64 => 64,
+            IntBitness::X128 => 128,
+            // The size depends on the target.
+            IntBitness::Xsize => return None,
+        };
+        match int_ty.signedness {
+            Signedness::Signed => Some((i128::MIN >> (128 - bits), u128::MAX >> (129 - bits))),
+            Signedness::Unsigned => Some((0, u128::MAX >> (128 - bits))),
+        }
+    }
+
+    /// Whether this is `usize` or `isize`, whose size depends on the target.
+    pub fn is_pointer_sized_int(&self) -> bool {
+        match self.ty.value {
+            Ty::Apply(ApplicationTy { ctor: TypeCtor::Int(it), .. }) => {
+                it.bitness == IntBitness::Xsize
+            }
+            _ => false,
+        }
+    }
+
     pub fn is_mutable_reference(&self) -> bool {
         matches!(
             self.ty.value,
@@ -1524,6 +1604,15 @@ impl Type {
         self.impls_trait(db, copy_trait.into(), &[])
     }
 
+    pub fn is_clone(&self, db: &dyn HirDatabase) -> bool {
+        let lang_item = db.lang_item(self.krate, SmolStr::new("clone"));
+        let clone_trait = match lang_item {
+            Some(LangItemTarget::TraitId(it)) => it,
+            _ => return false,
+        };
+        self.impls_trait(db, clone_trait.into(), &[])
+    }
+
     pub fn as_callable(&self, db: &dyn HirDatabase) -> Option<Callable> {
         let def = match self.ty.value {
             Ty::Apply(ApplicationTy { ctor: TypeCtor::FnDef(def), parameters: _ }) => Some(def),
@@ -1539,9 +1628,10 @@ impl Type {
     }
 
     pub fn is_fn(&self) -> bool {
-        matches!(&self.ty.value,
-            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnDef(..), .. }) |
-            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnPtr { .. }, .. })
+        matches!(
+            &self.ty.value,
+            Ty::Apply(ApplicationTy { ctor: TypeCtor::FnDef(..), .. })
+                | Ty::Apply(ApplicationTy { ctor: TypeCtor::FnPtr { .. }, .. })
         )
     }
 
@@ -1595,6 +1685,26 @@ impl Type {
         Vec::new()
     }
 
+    /// Returns the fields of `variant`, with the type arguments of this enum type substituted.
+    pub fn variant_fields(&self, db: &dyn HirDatabase, variant: Variant) -> Vec<(Field, Type)> {
+        if let Ty::Apply(a_ty) = &self.ty.value {
+            if a_ty.ctor != TypeCtor::Adt(AdtId::EnumId(variant.parent.id)) {
+                return Vec::new();
+            }
+            let variant_id: hir_def::VariantId = hir_def::EnumVariantId::from(variant).into();
+            return db
+                .field_types(variant_id)
+                .iter()
+                .map(|(local_id, ty)| {
+                    let def = Field { parent: variant.into(), id: local_id };
+                    let ty = ty.clone().subst(&a_ty.parameters);
+                    (def, self.derived(ty))
+                })
+                .collect();
+        }
+        Vec::new()
+    }
+
     pub fn tuple_fields(&self, _db: &dyn HirDatabase) -> Vec<Type> {
         let mut res = Vec::new();
         if let Ty::Apply(a_ty) = &self.ty.value {
@@ -1705,6 +1815,23 @@ impl Type {
         Some(adt.into())
     }
 
+    /// Returns the generic arguments of an ADT type, e.g. `i32` for `Option<i32>`.
+    pub fn type_arguments(&self) -> impl Iterator<Item = Type> + '_ {
+        self.ty
+            .value
+            .as_adt()
+            .into_iter()
+            .flat_map(|(_, substs)| substs.iter())
+            .map(move |ty| self.derived(ty.clone()))
+    }
+
+    pub fn as_type_param(&self) -> Option<TypeParam> {
+        match self.ty.value {
+            Ty::Placeholder(id) => Some(TypeParam { id }),
+            _ => None,
+        }
+    }
+
     pub fn as_dyn_trait(&self) -> Option<Trait> {
         self.ty.value.dyn_trait().map(Into::into)
     }
@@ -1903,7 +2030,7 @@ impl Callable {
 }
 
 /// For IDE only
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ScopeDef {
     ModuleDef(ModuleDef),
     MacroDef(MacroDef),
@@ -42,11 +42,12 @@ pub use crate::{
     semantics::{PathResolution, Semantics, SemanticsScope},
 };
 
+pub use cfg::{CfgAtom, CfgExpr};
 pub use hir_def::{
     adt::StructKind,
     attr::{Attrs, Documentation},
     body::scope::ExprScopes,
-    builtin_type::BuiltinType,
+    builtin_type::{BuiltinInt, BuiltinType},
     find_path::PrefixKind,
     import_map,
     item_scope::ItemInNs,
@@ -29,6 +29,10 @@ use crate::{
 pub struct Documentation(String);
 
 impl Documentation {
+    pub fn new(s: &str) -> Documentation {
+        Documentation(s.into())
+    }
+
     pub fn as_str(&self) -> &str {
         &self.0
     }
@@ -191,6 +195,12 @@ impl RawAttrs {
 impl Attrs {
     pub const EMPTY: Self = Self(RawAttrs::EMPTY);
 
+    /// Attributes of `owner` as written, without processing `cfg_attr`s. This
+    /// works for items without a definition, e.g. because they are cfg'd out.
+    pub fn from_syntax(owner: &dyn AttrsOwner) -> Attrs {
+        Attrs(RawAttrs::new(owner, &Hygiene::new_unhygienic()))
+    }
+
     pub(crate) fn attrs_query(db: &dyn DefDatabase, def: AttrDefId) -> Attrs {
         let raw_attrs = match def {
             AttrDefId::ModuleId(module) => {
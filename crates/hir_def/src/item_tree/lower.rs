@@ -349,6 +349,7 @@ impl Ctx {
             has_self_param,
             has_body,
             is_unsafe: func.unsafe_token().is_some(),
+            is_const: func.const_token().is_some(),
             is_extern: false,
             params: params.into_boxed_slice(),
             is_varargs,
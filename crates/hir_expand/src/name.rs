@@ -184,6 +184,7 @@ pub mod known {
         Neg,
         Not,
         Index,
+        Sized,
         // Builtin macros
         file,
         column,
@@ -2,7 +2,9 @@
 
 use std::sync::Arc;
 
-use hir_def::{expr::Statement, path::path, resolver::HasResolver, AdtId, DefWithBodyId};
+use hir_def::{
+    expr::Statement, path::path, resolver::HasResolver, AdtId, DefWithBodyId, HasModule,
+};
 use hir_expand::diagnostics::DiagnosticSink;
 use rustc_hash::FxHashSet;
 use syntax::{ast, AstPtr};
@@ -267,8 +269,15 @@ impl<'a, 'b> ExprValidator<'a, 'b> {
 
         match is_useful(&cx, &seen, &PatStack::from_wild()) {
             Ok(Usefulness::Useful) => (),
-            // if a wildcard pattern is not useful, then all patterns are covered
-            Ok(Usefulness::NotUseful) => return,
+            // if a wildcard pattern is not useful, then all patterns are covered,
+            // unless the enum is `#[non_exhaustive]` and needs a wildcard arm anyway
+            Ok(Usefulness::NotUseful) => {
+                if !self.is_foreign_non_exhaustive(db, match_expr_ty)
+                    || has_wildcard_arm(&cx.body, arms)
+                {
+                    return;
+                }
+            }
             // this path is for unimplemented checks, so we err on the side of not
             // reporting any errors
             _ => return,
@@ -290,6 +299,17 @@ impl<'a, 'b> ExprValidator<'a, 'b> {
         }
     }
 
+    /// Whether `ty` is a `#[non_exhaustive]` enum defined outside of the current crate.
+    fn is_foreign_non_exhaustive(&self, db: &dyn HirDatabase, ty: &Ty) -> bool {
+        let ty = ty.as_reference().map_or(ty, |(ty, _)| ty);
+        let adt = match ty.as_adt() {
+            Some((adt @ AdtId::EnumId(_), _)) => adt,
+            _ => return false,
+        };
+        adt.module(db.upcast()).krate != self.owner.module(db.upcast()).krate
+            && db.attrs(adt.into()).by_key("non_exhaustive").exists()
+    }
+
     fn validate_results_in_tail_expr(&mut self, body_id: ExprId, id: ExprId, db: &dyn HirDatabase) {
         // the mismatch will be on the whole block currently
         let mismatch = match self.infer.type_mismatch_for_expr(body_id) {
@@ -352,6 +372,12 @@ impl<'a, 'b> ExprValidator<'a, 'b> {
     }
 }
 
+fn has_wildcard_arm(body: &Body, arms: &[MatchArm]) -> bool {
+    arms.iter().any(|arm| {
+        arm.guard.is_none() && matches!(body[arm.pat], Pat::Wild | Pat::Bind { subpat: None, .. })
+    })
+}
+
 pub fn record_literal_missing_fields(
     db: &dyn HirDatabase,
     infer: &InferenceResult,
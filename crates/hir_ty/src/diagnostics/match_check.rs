@@ -1337,6 +1337,36 @@ fn bang(never: !) {
         );
     }
 
+    #[test]
+    fn non_exhaustive_enum_from_other_crate() {
+        check_diagnostics(
+            r#"
+//- /main.rs crate:main deps:lib
+#[non_exhaustive]
+enum Local { A, B }
+
+fn main() {
+    match lib::E::A {
+        //^^^^^^^^^ Missing match arm
+        lib::E::A => (),
+        lib::E::B => (),
+    }
+    match lib::E::A {
+        lib::E::A => (),
+        _ => (),
+    }
+    match Local::A {
+        Local::A => (),
+        Local::B => (),
+    }
+}
+//- /lib.rs crate:lib
+#[non_exhaustive]
+pub enum E { A, B }
+"#,
+        );
+    }
+
     #[test]
     fn or_pattern_panic() {
         check_diagnostics(
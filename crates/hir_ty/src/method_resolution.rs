@@ -21,8 +21,8 @@ use crate::{
     db::HirDatabase,
     primitive::{FloatBitness, FloatTy, IntTy},
     utils::all_super_traits,
-    ApplicationTy, Canonical, DebruijnIndex, InEnvironment, TraitEnvironment, TraitRef, Ty, TyKind,
-    TypeCtor, TypeWalk,
+    ApplicationTy, Canonical, DebruijnIndex, GenericPredicate, InEnvironment, TraitEnvironment,
+    TraitRef, Ty, TyKind, TypeCtor, TypeWalk,
 };
 
 /// This is used as a key for indexing impls.
@@ -535,9 +535,16 @@ fn iterate_trait_method_candidates(
     receiver_ty: Option<&Canonical<Ty>>,
     callback: &mut dyn FnMut(&Ty, AssocItemId) -> bool,
 ) -> bool {
-    // if ty is `dyn Trait`, the trait doesn't need to be in scope
-    let inherent_trait =
-        self_ty.value.dyn_trait().into_iter().flat_map(|t| all_super_traits(db.upcast(), t));
+    // if ty is `dyn Trait` or a return position `impl Trait`, the trait doesn't need to be in scope
+    let mut inherent_traits: Vec<TraitId> = self_ty.value.dyn_trait().into_iter().collect();
+    if let Ty::Opaque(_) = self_ty.value {
+        let bounds = self_ty.value.impl_trait_bounds(db).unwrap_or_default();
+        inherent_traits.extend(bounds.into_iter().filter_map(|pred| match pred {
+            GenericPredicate::Implemented(trait_ref) => Some(trait_ref.trait_),
+            _ => None,
+        }));
+    }
+    let inherent_trait = inherent_traits.into_iter().flat_map(|t| all_super_traits(db.upcast(), t));
     let env_traits = if let Ty::Placeholder(_) = self_ty.value {
         // if we have `T: Trait` in the param env, the trait doesn't need to be in scope
         env.trait_predicates_for_self_ty(&self_ty.value)
@@ -693,6 +693,20 @@ fn test() { (S::<u32>.foo(), S::<i32>.foo()); }
     );
 }
 
+#[test]
+fn method_resolution_return_position_impl_trait_not_in_scope() {
+    check_types(
+        r#"
+mod foo {
+    pub trait Trait { fn foo(&self) -> u128; }
+}
+fn bar() -> impl foo::Trait { loop {} }
+fn test() { bar().foo(); }
+                    //^ u128
+"#,
+    );
+}
+
 #[test]
 fn method_resolution_trait_before_autoref() {
     check_types(
@@ -4,8 +4,24 @@
 //! macro-expanded files, but we need to present them to the users in terms of
 //! original files. So we need to map the ranges.
 
+mod allow_lint;
+mod bare_trait_object;
+mod empty_impl;
 mod fixes;
 mod field_shorthand;
+mod float_equality;
+mod let_to_const;
+mod none_comparison;
+mod overflowing_literal;
+mod recursive_type;
+mod redundant_clone;
+mod shadowed_method;
+mod truncating_cast;
+mod unlinked_file;
+mod unnecessary_unwrap;
+mod unsatisfied_derive;
+mod unused_must_use;
+mod unused_param;
 
 use std::cell::RefCell;
 
@@ -13,13 +29,13 @@ use hir::{
     diagnostics::{Diagnostic as _, DiagnosticCode, DiagnosticSinkBuilder},
     Semantics,
 };
-use ide_db::base_db::SourceDatabase;
+use ide_db::base_db::{CrateId, SourceDatabase};
 use ide_db::RootDatabase;
 use itertools::Itertools;
 use rustc_hash::FxHashSet;
 use syntax::{
     ast::{self, AstNode},
-    SyntaxNode, TextRange, T,
+    SyntaxKind, SyntaxNode, TextRange, T,
 };
 use text_edit::TextEdit;
 
@@ -27,6 +43,8 @@ use crate::{FileId, Label, SourceChange, SourceFileEdit};
 
 use self::fixes::DiagnosticWithFix;
 
+pub(crate) use self::allow_lint::allow_lint_fix;
+
 #[derive(Debug)]
 pub struct Diagnostic {
     // pub name: Option<String>,
@@ -43,6 +61,10 @@ impl Diagnostic {
         Self { message, range, severity: Severity::Error, fix: None, unused: false, code: None }
     }
 
+    fn warning(range: TextRange, message: String) -> Self {
+        Self { message, range, severity: Severity::Warning, fix: None, unused: false, code: None }
+    }
+
     fn hint(range: TextRange, message: String) -> Self {
         Self {
             message,
@@ -85,6 +107,7 @@ impl Fix {
 #[derive(Debug, Copy, Clone)]
 pub enum Severity {
     Error,
+    Warning,
     WeakWarning,
 }
 
@@ -102,6 +125,7 @@ pub(crate) fn diagnostics(
     let _p = profile::span("diagnostics");
     let sema = Semantics::new(db);
     let parse = db.parse(file_id);
+    let source_file = sema.parse(file_id);
     let mut res = Vec::new();
 
     // [#34344] Only take first 128 errors to prevent slowing down editor/ide, the number 128 is chosen arbitrarily.
@@ -113,10 +137,29 @@ pub(crate) fn diagnostics(
             .map(|err| Diagnostic::error(err.range(), format!("Syntax Error: {}", err))),
     );
 
-    for node in parse.tree().syntax().descendants() {
+    for node in source_file.syntax().descendants() {
         check_unnecessary_braces_in_use_statement(&mut res, file_id, &node);
         field_shorthand::check(&mut res, file_id, &node);
+        unused_must_use::check(&mut res, &sema, file_id, &node);
+        unsatisfied_derive::check(&mut res, &sema, &node);
+        overflowing_literal::check(&mut res, &sema, &node);
+        bare_trait_object::check(&mut res, &sema, file_id, &node);
+        recursive_type::check(&mut res, &sema, file_id, &node);
+        // Lints which are a matter of taste or overlap with rustc's own.
+        if !config.disable_experimental {
+            unused_param::check(&mut res, &sema, file_id, &node);
+            redundant_clone::check(&mut res, &sema, file_id, &node);
+            float_equality::check(&mut res, &sema, &node);
+            let_to_const::check(&mut res, &sema, file_id, &node);
+            none_comparison::check(&mut res, &sema, file_id, &node);
+            shadowed_method::check(&mut res, &sema, &node);
+            unnecessary_unwrap::check(&mut res, &sema, file_id, &node);
+            truncating_cast::check(&mut res, &sema, &node);
+            empty_impl::check(&mut res, file_id, &node);
+        }
     }
+    unlinked_file::check(&mut res, &sema, file_id);
+    res.retain(|diag| diag.code.map_or(true, |code| !config.disabled.contains(code.as_str())));
     let res = RefCell::new(res);
     let sink_builder = DiagnosticSinkBuilder::new()
         .on::<hir::diagnostics::UnresolvedModule, _>(|d| {
@@ -125,6 +168,20 @@ pub(crate) fn diagnostics(
         .on::<hir::diagnostics::MissingFields, _>(|d| {
             res.borrow_mut().push(diagnostic_with_fix(d, &sema));
         })
+        .on::<hir::diagnostics::MissingMatchArms, _>(|d| {
+            let mut diagnostic = diagnostic_with_fix(d, &sema);
+            if let Some(pats) = fixes::missing_match_arm_pats(d, &sema) {
+                let pats = pats.iter().map(|pat| {
+                    // Only show the path of the variant, e.g. `E::A` for `E::A { x }`.
+                    pat.syntax().first_child().map_or_else(|| pat.to_string(), |it| it.to_string())
+                });
+                diagnostic.message = format!(
+                    "Missing match arms: {}",
+                    pats.map(|pat| format!("`{}`", pat)).format(", ")
+                );
+            }
+            res.borrow_mut().push(diagnostic);
+        })
         .on::<hir::diagnostics::MissingOkInTailExpr, _>(|d| {
             res.borrow_mut().push(diagnostic_with_fix(d, &sema));
         })
@@ -180,6 +237,44 @@ pub(crate) fn diagnostics(
     res.into_inner()
 }
 
+/// Computes diagnostics for every file of `krate`, sorted by file and range.
+pub(crate) fn crate_diagnostics(
+    db: &RootDatabase,
+    config: &DiagnosticsConfig,
+    krate: CrateId,
+) -> Vec<(FileId, Diagnostic)> {
+    let _p = profile::span("crate_diagnostics");
+    let krate = hir::Crate::from(krate);
+
+    let mut file_ids = FxHashSet::default();
+    let mut work = vec![krate.root_module(db)];
+    while let Some(module) = work.pop() {
+        file_ids.insert(module.definition_source(db).file_id.original_file(db));
+        work.extend(module.children(db));
+    }
+
+    let mut res = file_ids
+        .into_iter()
+        .flat_map(|file_id| {
+            diagnostics(db, config, file_id).into_iter().map(move |diag| (file_id, diag))
+        })
+        .collect::<Vec<_>>();
+    res.sort_by_key(|(file_id, diag)| (*file_id, diag.range.start(), diag.range.end()));
+    res
+}
+
+/// Checks whether `name` appears in any macro call in `body`. We don't look into
+/// macro expansions, so lints use this to conservatively assume such names are used.
+fn is_mentioned_in_macro_call(body: &ast::BlockExpr, name: &str) -> bool {
+    body.syntax()
+        .descendants()
+        .filter_map(ast::MacroCall::cast)
+        .filter_map(|call| call.token_tree())
+        .flat_map(|tt| tt.syntax().descendants_with_tokens())
+        .filter_map(|it| it.into_token())
+        .any(|token| token.kind() == SyntaxKind::IDENT && token.text().as_str() == name)
+}
+
 fn diagnostic_with_fix<D: DiagnosticWithFix>(d: &D, sema: &Semantics<RootDatabase>) -> Diagnostic {
     Diagnostic::error(sema.diagnostics_display_range(d).range, d.message())
         .with_fix(d.fix(&sema))
@@ -238,6 +333,7 @@ fn text_edit_for_remove_unnecessary_braces_with_self_in_use_statement(
 #[cfg(test)]
 mod tests {
     use expect_test::{expect, Expect};
+    use ide_db::helpers::FamousDefs;
     use stdx::trim_indent;
     use test_utils::assert_eq_text;
 
@@ -245,8 +341,7 @@ mod tests {
 
     /// Takes a multi-file input fixture with annotated cursor positions,
     /// and checks that:
-    ///  * a diagnostic is produced
-    ///  * this diagnostic fix trigger range touches the input cursor position
+    ///  * a diagnostic with a fix whose trigger range touches the input cursor position is produced
     ///  * that the contents of the file containing the cursor match `after` after the diagnostic fix is applied
     pub(super) fn check_fix(ra_fixture_before: &str, ra_fixture_after: &str) {
         let after = trim_indent(ra_fixture_after);
@@ -307,7 +402,10 @@ mod tests {
 
     /// Checks that a diagnostic applies to the file containing the `<|>` cursor marker
     /// which has a fix that can apply to other files.
-    fn check_apply_diagnostic_fix_in_other_file(ra_fixture_before: &str, ra_fixture_after: &str) {
+    pub(crate) fn check_apply_diagnostic_fix_in_other_file(
+        ra_fixture_before: &str,
+        ra_fixture_after: &str,
+    ) {
         let ra_fixture_after = &trim_indent(ra_fixture_after);
         let (analysis, file_pos) = fixture::position(ra_fixture_before);
         let current_file_id = file_pos.file_id;
@@ -341,12 +439,153 @@ mod tests {
         assert_eq!(diagnostics.len(), 0, "unexpected diagnostics:\n{:#?}", diagnostics);
     }
 
-    fn check_expect(ra_fixture: &str, expect: Expect) {
+    pub(crate) fn check_expect(ra_fixture: &str, expect: Expect) {
         let (analysis, file_id) = fixture::file(ra_fixture);
         let diagnostics = analysis.diagnostics(&DiagnosticsConfig::default(), file_id).unwrap();
         expect.assert_debug_eq(&diagnostics)
     }
 
+    #[test]
+    fn test_crate_diagnostics() {
+        let (analysis, files) = fixture::files(
+            r#"
+//- /lib.rs
+mod foo;
+fn bar(x: i32) {}
+//- /foo.rs
+fn baz(y: i32) {}
+"#,
+        );
+        let krate = analysis.crate_for(files[0]).unwrap()[0];
+        let diagnostics = analysis.crate_diagnostics(&DiagnosticsConfig::default(), krate).unwrap();
+        let actual = diagnostics
+            .iter()
+            .map(|(file_id, diagnostic)| (*file_id, diagnostic.message.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![(files[0], "Unused parameter `x`"), (files[1], "Unused parameter `y`")]
+        );
+    }
+
+    #[test]
+    fn experimental_lints_can_be_disabled() {
+        let (analysis, file_id) = fixture::file(&format!(
+            "//- /main.rs crate:main deps:core{}{}",
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+
+fn unused_param(x: i32) {}
+fn redundant_clone() {
+    let x = S;
+    let _y = x.clone();
+}
+fn float_cmp(a: f64) -> bool { a == 1.0 }
+fn let_to_const() -> i32 {
+    let x = 92;
+    x
+}
+fn partialeq_to_none(x: Option<i32>) -> bool { x == None }
+struct Shadowed;
+impl Shadowed { fn len(&self) -> usize { 0 } }
+trait Len { fn len(&self) -> usize; }
+impl Len for Shadowed { fn len(&self) -> usize { 1 } }
+fn unnecessary_literal_unwrap() -> i32 { Some(5).unwrap() }
+fn cast_possible_truncation(x: u32) -> u8 { x as u8 }
+impl S {}
+"#,
+            FamousDefs::FIXTURE
+        ));
+        let config = DiagnosticsConfig { disable_experimental: true, ..Default::default() };
+        let diagnostics = analysis.diagnostics(&config, file_id).unwrap();
+        assert!(diagnostics.is_empty(), "unexpected diagnostics:\n{:#?}", diagnostics);
+    }
+
+    #[test]
+    fn test_fill_missing_match_arms() {
+        check_fix(
+            r#"
+enum E { A, B(i32), C { x: i32 } }
+fn main(e: E) {
+    match e {
+        E::A => (),<|>
+    }
+}
+"#,
+            r#"
+enum E { A, B(i32), C { x: i32 } }
+fn main(e: E) {
+    match e {
+        E::A => (),
+        E::B(_) => {}
+        E::C { x } => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_missing_match_arms_message_lists_variants() {
+        let (analysis, file_id) = fixture::file(
+            r#"
+enum E { A, B(i32), C { x: i32 } }
+fn main(e: E) {
+    match e {
+        E::A => (),
+    }
+}
+"#,
+        );
+        let diagnostics = analysis.diagnostics(&DiagnosticsConfig::default(), file_id).unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].message, "Missing match arms: `E::B`, `E::C`");
+    }
+
+    #[test]
+    fn test_fill_wildcard_arm_for_non_exhaustive_enum() {
+        check_fix(
+            r#"
+//- /main.rs crate:main deps:lib
+fn main(e: lib::E) {
+    match e {
+        lib::E::A => (),
+        lib::E::B => (),<|>
+    }
+}
+//- /lib.rs crate:lib
+#[non_exhaustive]
+pub enum E { A, B }
+"#,
+            r#"
+fn main(e: lib::E) {
+    match e {
+        lib::E::A => (),
+        lib::E::B => (),
+        _ => {}
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_no_diagnostic_for_complete_match() {
+        check_no_diagnostics(
+            r#"
+enum E { A, B(i32) }
+fn main(e: E) {
+    match e {
+        E::A => (),
+        E::B(_) => (),
+    }
+}
+"#,
+        );
+    }
+
     #[test]
     fn test_wrap_return_type() {
         check_fix(
@@ -0,0 +1,169 @@
+//! Offers to silence a lint by adding `#[allow(lint)]` to the item containing the diagnostic.
+
+use hir::diagnostics::DiagnosticCode;
+use ide_db::{
+    base_db::{FileId, SourceDatabase},
+    source_change::SourceFileEdit,
+    RootDatabase,
+};
+use syntax::{
+    algo::{find_covering_element, skip_trivia_token},
+    ast::{self, edit::IndentLevel, AttrsOwner},
+    AstNode, Direction, NodeOrToken, SyntaxKind, T,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(crate) fn allow_lint_fix(
+    db: &RootDatabase,
+    file_id: FileId,
+    diagnostic: &Diagnostic,
+) -> Option<Fix> {
+    let lint = lint_name(diagnostic.code?)?;
+    let source_file = db.parse(file_id).tree();
+    let node = match find_covering_element(source_file.syntax(), diagnostic.range) {
+        NodeOrToken::Node(it) => it,
+        NodeOrToken::Token(it) => it.parent(),
+    };
+    let item = node.ancestors().find_map(ast::Item::cast)?;
+
+    let existing_allow = item.attrs().find(|attr| attr.simple_name().as_deref() == Some("allow"));
+    let edit = match existing_allow.and_then(|attr| attr.token_tree()) {
+        Some(tt) => {
+            let allows_lint = tt
+                .syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .any(|it| it.kind() == SyntaxKind::IDENT && it.text() == lint);
+            if allows_lint {
+                return None;
+            }
+            let r_paren = tt.r_paren_token()?;
+            let needs_comma = r_paren
+                .prev_token()
+                .and_then(|it| skip_trivia_token(it, Direction::Prev))
+                .map_or(false, |it| it.kind() != T!['('] && it.kind() != T![,]);
+            let text = if needs_comma { format!(", {}", lint) } else { lint.to_string() };
+            TextEdit::insert(r_paren.text_range().start(), text)
+        }
+        None => {
+            // Keep doc comments and other attributes above the new one.
+            let anchor = item.syntax().children_with_tokens().find(|it| {
+                !matches!(
+                    it.kind(),
+                    SyntaxKind::ATTR | SyntaxKind::COMMENT | SyntaxKind::WHITESPACE
+                )
+            })?;
+            let indent = IndentLevel::from_node(item.syntax());
+            TextEdit::insert(anchor.text_range().start(), format!("#[allow({})]\n{}", lint, indent))
+        }
+    };
+
+    Some(Fix::new("Allow this lint", SourceFileEdit { file_id, edit }.into(), diagnostic.range))
+}
+
+/// Maps a diagnostic code to the name of the rustc lint it mirrors.
+fn lint_name(code: DiagnosticCode) -> Option<&'static str> {
+    let lint = match code.as_str() {
+        "unused-variables" => "unused_variables",
+        "unused-must-use" => "unused_must_use",
+        "bare-trait-objects" => "bare_trait_objects",
+        "overflowing-literals" => "overflowing_literals",
+        _ => return None,
+    };
+    Some(lint)
+}
+
+#[cfg(test)]
+mod tests {
+    use stdx::trim_indent;
+    use test_utils::assert_eq_text;
+
+    use crate::{fixture, DiagnosticsConfig};
+
+    fn check(ra_fixture_before: &str, ra_fixture_after: &str) {
+        let after = trim_indent(ra_fixture_after);
+        let (analysis, file_position) = fixture::position(ra_fixture_before);
+        let mut fix = analysis
+            .diagnostics(&DiagnosticsConfig::default(), file_position.file_id)
+            .unwrap()
+            .iter()
+            .filter(|it| it.range.contains_inclusive(file_position.offset))
+            .find_map(|it| analysis.allow_lint_fix(file_position.file_id, it).unwrap())
+            .unwrap();
+        let edit = fix.source_change.source_file_edits.pop().unwrap().edit;
+        let mut actual = analysis.file_text(file_position.file_id).unwrap().to_string();
+        edit.apply(&mut actual);
+        assert_eq_text!(&after, &actual);
+    }
+
+    fn check_not_applicable(ra_fixture: &str) {
+        let (analysis, file_position) = fixture::position(ra_fixture);
+        let fixes = analysis
+            .diagnostics(&DiagnosticsConfig::default(), file_position.file_id)
+            .unwrap()
+            .iter()
+            .filter_map(|it| analysis.allow_lint_fix(file_position.file_id, it).unwrap())
+            .count();
+        assert_eq!(fixes, 0);
+    }
+
+    #[test]
+    fn allow_unused_variables() {
+        check(
+            r#"
+/// Docs.
+fn foo(x<|>: i32) {}
+"#,
+            r#"
+/// Docs.
+#[allow(unused_variables)]
+fn foo(x: i32) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn allow_in_impl() {
+        check(
+            r#"
+struct S;
+impl S {
+    fn foo(&self, x<|>: i32) {}
+}
+"#,
+            r#"
+struct S;
+impl S {
+    #[allow(unused_variables)]
+    fn foo(&self, x: i32) {}
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn extends_existing_allow() {
+        check(
+            r#"
+#[allow(dead_code)]
+fn foo(x<|>: i32) {}
+"#,
+            r#"
+#[allow(dead_code, unused_variables)]
+fn foo(x: i32) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn already_allowed() {
+        check_not_applicable(
+            r#"
+#[allow(dead_code, unused_variables)]
+fn foo(x<|>: i32) {}
+"#,
+        );
+    }
+}
@@ -0,0 +1,99 @@
+//! Suggests writing trait object types like `Box<Trait>` as `Box<dyn Trait>`.
+
+use hir::{diagnostics::DiagnosticCode, ModuleDef, PathResolution, Semantics};
+use ide_db::{base_db::FileId, source_change::SourceFileEdit, RootDatabase};
+use syntax::{ast, AstNode, SyntaxKind, SyntaxNode};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let path_type = ast::PathType::cast(node.clone())?;
+    let parent = path_type.syntax().parent()?;
+    // Bounds, `impl Trait for` headers and `<T as Trait>` qualifiers name the trait itself.
+    match parent.kind() {
+        SyntaxKind::TYPE_BOUND | SyntaxKind::PATH_SEGMENT => return None,
+        SyntaxKind::IMPL => {
+            let impl_ = ast::Impl::cast(parent)?;
+            if impl_.for_token().is_some()
+                && impl_.trait_().map_or(false, |it| it.syntax() == path_type.syntax())
+            {
+                return None;
+            }
+        }
+        _ => {}
+    }
+    match sema.resolve_path(&path_type.path()?)? {
+        PathResolution::Def(ModuleDef::Trait(_)) => {}
+        _ => return None,
+    }
+
+    let range = path_type.syntax().text_range();
+    let edit = TextEdit::insert(range.start(), "dyn ".to_string());
+    acc.push(
+        Diagnostic::hint(
+            range,
+            "Trait objects without an explicit `dyn` are deprecated".to_string(),
+        )
+        .with_fix(Some(Fix::new("Add `dyn`", SourceFileEdit { file_id, edit }.into(), range)))
+        .with_code(Some(DiagnosticCode("bare-trait-objects"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_bare_trait_object() {
+        check_fix(
+            r#"
+struct Box<T: ?Sized>(*const T);
+trait Trait {}
+fn foo(_b: Box<Tr<|>ait>) {}
+"#,
+            r#"
+struct Box<T: ?Sized>(*const T);
+trait Trait {}
+fn foo(_b: Box<dyn Trait>) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_bare_trait_object_reference() {
+        check_fix(
+            r#"
+trait Trait {}
+fn foo(_t: &Trait<|>) {}
+"#,
+            r#"
+trait Trait {}
+fn foo(_t: &dyn Trait) {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_trait_references_without_objects() {
+        check_no_diagnostics(
+            r#"
+struct Box<T: ?Sized>(*const T);
+trait Trait { fn foo(); }
+trait Sub: Trait {}
+struct S;
+impl Trait for S { fn foo() {} }
+fn dyn_object(_b: Box<dyn Trait>) {}
+fn bounds<T: Trait>(_t: T) -> impl Trait where T: Sub { S }
+fn qualified<T: Trait>() { <T as Trait>::foo(); }
+"#,
+        );
+    }
+}
@@ -0,0 +1,94 @@
+//! Reports inherent `impl` blocks without any items, e.g. `impl Foo {}`.
+
+use hir::diagnostics::DiagnosticCode;
+use ide_db::{base_db::FileId, source_change::SourceFileEdit};
+use syntax::{ast, AstNode, NodeOrToken, SyntaxKind, SyntaxNode, TextRange};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(acc: &mut Vec<Diagnostic>, file_id: FileId, node: &SyntaxNode) -> Option<()> {
+    let impl_ = ast::Impl::cast(node.clone())?;
+    // An empty trait impl still implements the trait, which matters for marker
+    // traits and for asserting bounds.
+    if impl_.trait_().is_some() {
+        return None;
+    }
+    let item_list = impl_.assoc_item_list()?;
+    let is_empty = item_list.syntax().children_with_tokens().all(|it| {
+        matches!(it.kind(), SyntaxKind::L_CURLY | SyntaxKind::R_CURLY | SyntaxKind::WHITESPACE)
+    });
+    if !is_empty {
+        return None;
+    }
+
+    let impl_range = impl_.syntax().text_range();
+    let delete_range = match impl_.syntax().prev_sibling_or_token() {
+        Some(NodeOrToken::Token(ws)) if ws.kind() == SyntaxKind::WHITESPACE => {
+            TextRange::new(ws.text_range().start(), impl_range.end())
+        }
+        _ => impl_range,
+    };
+    let edit = TextEdit::delete(delete_range);
+    acc.push(
+        Diagnostic::hint(impl_range, "Empty `impl` block has no effect".to_string())
+            .with_fix(Some(Fix::new(
+                "Remove empty `impl` block",
+                SourceFileEdit { file_id, edit }.into(),
+                impl_range,
+            )))
+            .with_unused(true)
+            .with_code(Some(DiagnosticCode("empty-impl"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_empty_inherent_impl() {
+        check_fix(
+            r#"
+struct Foo;
+
+impl Foo {<|>}
+
+fn main() {}
+"#,
+            r#"
+struct Foo;
+
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_empty_trait_impl() {
+        check_no_diagnostics(
+            r#"
+trait Marker {}
+struct Foo;
+impl Marker for Foo {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_impl_with_items_or_comments() {
+        check_no_diagnostics(
+            r#"
+struct Foo;
+impl Foo {
+    fn new() -> Foo { Foo }
+}
+impl Foo {
+    // TODO: add the builder methods
+}
+"#,
+        );
+    }
+}
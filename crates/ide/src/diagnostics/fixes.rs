@@ -1,15 +1,18 @@
 //! Provides a way to attach fixes to the diagnostics.
 //! The same module also has all curret custom fixes for the diagnostics implemented.
+use std::iter;
+
 use hir::{
     db::AstDatabase,
     diagnostics::{
-        Diagnostic, IncorrectCase, MissingFields, MissingOkInTailExpr, NoSuchField,
-        RemoveThisSemicolon, UnresolvedModule,
+        Diagnostic, IncorrectCase, MissingFields, MissingMatchArms, MissingOkInTailExpr,
+        NoSuchField, RemoveThisSemicolon, UnresolvedModule,
     },
-    HasSource, HirDisplay, InFile, Semantics, VariantDef,
+    HasAttrs, HasSource, HirDisplay, InFile, Semantics, VariantDef,
 };
 use ide_db::base_db::{AnchoredPathBuf, FileId};
 use ide_db::{
+    helpers::match_arms::{build_pat, is_variant_missing, resolve_enum_def},
     source_change::{FileSystemEdit, SourceFileEdit},
     RootDatabase,
 };
@@ -94,6 +97,59 @@ impl DiagnosticWithFix for MissingFields {
     }
 }
 
+impl DiagnosticWithFix for MissingMatchArms {
+    fn fix(&self, sema: &Semantics<RootDatabase>) -> Option<Fix> {
+        let root = sema.db.parse_or_expand(self.file)?;
+        let old_arm_list = self.arms.to_node(&root);
+        let missing_arms = missing_match_arm_pats(self, sema)?
+            .into_iter()
+            .map(|pat| make::match_arm(iter::once(pat), make::expr_empty_block()));
+        let new_arm_list = old_arm_list.append_arms(missing_arms);
+
+        let edit = {
+            let mut builder = TextEdit::builder();
+            algo::diff(&old_arm_list.syntax(), &new_arm_list.syntax()).into_text_edit(&mut builder);
+            builder.finish()
+        };
+        Some(Fix::new(
+            "Fill match arms",
+            SourceFileEdit { file_id: self.file.original_file(sema.db), edit }.into(),
+            sema.original_range(&old_arm_list.syntax()).range,
+        ))
+    }
+}
+
+/// Patterns for the enum variants `d` doesn't cover. A `#[non_exhaustive]`
+/// enum from another crate additionally needs a wildcard.
+pub(super) fn missing_match_arm_pats(
+    d: &MissingMatchArms,
+    sema: &Semantics<RootDatabase>,
+) -> Option<Vec<ast::Pat>> {
+    let root = sema.db.parse_or_expand(d.file)?;
+    let match_expr = d.match_expr.to_node(&root);
+    let arms: Vec<ast::MatchArm> = d.arms.to_node(&root).arms().collect();
+
+    let module = sema.scope(match_expr.syntax()).module()?;
+    let enum_def = resolve_enum_def(sema, &match_expr)?;
+    let mut pats: Vec<ast::Pat> = enum_def
+        .variants(sema.db)
+        .into_iter()
+        .filter_map(|variant| build_pat(sema.db, module, variant))
+        .filter(|pat| is_variant_missing(&arms, pat))
+        .collect();
+    if enum_def.module(sema.db).krate() != module.krate()
+        && enum_def.attrs(sema.db).by_key("non_exhaustive").exists()
+    {
+        pats.push(make::wildcard_pat().into());
+    }
+
+    if pats.is_empty() {
+        None
+    } else {
+        Some(pats)
+    }
+}
+
 impl DiagnosticWithFix for MissingOkInTailExpr {
     fn fix(&self, sema: &Semantics<RootDatabase>) -> Option<Fix> {
         let root = sema.db.parse_or_expand(self.file)?;
@@ -0,0 +1,100 @@
+//! Warns about comparing floating-point values for exact equality, e.g. `a == b` with `a: f64`.
+
+use hir::{diagnostics::DiagnosticCode, Semantics};
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, BinOp},
+    AstNode, SyntaxNode,
+};
+
+use crate::Diagnostic;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let bin_expr = ast::BinExpr::cast(node.clone())?;
+    let op = match bin_expr.op_kind()? {
+        BinOp::EqualityTest => "==",
+        BinOp::NegatedEqualityTest => "!=",
+        _ => return None,
+    };
+    let is_float = |expr: Option<ast::Expr>| {
+        expr.and_then(|it| sema.type_of_expr(&it)).map_or(false, |ty| ty.is_float())
+    };
+    if !is_float(bin_expr.lhs()) || !is_float(bin_expr.rhs()) {
+        return None;
+    }
+
+    acc.push(
+        Diagnostic::warning(
+            bin_expr.syntax().text_range(),
+            format!(
+                "Comparing floating-point values with `{}` is imprecise; consider checking \
+                 whether their difference is smaller than an epsilon instead",
+                op
+            ),
+        )
+        .with_code(Some(DiagnosticCode("float-cmp"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::diagnostics::tests::{check_expect, check_no_diagnostics};
+
+    #[test]
+    fn test_float_equality() {
+        check_expect(
+            r#"
+fn foo(a: f64, b: f64) -> bool {
+    a == b || a * 2.0 != b
+}
+"#,
+            expect![[r#"
+                [
+                    Diagnostic {
+                        message: "Comparing floating-point values with `==` is imprecise; consider checking whether their difference is smaller than an epsilon instead",
+                        range: 37..43,
+                        severity: Warning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "float-cmp",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Comparing floating-point values with `!=` is imprecise; consider checking whether their difference is smaller than an epsilon instead",
+                        range: 47..59,
+                        severity: Warning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "float-cmp",
+                            ),
+                        ),
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_non_float_equality() {
+        check_no_diagnostics(
+            r#"
+fn foo(a: i32, b: u64, c: f64) -> bool {
+    a == 1 && b != 2 && c < 1.0
+}
+"#,
+        );
+    }
+}
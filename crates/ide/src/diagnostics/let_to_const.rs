@@ -0,0 +1,164 @@
+//! Suggests turning `let` bindings of constant expressions, e.g. `let x = 92;`, into `const` items.
+
+use hir::{diagnostics::DiagnosticCode, HirDisplay, Semantics};
+use ide_db::{
+    base_db::FileId, defs::Definition, search::ReferenceKind, source_change::SourceFileEdit,
+    RootDatabase,
+};
+use syntax::{
+    ast::{self, NameOwner, PrefixOp},
+    AstNode, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let let_stmt = ast::LetStmt::cast(node.clone())?;
+    let ident_pat = match let_stmt.pat()? {
+        ast::Pat::IdentPat(it) => it,
+        _ => return None,
+    };
+    if ident_pat.mut_token().is_some()
+        || ident_pat.ref_token().is_some()
+        || ident_pat.pat().is_some()
+    {
+        return None;
+    }
+    let name = ident_pat.name()?;
+    if name.text().starts_with('_') {
+        return None;
+    }
+    let initializer = let_stmt.initializer()?;
+    if !is_const_expr(&initializer) {
+        return None;
+    }
+
+    let local = sema.to_def(&ident_pat)?;
+    let ty = match let_stmt.ty() {
+        Some(it) => it.to_string(),
+        None => {
+            let ty = local.ty(sema.db);
+            if ty.is_unknown() {
+                return None;
+            }
+            ty.display_source_code(sema.db, local.module(sema.db).into()).ok()?
+        }
+    };
+
+    // Unused bindings are reported as such, and shorthands like `S { x }` can't simply be
+    // renamed, so leave those alone.
+    let usages = Definition::Local(local).usages(sema).all();
+    if usages.is_empty()
+        || usages.iter().any(|it| {
+            it.file_range.file_id != file_id || it.kind == ReferenceKind::FieldShorthandForLocal
+        })
+    {
+        return None;
+    }
+
+    let const_name = stdx::to_upper_snake_case(name.text());
+    let mut edit = TextEdit::builder();
+    edit.replace(
+        let_stmt.syntax().text_range(),
+        format!("const {}: {} = {};", const_name, ty, initializer),
+    );
+    for usage in usages {
+        edit.replace(usage.file_range.range, const_name.clone());
+    }
+
+    let range = name.syntax().text_range();
+    acc.push(
+        Diagnostic::hint(range, format!("Binding `{}` could be a `const`", name.text()))
+            .with_fix(Some(Fix::new(
+                "Convert to `const`",
+                SourceFileEdit { file_id, edit: edit.finish() }.into(),
+                range,
+            )))
+            .with_code(Some(DiagnosticCode("let-to-const"))),
+    );
+
+    Some(())
+}
+
+/// Checks whether `expr` consists only of literals and operators, so that it can be
+/// evaluated at compile time without knowing anything about the surrounding code.
+fn is_const_expr(expr: &ast::Expr) -> bool {
+    match expr {
+        ast::Expr::Literal(_) => true,
+        ast::Expr::ParenExpr(it) => it.expr().map_or(false, |it| is_const_expr(&it)),
+        ast::Expr::PrefixExpr(it) => {
+            matches!(it.op_kind(), Some(PrefixOp::Neg) | Some(PrefixOp::Not))
+                && it.expr().map_or(false, |it| is_const_expr(&it))
+        }
+        ast::Expr::BinExpr(it) => {
+            // Assignments need a place on the left, which literals aren't.
+            it.op_kind().is_some()
+                && it.lhs().map_or(false, |it| is_const_expr(&it))
+                && it.rhs().map_or(false, |it| is_const_expr(&it))
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_let_to_const() {
+        check_fix(
+            r#"
+fn foo() -> i32 {
+    let max_len<|> = 2 * 46;
+    max_len + max_len
+}
+"#,
+            r#"
+fn foo() -> i32 {
+    const MAX_LEN: i32 = 2 * 46;
+    MAX_LEN + MAX_LEN
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_let_to_const_keeps_annotation() {
+        check_fix(
+            r#"
+fn foo() -> &'static str {
+    let greeting<|>: &'static str = "hello";
+    greeting
+}
+"#,
+            r#"
+fn foo() -> &'static str {
+    const GREETING: &'static str = "hello";
+    GREETING
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_non_const_let() {
+        check_no_diagnostics(
+            r#"
+fn bar() -> i32 { 92 }
+fn foo(x: i32) -> i32 {
+    let a = bar();
+    let b = x + 1;
+    let mut c = 1;
+    c += 1;
+    a + b + c
+}
+"#,
+        );
+    }
+}
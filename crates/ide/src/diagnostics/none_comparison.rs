@@ -0,0 +1,141 @@
+//! Suggests `x.is_none()` and `x.is_some()` over comparing an `Option` with `None`.
+
+use hir::{diagnostics::DiagnosticCode, PathResolution, Semantics};
+use ide_db::{base_db::FileId, helpers::FamousDefs, source_change::SourceFileEdit, RootDatabase};
+use syntax::{
+    ast::{self, BinOp},
+    AstNode, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let bin_expr = ast::BinExpr::cast(node.clone())?;
+    let method = match bin_expr.op_kind()? {
+        BinOp::EqualityTest => "is_none",
+        BinOp::NegatedEqualityTest => "is_some",
+        _ => return None,
+    };
+    let (lhs, rhs) = (bin_expr.lhs()?, bin_expr.rhs()?);
+    let option = if is_none(sema, &rhs) {
+        lhs
+    } else if is_none(sema, &lhs) {
+        rhs
+    } else {
+        return None;
+    };
+
+    let krate = sema.scope(bin_expr.syntax()).module()?.krate();
+    let option_enum = FamousDefs(sema, Some(krate)).core_option_Option()?;
+    if sema.type_of_expr(&option)?.as_adt() != Some(option_enum.into()) {
+        return None;
+    }
+
+    let receiver = match option {
+        ast::Expr::PathExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::IndexExpr(_)
+        | ast::Expr::ParenExpr(_) => option.to_string(),
+        _ => format!("({})", option),
+    };
+    let range = bin_expr.syntax().text_range();
+    let edit = TextEdit::replace(range, format!("{}.{}()", receiver, method));
+    acc.push(
+        Diagnostic::hint(range, format!("Use `.{}()` instead of comparing with `None`", method))
+            .with_fix(Some(Fix::new(
+                &format!("Replace with `.{}()`", method),
+                SourceFileEdit { file_id, edit }.into(),
+                range,
+            )))
+            .with_code(Some(DiagnosticCode("partialeq-to-none"))),
+    );
+
+    Some(())
+}
+
+fn is_none(sema: &Semantics<RootDatabase>, expr: &ast::Expr) -> bool {
+    let path = match expr {
+        ast::Expr::PathExpr(it) => it.path(),
+        _ => return false,
+    };
+    match path.and_then(|it| sema.resolve_path(&it)) {
+        Some(PathResolution::Def(hir::ModuleDef::Variant(variant))) => {
+            variant.name(sema.db).to_string() == "None"
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use ide_db::helpers::FamousDefs;
+
+    use crate::diagnostics::tests::{check_expect, check_fix};
+
+    fn check_none_fix(before: &str, after: &str) {
+        check_fix(
+            &format!("//- /main.rs crate:main deps:core{}{}", before, FamousDefs::FIXTURE),
+            after,
+        );
+    }
+
+    #[test]
+    fn test_eq_none() {
+        check_none_fix(
+            r#"
+fn foo(x: Option<i32>) -> bool {
+    x <|>== None
+}
+"#,
+            r#"
+fn foo(x: Option<i32>) -> bool {
+    x.is_none()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_ne_none() {
+        check_none_fix(
+            r#"
+fn foo(x: Option<i32>) -> bool {
+    None <|>!= x
+}
+"#,
+            r#"
+fn foo(x: Option<i32>) -> bool {
+    x.is_some()
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_other_comparisons() {
+        check_expect(
+            &format!(
+                "//- /main.rs crate:main deps:core{}{}",
+                r#"
+enum Maybe { None, Some(i32) }
+fn foo(x: Option<i32>, y: Maybe) -> bool {
+    x == Some(1) && y == Maybe::None
+}
+"#,
+                FamousDefs::FIXTURE
+            ),
+            expect![[r#"
+                []
+            "#]],
+        );
+    }
+}
@@ -0,0 +1,143 @@
+//! Reports integer literals which don't fit into their type, e.g. `let x: u8 = 300;`.
+
+use hir::{diagnostics::DiagnosticCode, HirDisplay, Semantics};
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, LiteralKind, PrefixOp},
+    AstNode, SyntaxNode,
+};
+
+use crate::Diagnostic;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let literal = ast::Literal::cast(node.clone())?;
+    let int_number = match literal.kind() {
+        LiteralKind::IntNumber(it) => it,
+        _ => return None,
+    };
+    let ty = sema.type_of_expr(&literal.clone().into())?;
+    let (min, max) = ty.int_bounds()?;
+
+    // `-128i8` is fine even though `128i8` isn't.
+    let is_negated = literal
+        .syntax()
+        .parent()
+        .and_then(ast::PrefixExpr::cast)
+        .map_or(false, |prefix| prefix.op_kind() == Some(PrefixOp::Neg));
+    let limit = match (is_negated, min) {
+        // Negating an unsigned integer is a type error instead.
+        (true, 0) => return None,
+        (true, min) => (min as u128).wrapping_neg(),
+        (false, _) => max,
+    };
+
+    let overflows = match int_number.value() {
+        Some(value) => value > limit,
+        // Doesn't even fit into `u128`.
+        None => true,
+    };
+    if overflows {
+        acc.push(
+            Diagnostic::error(
+                literal.syntax().text_range(),
+                format!("Literal out of range for `{}`", ty.display(sema.db)),
+            )
+            .with_code(Some(DiagnosticCode("overflowing-literals"))),
+        );
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::diagnostics::tests::{check_expect, check_no_diagnostics};
+
+    #[test]
+    fn test_overflowing_literals() {
+        check_expect(
+            r#"
+const C: u8 = 256;
+fn main() {
+    let x: u8 = 300;
+    let y = -129i8;
+    let z: u128 = 340282366920938463463374607431768211456;
+}
+"#,
+            expect![[r#"
+                [
+                    Diagnostic {
+                        message: "Literal out of range for `u8`",
+                        range: 14..17,
+                        severity: Error,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "overflowing-literals",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Literal out of range for `u8`",
+                        range: 47..50,
+                        severity: Error,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "overflowing-literals",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Literal out of range for `i8`",
+                        range: 65..70,
+                        severity: Error,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "overflowing-literals",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Literal out of range for `u128`",
+                        range: 90..129,
+                        severity: Error,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "overflowing-literals",
+                            ),
+                        ),
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_literals_in_range() {
+        check_no_diagnostics(
+            r#"
+const C: u8 = 255;
+static S: i8 = -128;
+fn main() {
+    let x: u8 = 0xff;
+    let y = 127i8;
+    let z: i64 = -9_223_372_036_854_775_808;
+    let w: usize = 18446744073709551615;
+}
+"#,
+        );
+    }
+}
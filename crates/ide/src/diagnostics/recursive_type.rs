@@ -0,0 +1,169 @@
+//! Reports structs and enums which contain themselves by value, e.g. `struct List { next: List }`,
+//! and suggests boxing the offending field.
+
+use hir::{diagnostics::DiagnosticCode, Adt, Semantics, Type};
+use ide_db::{base_db::FileId, source_change::SourceFileEdit, RootDatabase};
+use syntax::{ast, match_ast, AstNode, SyntaxNode};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let (adt, field_lists): (Adt, Vec<ast::FieldList>) = match_ast! {
+        match node {
+            ast::Struct(it) => (sema.to_def(&it)?.into(), it.field_list().into_iter().collect()),
+            ast::Enum(it) => (
+                sema.to_def(&it)?.into(),
+                it.variant_list()?.variants().filter_map(|it| it.field_list()).collect(),
+            ),
+            _ => return None,
+        }
+    };
+    let name = adt.name(sema.db);
+
+    for field_list in field_lists {
+        let fields: Vec<(ast::Type, hir::Field)> = match field_list {
+            ast::FieldList::RecordFieldList(it) => {
+                it.fields().filter_map(|field| Some((field.ty()?, sema.to_def(&field)?))).collect()
+            }
+            ast::FieldList::TupleFieldList(it) => {
+                it.fields().filter_map(|field| Some((field.ty()?, sema.to_def(&field)?))).collect()
+            }
+        };
+        for (ty, field) in fields {
+            if !contains_by_value(sema.db, &field.signature_ty(sema.db), adt, &mut Vec::new()) {
+                continue;
+            }
+            let range = ty.syntax().text_range();
+            let edit = TextEdit::replace(range, format!("Box<{}>", ty));
+            acc.push(
+                Diagnostic::error(range, format!("Recursive type `{}` has infinite size", name))
+                    .with_fix(Some(Fix::new(
+                        "Insert `Box` indirection",
+                        SourceFileEdit { file_id, edit }.into(),
+                        range,
+                    )))
+                    .with_code(Some(DiagnosticCode("recursive-type"))),
+            );
+        }
+    }
+
+    Some(())
+}
+
+/// Checks whether a value of type `ty` stores a value of `target` inline, either
+/// directly or through the fields of other structs, enums and tuples.
+fn contains_by_value(db: &RootDatabase, ty: &Type, target: Adt, visited: &mut Vec<Type>) -> bool {
+    if ty.tuple_fields(db).iter().any(|it| contains_by_value(db, it, target, visited)) {
+        return true;
+    }
+    let adt = match ty.as_adt() {
+        Some(it) => it,
+        None => return false,
+    };
+    if adt == target {
+        return true;
+    }
+    // Generic types are visited once per instantiation. Types which keep growing
+    // their type arguments, like `struct W<T>(Option<W<(T,)>>)`, are cut off.
+    if visited.contains(ty) || visited.len() > 64 {
+        return false;
+    }
+    visited.push(ty.clone());
+    match adt {
+        Adt::Struct(_) | Adt::Union(_) => {
+            ty.fields(db).iter().any(|(_, ty)| contains_by_value(db, ty, target, visited))
+        }
+        Adt::Enum(it) => it
+            .variants(db)
+            .into_iter()
+            .flat_map(|variant| ty.variant_fields(db, variant))
+            .any(|(_, ty)| contains_by_value(db, &ty, target, visited)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_recursive_struct() {
+        check_fix(
+            r#"
+struct Node {
+    value: i32,
+    next: Node<|>,
+}
+"#,
+            r#"
+struct Node {
+    value: i32,
+    next: Box<Node>,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_mutually_recursive_types() {
+        check_fix(
+            r#"
+struct Expr {
+    op: Op,
+}
+enum Op {
+    Neg(Expr<|>),
+    Lit(i32),
+}
+"#,
+            r#"
+struct Expr {
+    op: Op,
+}
+enum Op {
+    Neg(Box<Expr>),
+    Lit(i32),
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_recursive_struct_through_generic_enum() {
+        check_fix(
+            r#"
+enum Option<T> { None, Some(T) }
+struct List {
+    value: i32,
+    next: Option<Self><|>,
+}
+"#,
+            r#"
+enum Option<T> { None, Some(T) }
+struct List {
+    value: i32,
+    next: Box<Option<Self>>,
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_boxed_recursive_struct() {
+        check_no_diagnostics(
+            r#"
+struct Box<T>(*const T);
+struct Node {
+    value: i32,
+    next: Box<Node>,
+    children: (i32, Box<Node>),
+}
+"#,
+        );
+    }
+}
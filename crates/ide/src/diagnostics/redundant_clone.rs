@@ -0,0 +1,229 @@
+//! Warns about clones of local variables which are never used afterwards,
+//! e.g. `let y = x.clone();` where `x` isn't used after this statement.
+
+use hir::{diagnostics::DiagnosticCode, PathResolution, Semantics};
+use ide_db::{
+    base_db::FileId,
+    defs::{Definition, NameRefClass},
+    source_change::SourceFileEdit,
+    RootDatabase,
+};
+use syntax::{
+    ast::{self, ArgListOwner},
+    AstNode, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+use super::is_mentioned_in_macro_call;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let let_stmt = ast::LetStmt::cast(node.clone())?;
+    let call = match let_stmt.initializer()? {
+        ast::Expr::MethodCallExpr(it) => it,
+        _ => return None,
+    };
+    if call.name_ref()?.text() != "clone" || call.arg_list()?.args().next().is_some() {
+        return None;
+    }
+    // Make sure this is an actual method call, and not an unresolved one.
+    sema.resolve_method_call(&call)?;
+
+    let receiver = match call.receiver()? {
+        ast::Expr::PathExpr(it) => it,
+        _ => return None,
+    };
+    let local = match sema.resolve_path(&receiver.path()?)? {
+        PathResolution::Local(it) => it,
+        _ => return None,
+    };
+    // Cloning through a reference produces a value of a different type.
+    if sema.type_of_expr(&receiver.clone().into())? != sema.type_of_expr(&call.clone().into())? {
+        return None;
+    }
+
+    // The value is still needed by the next iteration of any loop it was declared outside of.
+    let decl_range = local
+        .source(sema.db)
+        .value
+        .either(|it| it.syntax().text_range(), |it| it.syntax().text_range());
+    let in_loop = let_stmt.syntax().ancestors().any(|it| {
+        let is_loop = ast::LoopExpr::can_cast(it.kind())
+            || ast::WhileExpr::can_cast(it.kind())
+            || ast::ForExpr::can_cast(it.kind());
+        is_loop && !it.text_range().contains_range(decl_range)
+    });
+    if in_loop {
+        return None;
+    }
+
+    let body = let_stmt.syntax().ancestors().find_map(ast::Fn::cast)?.body()?;
+    let stmt_range = let_stmt.syntax().text_range();
+    let mut uses = body.syntax().descendants().filter_map(ast::NameRef::cast).filter(|name_ref| {
+        NameRefClass::classify(sema, name_ref)
+            .map_or(false, |class| class.referenced(sema.db) == Definition::Local(local))
+    });
+    // A borrow taken earlier may still be alive, in which case the value can't be moved.
+    let is_used_later_or_borrowed = uses.any(|name_ref| {
+        let range = name_ref.syntax().text_range();
+        range.start() >= stmt_range.end()
+            || (range.end() <= stmt_range.start() && may_borrow(&name_ref, &body))
+    });
+    let name = local.name(sema.db)?.to_string();
+    if is_used_later_or_borrowed || is_mentioned_in_macro_call(&body, &name) {
+        return None;
+    }
+
+    let range = call.syntax().text_range();
+    let edit = TextEdit::replace(range, receiver.syntax().text().to_string());
+    acc.push(
+        Diagnostic::hint(range, format!("Redundant clone, `{}` is not used afterwards", name))
+            .with_fix(Some(Fix::new(
+                "Remove clone",
+                SourceFileEdit { file_id, edit }.into(),
+                range,
+            )))
+            .with_code(Some(DiagnosticCode("redundant-clone"))),
+    );
+
+    Some(())
+}
+
+/// Whether this use of a variable may create a reference to it, by an explicit
+/// `&`, autoref of a method receiver or a closure capture.
+fn may_borrow(name_ref: &ast::NameRef, body: &ast::BlockExpr) -> bool {
+    let is_captured = name_ref
+        .syntax()
+        .ancestors()
+        .take_while(|it| it != body.syntax())
+        .any(|it| ast::ClosureExpr::can_cast(it.kind()));
+    if is_captured {
+        return true;
+    }
+
+    let mut expr = match name_ref.syntax().ancestors().find_map(ast::PathExpr::cast) {
+        Some(it) => it.syntax().clone(),
+        None => return false,
+    };
+    // Borrowing a field keeps the whole variable borrowed.
+    while let Some(parent) = expr.parent().filter(|it| ast::FieldExpr::can_cast(it.kind())) {
+        expr = parent;
+    }
+    let parent = match expr.parent() {
+        Some(it) => it,
+        None => return false,
+    };
+    if ast::RefExpr::can_cast(parent.kind()) {
+        return true;
+    }
+    match ast::MethodCallExpr::cast(parent) {
+        Some(call) => call.receiver().map_or(false, |it| it.syntax() == &expr),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_redundant_clone() {
+        check_fix(
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn main() {
+    let x = S;
+    let y = x.clone<|>();
+}
+"#,
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn main() {
+    let x = S;
+    let y = x;
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_necessary_clone() {
+        check_no_diagnostics(
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn consume(_s: S) {}
+fn main() {
+    let x = S;
+    let y = x.clone();
+    consume(x);
+    consume(y);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_necessary_clone_in_loop() {
+        check_no_diagnostics(
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn main() {
+    let x = S;
+    loop {
+        let _y = x.clone();
+    }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_necessary_clone_with_live_borrow() {
+        check_no_diagnostics(
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn consume(_s: &S) {}
+fn main() {
+    let x = S;
+    let r = &x;
+    let _y = x.clone();
+    consume(r);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_necessary_clone_with_closure_capture() {
+        check_no_diagnostics(
+            r#"
+trait Clone { fn clone(&self) -> Self; }
+struct S;
+impl Clone for S { fn clone(&self) -> S { S } }
+fn consume(_s: &S) {}
+fn main() {
+    let x = S;
+    let f = || consume(&x);
+    let _y = x.clone();
+    f();
+}
+"#,
+        );
+    }
+}
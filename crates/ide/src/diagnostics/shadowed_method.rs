@@ -0,0 +1,118 @@
+//! Warns about methods in trait impls that share their name with an inherent method of the
+//! self type. Method calls resolve to the inherent method, which is easy to miss.
+
+use hir::{diagnostics::DiagnosticCode, AssocItem, HirDisplay, Semantics};
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, NameOwner},
+    AstNode, SyntaxNode,
+};
+
+use crate::Diagnostic;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let fn_ = ast::Fn::cast(node.clone())?;
+    let impl_ = fn_.syntax().parent().and_then(ast::AssocItemList::cast)?.syntax().parent()?;
+    let impl_ = ast::Impl::cast(impl_)?;
+    impl_.trait_()?;
+    let name = fn_.name()?;
+    let function = sema.to_def(&fn_)?;
+    function.self_param(sema.db)?;
+
+    let impl_def = sema.to_def(&impl_)?;
+    let ty = impl_def.target_ty(sema.db);
+    let krate = impl_def.krate(sema.db);
+    let fn_name = function.name(sema.db);
+    let shadowed = ty.clone().iterate_assoc_items(sema.db, krate, |item| match item {
+        AssocItem::Function(it)
+            if it.name(sema.db) == fn_name && it.self_param(sema.db).is_some() =>
+        {
+            Some(())
+        }
+        _ => None,
+    });
+    if shadowed.is_none() {
+        return None;
+    }
+
+    acc.push(
+        Diagnostic::hint(
+            name.syntax().text_range(),
+            format!(
+                "`{}` has the same name as an inherent method of `{}`, which takes precedence \
+                 in method calls",
+                fn_name,
+                ty.display(sema.db)
+            ),
+        )
+        .with_code(Some(DiagnosticCode("shadowed-method"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::diagnostics::tests::{check_expect, check_no_diagnostics};
+
+    #[test]
+    fn test_trait_method_shadowed_by_inherent_method() {
+        check_expect(
+            r#"
+struct S;
+impl S {
+    fn len(&self) -> usize { 0 }
+}
+trait Len {
+    fn len(&self) -> usize;
+}
+impl Len for S {
+    fn len(&self) -> usize { 1 }
+}
+"#,
+            expect![[r#"
+                [
+                    Diagnostic {
+                        message: "`len` has the same name as an inherent method of `S`, which takes precedence in method calls",
+                        range: 120..123,
+                        severity: WeakWarning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "shadowed-method",
+                            ),
+                        ),
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_no_clash() {
+        check_no_diagnostics(
+            r#"
+struct S;
+impl S {
+    fn new() -> S { S }
+    fn size(&self) -> usize { 0 }
+}
+trait Len {
+    fn new() -> Self;
+    fn len(&self) -> usize;
+}
+impl Len for S {
+    fn new() -> S { S }
+    fn len(&self) -> usize { 1 }
+}
+"#,
+        );
+    }
+}
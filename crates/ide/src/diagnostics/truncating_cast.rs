@@ -0,0 +1,126 @@
+//! Warns about `as` casts to a narrower integer type, e.g. `x as u8` with `x: u32`.
+
+use hir::{diagnostics::DiagnosticCode, HirDisplay, Semantics, Type};
+use ide_db::RootDatabase;
+use syntax::{ast, AstNode, SyntaxNode};
+
+use crate::Diagnostic;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let cast_expr = ast::CastExpr::cast(node.clone())?;
+    let from = sema.type_of_expr(&cast_expr.expr()?)?;
+    let to = sema.type_of_expr(&cast_expr.clone().into())?;
+    let (from_min_width, _) = bit_widths(&from)?;
+    let (_, to_max_width) = bit_widths(&to)?;
+    if from_min_width <= to_max_width {
+        return None;
+    }
+
+    acc.push(
+        Diagnostic::warning(
+            cast_expr.syntax().text_range(),
+            format!(
+                "Casting `{}` to `{}` silently truncates values that don't fit",
+                from.display(sema.db),
+                to.display(sema.db)
+            ),
+        )
+        .with_code(Some(DiagnosticCode("cast-possible-truncation"))),
+    );
+
+    Some(())
+}
+
+/// The smallest and largest bit width of `ty` across targets. `usize` and `isize`
+/// are assumed to be at least 32 bits wide, as 16-bit targets are rare.
+fn bit_widths(ty: &Type) -> Option<(u32, u32)> {
+    if ty.is_pointer_sized_int() {
+        return Some((32, 64));
+    }
+    let width = bit_width(ty.int_bounds()?);
+    Some((width, width))
+}
+
+fn bit_width((min, max): (i128, u128)) -> u32 {
+    let sign_bit = if min < 0 { 1 } else { 0 };
+    128 - max.leading_zeros() + sign_bit
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use crate::diagnostics::tests::{check_expect, check_no_diagnostics};
+
+    #[test]
+    fn test_narrowing_cast() {
+        check_expect(
+            r#"
+fn foo(x: u32, y: i64, z: usize) {
+    let a = x as u8;
+    let b = y as i32;
+    let c = z as u8;
+}
+"#,
+            expect![[r#"
+                [
+                    Diagnostic {
+                        message: "Casting `u32` to `u8` silently truncates values that don't fit",
+                        range: 47..54,
+                        severity: Warning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "cast-possible-truncation",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Casting `i64` to `i32` silently truncates values that don't fit",
+                        range: 68..76,
+                        severity: Warning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "cast-possible-truncation",
+                            ),
+                        ),
+                    },
+                    Diagnostic {
+                        message: "Casting `usize` to `u8` silently truncates values that don't fit",
+                        range: 90..97,
+                        severity: Warning,
+                        fix: None,
+                        unused: false,
+                        code: Some(
+                            DiagnosticCode(
+                                "cast-possible-truncation",
+                            ),
+                        ),
+                    },
+                ]
+            "#]],
+        );
+    }
+
+    #[test]
+    fn test_widening_and_same_width_casts() {
+        check_no_diagnostics(
+            r#"
+fn foo(x: u8, y: i32, z: usize, w: u64) -> u64 {
+    let a = y as i64;
+    let b = y as u32;
+    let c = z as u32;
+    let d = w as usize;
+    x as u64
+}
+"#,
+        );
+    }
+}
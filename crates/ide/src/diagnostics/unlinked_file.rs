@@ -0,0 +1,159 @@
+//! Reports files which are not part of any crate's module tree, e.g. a `foo.rs`
+//! next to `lib.rs` without a `mod foo;`, and offers to declare them.
+
+use hir::{diagnostics::DiagnosticCode, Semantics};
+use ide_db::{
+    base_db::{FileId, SourceDatabaseExt, VfsPath},
+    source_change::SourceFileEdit,
+    RootDatabase,
+};
+use syntax::{
+    ast::{self, ModuleItemOwner},
+    AstNode, TextRange, TextSize,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+) -> Option<()> {
+    if sema.to_module_def(file_id).is_some() {
+        return None;
+    }
+    let db = sema.db;
+    let source_root = db.source_root(db.file_source_root(file_id));
+    let path = source_root.path_for_file(&file_id)?;
+    let (name, ext) = path.name_and_extension()?;
+    if ext != Some("rs") {
+        return None;
+    }
+
+    // `foo/mod.rs` declares the module `foo` from the directory containing `foo`.
+    let mut dir = path.parent()?;
+    let module_name = if name == "mod" {
+        let dir_name = dir.name_and_extension()?.0.to_string();
+        dir = dir.parent()?;
+        dir_name
+    } else {
+        name.to_string()
+    };
+
+    let mut candidates: Vec<VfsPath> =
+        ["lib.rs", "main.rs", "mod.rs"].iter().filter_map(|it| dir.join(it)).collect();
+    if let Some((dir_name, _)) = dir.name_and_extension() {
+        if let Some(sibling) = dir.parent().and_then(|it| it.join(&format!("{}.rs", dir_name))) {
+            candidates.push(sibling);
+        }
+    }
+    let parent_id = candidates
+        .iter()
+        .filter_map(|it| source_root.file_for_path(it))
+        .copied()
+        .find(|&it| it != file_id && sema.to_module_def(it).is_some())?;
+
+    let parent = sema.parse(parent_id);
+    let last_mod_decl = parent
+        .items()
+        .filter_map(|item| match item {
+            ast::Item::Module(it) if it.item_list().is_none() => Some(it),
+            _ => None,
+        })
+        .last();
+    let edit = match last_mod_decl {
+        Some(it) => {
+            TextEdit::insert(it.syntax().text_range().end(), format!("\nmod {};", module_name))
+        }
+        None => TextEdit::insert(TextSize::from(0), format!("mod {};\n", module_name)),
+    };
+
+    let file_range = TextRange::up_to(TextSize::of(&*db.file_text(file_id)));
+    acc.push(
+        Diagnostic::hint(
+            TextRange::empty(TextSize::from(0)),
+            "File is not included in the module tree".to_string(),
+        )
+        .with_fix(Some(Fix::new(
+            &format!("Add `mod {};`", module_name),
+            SourceFileEdit { file_id: parent_id, edit }.into(),
+            file_range,
+        )))
+        .with_code(Some(DiagnosticCode("unlinked-file"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{
+        check_apply_diagnostic_fix_in_other_file, check_no_diagnostics,
+    };
+
+    #[test]
+    fn test_unlinked_sibling_file() {
+        check_apply_diagnostic_fix_in_other_file(
+            r#"
+//- /lib.rs
+fn main() {}
+//- /foo.rs
+<|>fn foo() {}
+"#,
+            r#"
+mod foo;
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unlinked_file_after_mod_decls() {
+        check_apply_diagnostic_fix_in_other_file(
+            r#"
+//- /main.rs
+mod bar;
+
+fn main() {}
+//- /bar.rs
+//- /foo/mod.rs
+<|>fn foo() {}
+"#,
+            r#"
+mod bar;
+mod foo;
+
+fn main() {}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unlinked_file_in_submodule_dir() {
+        check_apply_diagnostic_fix_in_other_file(
+            r#"
+//- /lib.rs
+mod bar;
+//- /bar.rs
+//- /bar/baz.rs
+<|>fn baz() {}
+"#,
+            r#"
+mod baz;
+"#,
+        );
+    }
+
+    #[test]
+    fn test_linked_file() {
+        check_no_diagnostics(
+            r#"
+//- /lib.rs
+mod foo;
+//- /foo.rs
+fn foo() {}
+"#,
+        );
+    }
+}
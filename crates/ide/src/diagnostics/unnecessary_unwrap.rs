@@ -0,0 +1,160 @@
+//! Suggests using the value directly instead of unwrapping a freshly built `Some(x)` or `Ok(x)`.
+
+use hir::{diagnostics::DiagnosticCode, PathResolution, Semantics};
+use ide_db::{base_db::FileId, helpers::FamousDefs, source_change::SourceFileEdit, RootDatabase};
+use syntax::{
+    ast::{self, ArgListOwner},
+    AstNode, SyntaxKind, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let method_call = ast::MethodCallExpr::cast(node.clone())?;
+    if method_call.name_ref()?.text() != "unwrap" || method_call.arg_list()?.args().next().is_some()
+    {
+        return None;
+    }
+    let call = match method_call.receiver()? {
+        ast::Expr::CallExpr(it) => it,
+        _ => return None,
+    };
+    let path = match call.expr()? {
+        ast::Expr::PathExpr(it) => it.path()?,
+        _ => return None,
+    };
+    let variant = match sema.resolve_path(&path)? {
+        PathResolution::Def(hir::ModuleDef::Variant(it)) => it,
+        _ => return None,
+    };
+    let famous_defs = FamousDefs(sema, sema.scope(node).module().map(|it| it.krate()));
+    let enum_ = Some(variant.parent_enum(sema.db));
+    let expected = if enum_ == famous_defs.core_option_Option() {
+        "Some"
+    } else if enum_ == famous_defs.core_result_Result() {
+        "Ok"
+    } else {
+        return None;
+    };
+    if variant.name(sema.db).to_string() != expected {
+        return None;
+    }
+    let mut args = call.arg_list()?.args();
+    let value = args.next()?;
+    if args.next().is_some() {
+        return None;
+    }
+
+    let needs_parens = match value {
+        ast::Expr::Literal(_)
+        | ast::Expr::PathExpr(_)
+        | ast::Expr::CallExpr(_)
+        | ast::Expr::MethodCallExpr(_)
+        | ast::Expr::FieldExpr(_)
+        | ast::Expr::IndexExpr(_)
+        | ast::Expr::ParenExpr(_)
+        | ast::Expr::TupleExpr(_)
+        | ast::Expr::ArrayExpr(_)
+        | ast::Expr::MacroCall(_) => false,
+        _ => method_call.syntax().parent().map_or(true, |it| {
+            !matches!(
+                it.kind(),
+                SyntaxKind::EXPR_STMT
+                    | SyntaxKind::LET_STMT
+                    | SyntaxKind::ARG_LIST
+                    | SyntaxKind::BLOCK_EXPR
+            )
+        }),
+    };
+    let replacement = if needs_parens { format!("({})", value) } else { value.to_string() };
+
+    let range = method_call.syntax().text_range();
+    let edit = TextEdit::replace(range, replacement);
+    acc.push(
+        Diagnostic::hint(
+            range,
+            format!("Unnecessary `unwrap` on `{}`; use the value directly", expected),
+        )
+        .with_fix(Some(Fix::new(
+            "Use the value directly",
+            SourceFileEdit { file_id, edit }.into(),
+            range,
+        )))
+        .with_code(Some(DiagnosticCode("unnecessary-literal-unwrap"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+    use ide_db::helpers::FamousDefs;
+
+    use crate::diagnostics::tests::{check_expect, check_fix};
+
+    fn check_unwrap_fix(before: &str, after: &str) {
+        check_fix(
+            &format!("//- /main.rs crate:main deps:core{}{}", before, FamousDefs::FIXTURE),
+            after,
+        );
+    }
+
+    #[test]
+    fn test_unwrap_some() {
+        check_unwrap_fix(
+            r#"
+fn foo() -> i32 {
+    Some(5).unwrap<|>()
+}
+"#,
+            r#"
+fn foo() -> i32 {
+    5
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unwrap_ok_keeps_precedence() {
+        check_unwrap_fix(
+            r#"
+fn foo(a: i32) -> i32 {
+    Ok::<_, ()>(a + 1).unwrap<|>() * 2
+}
+"#,
+            r#"
+fn foo(a: i32) -> i32 {
+    (a + 1) * 2
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unwrap_on_other_values() {
+        check_expect(
+            &format!(
+                "//- /main.rs crate:main deps:core{}{}",
+                r#"
+enum Maybe { Some(i32) }
+impl Maybe { fn unwrap(self) -> i32 { 0 } }
+fn foo(x: Option<i32>) -> i32 {
+    x.unwrap() + Maybe::Some(1).unwrap() + Some(1).unwrap_or(0)
+}
+"#,
+                FamousDefs::FIXTURE
+            ),
+            expect![[r#"
+                []
+            "#]],
+        );
+    }
+}
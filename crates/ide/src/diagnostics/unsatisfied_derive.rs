@@ -0,0 +1,190 @@
+//! Reports fields that prevent a struct from deriving `Copy` or `Clone`, e.g. a
+//! `String` field in a `#[derive(Clone, Copy)]` struct.
+//!
+//! This is not full trait solving: only fields of ADT types are checked, and
+//! generic structs are skipped altogether.
+
+use hir::{diagnostics::DiagnosticCode, Semantics, Type};
+use ide_db::RootDatabase;
+use syntax::{
+    ast::{self, AttrsOwner, GenericParamsOwner},
+    AstNode, SyntaxKind, SyntaxNode,
+};
+
+use crate::Diagnostic;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let strukt = ast::Struct::cast(node.clone())?;
+    if strukt.generic_param_list().is_some() {
+        return None;
+    }
+    let derives = derived_traits(&strukt);
+    if derives.is_empty() {
+        return None;
+    }
+
+    let fields: Vec<(SyntaxNode, Type)> = match strukt.field_list()? {
+        ast::FieldList::RecordFieldList(it) => it
+            .fields()
+            .filter_map(|field| {
+                Some((field.syntax().clone(), sema.to_def(&field)?.signature_ty(sema.db)))
+            })
+            .collect(),
+        ast::FieldList::TupleFieldList(it) => it
+            .fields()
+            .filter_map(|field| {
+                Some((field.syntax().clone(), sema.to_def(&field)?.signature_ty(sema.db)))
+            })
+            .collect(),
+    };
+    let struct_ty = sema.to_def(&strukt)?.ty(sema.db);
+
+    for trait_name in derives {
+        let implements: fn(&Type, &RootDatabase) -> bool = match trait_name.as_str() {
+            "Copy" => |ty, db| ty.is_copy(db),
+            "Clone" => |ty, db| ty.is_clone(db),
+            _ => continue,
+        };
+        // If the derived impl itself didn't resolve, the trait isn't available
+        // and every field would be reported.
+        if !implements(&struct_ty, sema.db) {
+            continue;
+        }
+        for (field, ty) in fields.iter() {
+            if ty.as_adt().is_none() || implements(ty, sema.db) {
+                continue;
+            }
+            acc.push(
+                Diagnostic::error(
+                    field.text_range(),
+                    format!(
+                        "Field does not implement `{}` required by `#[derive({})]`",
+                        trait_name, trait_name
+                    ),
+                )
+                .with_code(Some(DiagnosticCode("unsatisfied-derive"))),
+            );
+        }
+    }
+
+    Some(())
+}
+
+fn derived_traits(strukt: &ast::Struct) -> Vec<String> {
+    strukt
+        .attrs()
+        .filter_map(|attr| attr.as_simple_call())
+        .filter(|(name, _)| name == "derive")
+        .flat_map(|(_, tt)| {
+            tt.syntax()
+                .children_with_tokens()
+                .filter_map(|it| it.into_token())
+                .filter(|it| it.kind() == SyntaxKind::IDENT)
+                .map(|it| it.text().to_string())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use test_utils::extract_annotations;
+
+    use crate::{fixture, DiagnosticsConfig};
+
+    fn check(ra_fixture: &str) {
+        let (analysis, file_id) = fixture::file(ra_fixture);
+        let expected = extract_annotations(&*analysis.file_text(file_id).unwrap());
+        let actual = analysis
+            .diagnostics(&DiagnosticsConfig::default(), file_id)
+            .unwrap()
+            .into_iter()
+            .map(|it| (it.range, it.message))
+            .collect::<Vec<_>>();
+        assert_eq!(expected, actual);
+    }
+
+    const CORE_FIXTURE: &str = r#"
+//- /core/lib.rs crate:core
+#[prelude_import]
+use prelude::*;
+pub mod prelude {
+    pub use crate::{clone::Clone, marker::Copy};
+}
+pub mod marker {
+    #[lang = "copy"]
+    pub trait Copy {}
+    #[rustc_builtin_macro]
+    pub macro Copy {}
+}
+pub mod clone {
+    #[lang = "clone"]
+    pub trait Clone {}
+    #[rustc_builtin_macro]
+    pub macro Clone {}
+}
+"#;
+
+    #[test]
+    fn derive_copy_with_non_copy_field() {
+        check(&format!(
+            r#"
+//- /main.rs crate:main deps:core
+#[derive(Clone)]
+struct NotCopy;
+#[derive(Clone, Copy)]
+struct S {{ a: u32, b: NotCopy }}
+                 //^^^^^^^^^^ Field does not implement `Copy` required by `#[derive(Copy)]`
+{}"#,
+            CORE_FIXTURE
+        ));
+    }
+
+    #[test]
+    fn derive_clone_with_non_clone_field() {
+        check(&format!(
+            r#"
+//- /main.rs crate:main deps:core
+struct NotClone;
+#[derive(Clone)]
+struct S(u32, NotClone);
+            //^^^^^^^^ Field does not implement `Clone` required by `#[derive(Clone)]`
+{}"#,
+            CORE_FIXTURE
+        ));
+    }
+
+    #[test]
+    fn derive_copy_with_copy_fields() {
+        check(&format!(
+            r#"
+//- /main.rs crate:main deps:core
+#[derive(Clone, Copy)]
+struct Inner;
+#[derive(Clone, Copy)]
+struct S {{ a: u32, b: Inner }}
+{}"#,
+            CORE_FIXTURE
+        ));
+    }
+
+    #[test]
+    fn respects_disabled_code() {
+        let (analysis, file_id) = fixture::file(&format!(
+            r#"
+//- /main.rs crate:main deps:core
+struct NotClone;
+#[derive(Clone)]
+struct S(NotClone);
+{}"#,
+            CORE_FIXTURE
+        ));
+        let mut config = DiagnosticsConfig::default();
+        config.disabled.insert("unsatisfied-derive".into());
+        assert!(analysis.diagnostics(&config, file_id).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,143 @@
+//! Warns about results of `#[must_use]` functions and `Result`s that are
+//! dropped on the floor, e.g. `foo();` where `foo` returns a `Result`.
+
+use hir::{diagnostics::DiagnosticCode, HasAttrs, ModuleDef, PathResolution, Semantics};
+use ide_db::{base_db::FileId, source_change::SourceFileEdit, ty_filter::TryEnum, RootDatabase};
+use syntax::{ast, AstNode, SyntaxNode};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let expr_stmt = ast::ExprStmt::cast(node.clone())?;
+    let expr = expr_stmt.expr()?;
+    let func = match &expr {
+        ast::Expr::CallExpr(call) => match call.expr()? {
+            ast::Expr::PathExpr(path_expr) => match sema.resolve_path(&path_expr.path()?)? {
+                PathResolution::Def(ModuleDef::Function(func)) => Some(func),
+                _ => None,
+            },
+            _ => None,
+        },
+        ast::Expr::MethodCallExpr(call) => sema.resolve_method_call(call),
+        _ => return None,
+    };
+
+    let message = if func.map_or(false, |it| it.attrs(sema.db).by_key("must_use").exists()) {
+        "Unused result of a `#[must_use]` function"
+    } else {
+        let ty = sema.type_of_expr(&expr)?;
+        if TryEnum::from_ty(sema, &ty)? != TryEnum::Result {
+            return None;
+        }
+        "Unused `Result` that must be used"
+    };
+
+    let range = expr.syntax().text_range();
+    let edit = TextEdit::insert(range.start(), "let _ = ".to_string());
+    acc.push(
+        Diagnostic::warning(range, message.to_string())
+            .with_fix(Some(Fix::new(
+                "Assign to `let _`",
+                SourceFileEdit { file_id, edit }.into(),
+                range,
+            )))
+            .with_code(Some(DiagnosticCode("unused-must-use"))),
+    );
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_unused_must_use_fn_result() {
+        check_fix(
+            r#"
+#[must_use]
+fn answer() -> i32 { 42 }
+fn main() {
+    answer<|>();
+}
+"#,
+            r#"
+#[must_use]
+fn answer() -> i32 { 42 }
+fn main() {
+    let _ = answer();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unused_must_use_method_result() {
+        check_fix(
+            r#"
+struct S;
+impl S {
+    #[must_use]
+    fn answer(&self) -> i32 { 42 }
+}
+fn main() {
+    S.answer<|>();
+}
+"#,
+            r#"
+struct S;
+impl S {
+    #[must_use]
+    fn answer(&self) -> i32 { 42 }
+}
+fn main() {
+    let _ = S.answer();
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unused_result() {
+        check_fix(
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+fn div(x: i32, y: i32) -> Result<i32, ()> { Result::Ok(x / y) }
+fn main() {
+    div<|>(92, 1);
+}
+"#,
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+fn div(x: i32, y: i32) -> Result<i32, ()> { Result::Ok(x / y) }
+fn main() {
+    let _ = div(92, 1);
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_used_must_use_result() {
+        check_no_diagnostics(
+            r#"
+enum Result<T, E> { Ok(T), Err(E) }
+#[must_use]
+fn answer() -> i32 { 42 }
+fn div(x: i32, y: i32) -> Result<i32, ()> { Result::Ok(x / y) }
+fn unit() {}
+fn main() {
+    let _x = answer();
+    let _y = div(92, 1);
+    unit();
+}
+"#,
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Warns about function parameters which are never read, e.g. `x` in
+//! `fn foo(x: i32) {}`, including ones shadowed before their first use.
+
+use hir::{diagnostics::DiagnosticCode, Semantics};
+use ide_db::{
+    base_db::FileId,
+    defs::{Definition, NameRefClass},
+    source_change::SourceFileEdit,
+    RootDatabase,
+};
+use syntax::{
+    ast::{self, NameOwner},
+    AstNode, SyntaxNode,
+};
+use text_edit::TextEdit;
+
+use crate::{Diagnostic, Fix};
+
+use super::is_mentioned_in_macro_call;
+
+pub(super) fn check(
+    acc: &mut Vec<Diagnostic>,
+    sema: &Semantics<RootDatabase>,
+    file_id: FileId,
+    node: &SyntaxNode,
+) -> Option<()> {
+    let func = ast::Fn::cast(node.clone())?;
+    let body = func.body()?;
+
+    for param in func.param_list()?.params() {
+        let ident_pat = match param.pat() {
+            Some(ast::Pat::IdentPat(it)) => it,
+            _ => continue,
+        };
+        let name = match ident_pat.name() {
+            Some(it) => it,
+            None => continue,
+        };
+        if name.text().starts_with('_') {
+            continue;
+        }
+        let local = match sema.to_def(&ident_pat) {
+            Some(it) => it,
+            None => continue,
+        };
+
+        let is_read = body.syntax().descendants().filter_map(ast::NameRef::cast).any(|name_ref| {
+            NameRefClass::classify(sema, &name_ref)
+                .map_or(false, |class| class.referenced(sema.db) == Definition::Local(local))
+        });
+        // We don't look into macro calls, so conservatively assume they use the parameter.
+        if is_read || is_mentioned_in_macro_call(&body, name.text()) {
+            continue;
+        }
+
+        let range = name.syntax().text_range();
+        let edit = TextEdit::insert(range.start(), "_".to_string());
+        acc.push(
+            Diagnostic::hint(range, format!("Unused parameter `{}`", name.text()))
+                .with_fix(Some(Fix::new(
+                    "Prefix with `_`",
+                    SourceFileEdit { file_id, edit }.into(),
+                    range,
+                )))
+                .with_unused(true)
+                .with_code(Some(DiagnosticCode("unused-variables"))),
+        );
+    }
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::diagnostics::tests::{check_fix, check_no_diagnostics};
+
+    #[test]
+    fn test_unused_param() {
+        check_fix(
+            r#"
+fn foo(x<|>: i32) -> i32 { 92 }
+"#,
+            r#"
+fn foo(_x: i32) -> i32 { 92 }
+"#,
+        );
+    }
+
+    #[test]
+    fn test_unused_shadowed_param() {
+        check_fix(
+            r#"
+fn foo(x<|>: i32, y: i32) -> i32 {
+    let x = y;
+    x
+}
+"#,
+            r#"
+fn foo(_x: i32, y: i32) -> i32 {
+    let x = y;
+    x
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_used_param() {
+        check_no_diagnostics(
+            r#"
+struct S { x: i32 }
+fn foo(x: i32, y: i32) -> S {
+    let _ = y;
+    S { x }
+}
+"#,
+        );
+    }
+
+    #[test]
+    fn test_underscored_param() {
+        check_no_diagnostics(
+            r#"
+fn foo(_x: i32) {}
+"#,
+        );
+    }
+}
@@ -8,3 +8,86 @@ pub(crate) use navigation_target::{ToNav, TryToNav};
 pub(crate) use short_label::ShortLabel;
 
 pub(crate) use syntax::display::{function_declaration, macro_label};
+
+use hir::HirDisplay;
+use ide_db::RootDatabase;
+
+/// Renders a type the same way hover and completion show it.
+pub fn format_type(db: &RootDatabase, ty: &hir::Type) -> String {
+    ty.display(db).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::{expect, Expect};
+    use hir::Semantics;
+    use syntax::{algo::find_node_at_offset, ast, AstNode};
+
+    use crate::fixture;
+
+    use super::format_type;
+
+    fn check(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let sema = Semantics::new(&*analysis.db);
+        let file = sema.parse(position.file_id);
+        let pat: ast::IdentPat = find_node_at_offset(file.syntax(), position.offset).unwrap();
+        let ty = sema.type_of_pat(&pat.into()).unwrap();
+        expect.assert_eq(&format_type(&analysis.db, &ty));
+    }
+
+    #[test]
+    fn formats_references_and_generics() {
+        check(
+            r#"
+struct Wrapper<T>(T);
+fn main() {
+    let w<|> = &mut Wrapper(1u8);
+}
+"#,
+            expect![["&mut Wrapper<u8>"]],
+        );
+    }
+
+    #[test]
+    fn formats_type_params() {
+        check(
+            r#"
+fn id<T>(t: T) -> T {
+    let x<|> = t;
+    x
+}
+"#,
+            expect![["T"]],
+        );
+    }
+
+    #[test]
+    fn formats_trait_objects() {
+        check(
+            r#"
+trait Shape {}
+fn area(shape: &dyn Shape) {
+    let s<|> = shape;
+}
+"#,
+            expect![["&dyn Shape"]],
+        );
+    }
+
+    #[test]
+    fn formats_impl_trait() {
+        check(
+            r#"
+trait Shape {}
+struct Square;
+impl Shape for Square {}
+fn make() -> impl Shape { Square }
+fn main() {
+    let s<|> = make();
+}
+"#,
+            expect![["impl Shape"]],
+        );
+    }
+}
@@ -0,0 +1,78 @@
+use hir::Semantics;
+use ide_db::{base_db::FilePosition, RootDatabase};
+use syntax::{ast, AstNode};
+
+use crate::{display::ToNav, NavigationTarget};
+
+/// Returns the innermost function containing `position`. Closures are not
+/// functions in this sense, so positions inside them resolve to the function
+/// the closure is defined in.
+pub(crate) fn enclosing_function(
+    db: &RootDatabase,
+    position: FilePosition,
+) -> Option<NavigationTarget> {
+    let sema = Semantics::new(db);
+    let source_file = sema.parse(position.file_id);
+    let fn_ =
+        sema.find_node_at_offset_with_descend::<ast::Fn>(source_file.syntax(), position.offset)?;
+    let function = sema.to_def(&fn_)?;
+    Some(function.to_nav(db))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixture;
+
+    fn check(ra_fixture: &str, expected: &str) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let nav = analysis.enclosing_function(position).unwrap().unwrap();
+        nav.assert_match(expected);
+    }
+
+    #[test]
+    fn test_enclosing_function_nested() {
+        check(
+            r#"
+fn outer() {
+    fn inner() {
+        let x = <|>1;
+    }
+}
+"#,
+            "inner Function FileId(0) 17..54 20..25",
+        );
+    }
+
+    #[test]
+    fn test_enclosing_function_in_closure() {
+        check(
+            r#"
+fn main() {
+    let f = |x: i32| {
+        x + <|>1
+    };
+}
+"#,
+            "main Function FileId(0) 0..57 3..7",
+        );
+    }
+
+    #[test]
+    fn test_enclosing_function_in_method() {
+        check(
+            r#"
+struct S;
+impl S {
+    fn foo(&self) { <|> }
+}
+"#,
+            "foo Function FileId(0) 23..41 26..29",
+        );
+    }
+
+    #[test]
+    fn test_no_enclosing_function() {
+        let (analysis, position) = fixture::position(r#"struct S { <|> }"#);
+        assert!(analysis.enclosing_function(position).unwrap().is_none());
+    }
+}
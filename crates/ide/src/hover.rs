@@ -1,19 +1,22 @@
 use hir::{
-    Adt, AsAssocItem, AssocItemContainer, FieldSource, HasAttrs, HasSource, HirDisplay, Module,
-    ModuleDef, ModuleSource, Semantics,
+    Adt, AsAssocItem, AssocItemContainer, FieldSource, GenericDef, HasAttrs, HasSource, HirDisplay,
+    LifetimeParam, Module, ModuleDef, ModuleSource, Semantics, Type,
 };
 use ide_db::base_db::SourceDatabase;
 use ide_db::{
     defs::{Definition, NameClass, NameRefClass},
+    helpers::FamousDefs,
     RootDatabase,
 };
 use itertools::Itertools;
 use stdx::format_to;
-use syntax::{ast, match_ast, AstNode, SyntaxKind::*, SyntaxToken, TokenAtOffset, T};
+use syntax::{
+    ast, match_ast, AstNode, AstToken, SyntaxKind::*, SyntaxNode, SyntaxToken, TokenAtOffset, T,
+};
 use test_utils::mark;
 
 use crate::{
-    display::{macro_label, ShortLabel, ToNav, TryToNav},
+    display::{format_type, macro_label, ShortLabel, ToNav, TryToNav},
     doc_links::{remove_links, rewrite_links},
     markdown_remove::remove_markdown,
     markup::Markup,
@@ -29,6 +32,7 @@ pub struct HoverConfig {
     pub goto_type_def: bool,
     pub links_in_hover: bool,
     pub markdown: bool,
+    pub traits: bool,
 }
 
 impl Default for HoverConfig {
@@ -40,6 +44,7 @@ impl Default for HoverConfig {
             goto_type_def: true,
             links_in_hover: true,
             markdown: true,
+            traits: false,
         }
     }
 }
@@ -52,6 +57,7 @@ impl HoverConfig {
         goto_type_def: false,
         links_in_hover: true,
         markdown: true,
+        traits: false,
     };
 
     pub fn any(&self) -> bool {
@@ -94,8 +100,7 @@ pub struct HoverResult {
 pub(crate) fn hover(
     db: &RootDatabase,
     position: FilePosition,
-    links_in_hover: bool,
-    markdown: bool,
+    config: &HoverConfig,
 ) -> Option<RangeInfo<HoverResult>> {
     let sema = Semantics::new(db);
     let file = sema.parse(position.file_id).syntax().clone();
@@ -109,14 +114,24 @@ pub(crate) fn hover(
         match node {
             ast::Name(name) => NameClass::classify(&sema, &name).and_then(|d| d.defined(sema.db)),
             ast::NameRef(name_ref) => NameRefClass::classify(&sema, &name_ref).map(|d| d.referenced(sema.db)),
+            ast::Lifetime(lifetime) => NameClass::classify_lifetime(&sema, &lifetime)
+                .and_then(|d| d.defined(sema.db))
+                .or_else(|| NameRefClass::classify_lifetime(&sema, &lifetime).map(|d| d.referenced(sema.db))),
             _ => None,
         }
     };
     if let Some(definition) = definition {
-        if let Some(markup) = hover_for_definition(db, definition) {
-            let markup = if !markdown {
+        if let Some(mut markup) = hover_for_definition(db, definition) {
+            if config.traits {
+                if let Some(impls) = definition_ty(db, definition)
+                    .and_then(|ty| implemented_traits(&sema, &node, &ty))
+                {
+                    markup = format!("{}\n\n```rust\n{}\n```", markup, impls).into();
+                }
+            }
+            let markup = if !config.markdown {
                 remove_markdown(&markup.as_str())
-            } else if links_in_hover {
+            } else if config.links_in_hover {
                 rewrite_links(db, &markup.as_str(), &definition)
             } else {
                 remove_links(&markup.as_str())
@@ -139,6 +154,17 @@ pub(crate) fn hover(
         }
     }
 
+    if token.kind() == LIFETIME_IDENT && token.text() == "'static" {
+        let markup = hover_markup(
+            Some("The lifetime of values that live for the entire duration of the program.".into()),
+            Some("'static".to_string()),
+            None,
+        )?;
+        res.markup = if config.markdown { markup } else { remove_markdown(markup.as_str()).into() };
+        let range = sema.original_range(&node).range;
+        return Some(RangeInfo::new(range, res));
+    }
+
     if token.kind() == syntax::SyntaxKind::COMMENT {
         // don't highlight the entire parent node on comment hover
         return None;
@@ -162,15 +188,96 @@ pub(crate) fn hover(
         }
     };
 
-    res.markup = if markdown {
-        Markup::fenced_block(&ty.display(db))
+    let mut markup = if config.markdown {
+        Markup::fenced_block(&format_type(db, &ty)).to_string()
     } else {
-        ty.display(db).to_string().into()
+        format_type(db, &ty)
     };
+    if let Some(value) = ast::Literal::cast(node.clone()).and_then(|it| literal_value(db, &it, &ty))
+    {
+        if config.markdown {
+            format_to!(markup, "\n___\n\n`{}`", value);
+        } else {
+            format_to!(markup, "\n\n{}", value);
+        }
+    }
+    if config.traits {
+        if let Some(impls) = implemented_traits(&sema, &node, &ty) {
+            if config.markdown {
+                format_to!(markup, "\n\n```rust\n{}\n```", impls);
+            } else {
+                format_to!(markup, "\n\n{}", impls);
+            }
+        }
+    }
+    res.markup = markup.into();
     let range = sema.original_range(&node).range;
     Some(RangeInfo::new(range, res))
 }
 
+/// Spells out the value of a numeric literal: `0xff` is `= 255 = 0xff = 0b11111111`, while a
+/// float literal shows the exact value of the floating-point number it's rounded to.
+fn literal_value(db: &RootDatabase, literal: &ast::Literal, ty: &Type) -> Option<String> {
+    match literal.kind() {
+        ast::LiteralKind::IntNumber(it) => {
+            let value = it.value()?;
+            Some(format!("= {} = {:#x} = {:#b}", value, value, value))
+        }
+        ast::LiteralKind::FloatNumber(it) => {
+            let mut text = it.text().replace('_', "");
+            if let Some(suffix) = it.suffix() {
+                text.truncate(text.len() - suffix.len());
+            }
+            let value = if ty.display(db).to_string() == "f32" {
+                f64::from(text.parse::<f32>().ok()?)
+            } else {
+                text.parse::<f64>().ok()?
+            };
+            // 1074 digits are enough for the smallest subnormal `f64`, the padding is dropped.
+            let exact = format!("{:.1074}", value);
+            Some(format!("= {}", exact.trim_end_matches('0').trim_end_matches('.')))
+        }
+        _ => None,
+    }
+}
+
+fn definition_ty(db: &RootDatabase, def: Definition) -> Option<Type> {
+    match def {
+        Definition::ModuleDef(ModuleDef::Adt(it)) => Some(it.ty(db)),
+        Definition::SelfType(it) => Some(it.target_ty(db)),
+        Definition::Local(it) => Some(it.ty(db)),
+        _ => None,
+    }
+}
+
+/// Lists which of the commonly needed `Copy`, `Clone`, `Send` and `Sync` traits `ty` implements,
+/// e.g. `impl Copy + Clone`.
+fn implemented_traits(
+    sema: &Semantics<RootDatabase>,
+    node: &SyntaxNode,
+    ty: &Type,
+) -> Option<String> {
+    let db = sema.db;
+    let famous_defs = FamousDefs(sema, sema.scope(node).module().map(|it| it.krate()));
+    let mut traits = Vec::new();
+    if ty.is_copy(db) {
+        traits.push("Copy");
+    }
+    if ty.is_clone(db) {
+        traits.push("Clone");
+    }
+    if famous_defs.core_marker_Send().map_or(false, |it| ty.impls_trait(db, it, &[])) {
+        traits.push("Send");
+    }
+    if famous_defs.core_marker_Sync().map_or(false, |it| ty.impls_trait(db, it, &[])) {
+        traits.push("Sync");
+    }
+    if traits.is_empty() {
+        return None;
+    }
+    Some(format!("impl {}", traits.join(" + ")))
+}
+
 fn show_implementations_action(db: &RootDatabase, def: Definition) -> Option<HoverAction> {
     fn to_action(nav_target: NavigationTarget) -> HoverAction {
         HoverAction::Implementaion(FilePosition {
@@ -370,12 +477,28 @@ fn hover_for_definition(db: &RootDatabase, def: Definition) -> Option<Markup> {
                 Adt::Enum(it) => from_def_source(db, it, mod_path),
             })
         }
-        Definition::TypeParam(_) | Definition::LifetimeParam(_) => {
+        Definition::LifetimeParam(it) => lifetime_param_markup(db, it),
+        Definition::TypeParam(_) => {
             // FIXME: Hover for generic param
             None
         }
     };
 
+    fn lifetime_param_markup(db: &RootDatabase, param: LifetimeParam) -> Option<Markup> {
+        let owner = match param.parent(db) {
+            GenericDef::Function(it) => format!("fn {}", it.name(db)),
+            GenericDef::Adt(Adt::Struct(it)) => format!("struct {}", it.name(db)),
+            GenericDef::Adt(Adt::Union(it)) => format!("union {}", it.name(db)),
+            GenericDef::Adt(Adt::Enum(it)) => format!("enum {}", it.name(db)),
+            GenericDef::Trait(it) => format!("trait {}", it.name(db)),
+            GenericDef::TypeAlias(it) => format!("type {}", it.name(db)),
+            GenericDef::Impl(it) => format!("impl {}", it.target_ty(db).display(db)),
+            GenericDef::Variant(_) | GenericDef::Const(_) => return None,
+        };
+        let docs = format!("Lifetime parameter declared on `{}`", owner);
+        hover_markup(Some(docs), Some(param.name(db).to_string()), None)
+    }
+
     fn from_def_source<A, D>(db: &RootDatabase, def: D, mod_path: Option<String>) -> Option<Markup>
     where
         D: HasSource<Ast = A> + HasAttrs + Copy,
@@ -403,7 +526,7 @@ fn pick_best(tokens: TokenAtOffset<SyntaxToken>) -> Option<SyntaxToken> {
     return tokens.max_by_key(priority);
     fn priority(n: &SyntaxToken) -> usize {
         match n.kind() {
-            IDENT | INT_NUMBER => 3,
+            IDENT | INT_NUMBER | LIFETIME_IDENT => 3,
             T!['('] | T![')'] => 2,
             kind if kind.is_trivia() => 0,
             _ => 1,
@@ -422,12 +545,12 @@ mod tests {
 
     fn check_hover_no_result(ra_fixture: &str) {
         let (analysis, position) = fixture::position(ra_fixture);
-        assert!(analysis.hover(position, true, true).unwrap().is_none());
+        assert!(analysis.hover(position, &HoverConfig::default()).unwrap().is_none());
     }
 
     fn check(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let hover = analysis.hover(position, true, true).unwrap().unwrap();
+        let hover = analysis.hover(position, &HoverConfig::default()).unwrap().unwrap();
 
         let content = analysis.db.file_text(position.file_id);
         let hovered_element = &content[hover.range];
@@ -438,7 +561,10 @@ mod tests {
 
     fn check_hover_no_links(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let hover = analysis.hover(position, false, true).unwrap().unwrap();
+        let hover = analysis
+            .hover(position, &HoverConfig { links_in_hover: false, ..HoverConfig::default() })
+            .unwrap()
+            .unwrap();
 
         let content = analysis.db.file_text(position.file_id);
         let hovered_element = &content[hover.range];
@@ -449,7 +575,24 @@ mod tests {
 
     fn check_hover_no_markdown(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let hover = analysis.hover(position, true, false).unwrap().unwrap();
+        let hover = analysis
+            .hover(position, &HoverConfig { markdown: false, ..HoverConfig::default() })
+            .unwrap()
+            .unwrap();
+
+        let content = analysis.db.file_text(position.file_id);
+        let hovered_element = &content[hover.range];
+
+        let actual = format!("*{}*\n{}\n", hovered_element, hover.info.markup);
+        expect.assert_eq(&actual)
+    }
+
+    fn check_hover_traits(ra_fixture: &str, expect: Expect) {
+        let (analysis, position) = fixture::position(ra_fixture);
+        let hover = analysis
+            .hover(position, &HoverConfig { traits: true, ..HoverConfig::default() })
+            .unwrap()
+            .unwrap();
 
         let content = analysis.db.file_text(position.file_id);
         let hovered_element = &content[hover.range];
@@ -460,7 +603,7 @@ mod tests {
 
     fn check_actions(ra_fixture: &str, expect: Expect) {
         let (analysis, position) = fixture::position(ra_fixture);
-        let hover = analysis.hover(position, true, true).unwrap().unwrap();
+        let hover = analysis.hover(position, &HoverConfig::default()).unwrap().unwrap();
         expect.assert_debug_eq(&hover.info.actions)
     }
 
@@ -883,6 +1026,61 @@ fn main() {
         );
     }
 
+    #[test]
+    fn hover_for_lifetime_param_usage() {
+        check(
+            r#"fn foo<'a>(x: &'a<|> u32) -> &'a u32 { x }"#,
+            expect![[r#"
+                *'a*
+
+                ```rust
+                'a
+                ```
+
+                ---
+
+                Lifetime parameter declared on `fn foo`
+            "#]],
+        )
+    }
+
+    #[test]
+    fn hover_for_lifetime_param_in_impl() {
+        check(
+            r#"
+struct Foo<'a>(&'a u32);
+impl<'a> Foo<'a<|>> {}
+"#,
+            expect![[r#"
+                *'a*
+
+                ```rust
+                'a
+                ```
+
+                ---
+
+                Lifetime parameter declared on `impl Foo`
+            "#]],
+        )
+    }
+
+    #[test]
+    fn hover_for_static_lifetime() {
+        check(
+            r#"static FOO: &'stat<|>ic str = "";"#,
+            expect![[r#"
+                *'static*
+                ```rust
+                'static
+                ```
+                ___
+
+                The lifetime of values that live for the entire duration of the program.
+            "#]],
+        )
+    }
+
     #[test]
     fn hover_for_local_variable() {
         check(
@@ -897,6 +1095,50 @@ fn main() {
         )
     }
 
+    #[test]
+    fn hover_for_hex_literal() {
+        check(
+            r#"fn f() { let x: u8 = 0x<|>ff; }"#,
+            expect![[r#"
+                *0xff*
+                ```rust
+                u8
+                ```
+                ___
+
+                `= 255 = 0xff = 0b11111111`
+            "#]],
+        )
+    }
+
+    #[test]
+    fn hover_for_float_literal() {
+        check(
+            r#"fn f() { let x = 0.<|>1; }"#,
+            expect![[r#"
+                *0.1*
+                ```rust
+                f64
+                ```
+                ___
+
+                `= 0.1000000000000000055511151231257827021181583404541015625`
+            "#]],
+        );
+        check(
+            r#"fn f() { let x = 0.<|>1f32; }"#,
+            expect![[r#"
+                *0.1f32*
+                ```rust
+                f32
+                ```
+                ___
+
+                `= 0.100000001490116119384765625`
+            "#]],
+        )
+    }
+
     #[test]
     fn hover_for_local_variable_pat() {
         check(
@@ -3221,4 +3463,80 @@ fn no_hover() {
 "#,
         );
     }
+
+    #[test]
+    fn hover_shows_implemented_traits() {
+        check_hover_traits(
+            r#"
+//- /main.rs crate:main deps:core
+use core::{clone::Clone, marker::Copy};
+
+struct Point<|> { x: i32, y: i32 }
+impl Clone for Point {}
+impl Copy for Point {}
+//- /core/lib.rs crate:core
+pub mod clone {
+    #[lang = "clone"]
+    pub trait Clone {}
+}
+pub mod marker {
+    #[lang = "copy"]
+    pub trait Copy: crate::clone::Clone {}
+    pub unsafe auto trait Send {}
+    pub unsafe auto trait Sync {}
+}
+"#,
+            expect![[r#"
+                *Point*
+
+                ```rust
+                main
+                ```
+
+                ```rust
+                struct Point
+                ```
+
+                ```rust
+                impl Copy + Clone + Send + Sync
+                ```
+            "#]],
+        );
+    }
+
+    #[test]
+    fn hover_shows_implemented_traits_of_non_copy_type() {
+        check_hover_traits(
+            r#"
+//- /main.rs crate:main deps:core
+struct Name<|> { len: usize }
+//- /core/lib.rs crate:core
+pub mod clone {
+    #[lang = "clone"]
+    pub trait Clone {}
+}
+pub mod marker {
+    #[lang = "copy"]
+    pub trait Copy: crate::clone::Clone {}
+    pub unsafe auto trait Send {}
+    pub unsafe auto trait Sync {}
+}
+"#,
+            expect![[r#"
+                *Name*
+
+                ```rust
+                main
+                ```
+
+                ```rust
+                struct Name
+                ```
+
+                ```rust
+                impl Send + Sync
+                ```
+            "#]],
+        );
+    }
 }
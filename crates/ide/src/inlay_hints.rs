@@ -163,10 +163,7 @@ fn get_param_name_hints(
         .filter_map(|((param, _ty), arg)| {
             let param_name = match param? {
                 Either::Left(_) => "self".to_string(),
-                Either::Right(pat) => match pat {
-                    ast::Pat::IdentPat(it) => it.name()?.to_string(),
-                    _ => return None,
-                },
+                Either::Right(pat) => get_pat_param_name(pat)?,
             };
             Some((param_name, arg))
         })
@@ -181,6 +178,17 @@ fn get_param_name_hints(
     Some(())
 }
 
+/// Returns the name to show for a parameter pattern, looking through reference
+/// and parenthesized patterns. Destructuring patterns have no sensible name.
+fn get_pat_param_name(pat: ast::Pat) -> Option<String> {
+    match pat {
+        ast::Pat::IdentPat(it) => Some(it.name()?.to_string()),
+        ast::Pat::RefPat(it) => get_pat_param_name(it.pat()?),
+        ast::Pat::ParenPat(it) => get_pat_param_name(it.pat()?),
+        _ => None,
+    }
+}
+
 fn get_bind_pat_hints(
     acc: &mut Vec<InlayHint>,
     sema: &Semantics<RootDatabase>,
@@ -478,6 +486,29 @@ fn main() {
         );
     }
 
+    #[test]
+    fn param_hints_for_destructuring_patterns() {
+        check_with_config(
+            InlayHintsConfig {
+                parameter_hints: true,
+                type_hints: false,
+                chaining_hints: false,
+                max_length: None,
+            },
+            r#"
+fn foo((a, b): (i32, i32), &len: &u32, (count): u32) {}
+fn main() {
+    foo(
+        (1, 2),
+        &3,
+      //^^ len
+        4,
+      //^ count
+    );
+}"#,
+        );
+    }
+
     #[test]
     fn param_name_similar_to_fn_name_still_hints() {
         check_with_config(
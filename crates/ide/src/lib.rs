@@ -24,6 +24,7 @@ mod display;
 
 mod call_hierarchy;
 mod diagnostics;
+mod enclosing_function;
 mod expand_macro;
 mod extend_selection;
 mod file_structure;
@@ -64,7 +65,10 @@ use crate::display::ToNav;
 pub use crate::{
     call_hierarchy::CallItem,
     diagnostics::{Diagnostic, DiagnosticsConfig, Fix, Severity},
-    display::navigation_target::{NavigationTarget, SymbolKind},
+    display::{
+        format_type,
+        navigation_target::{NavigationTarget, SymbolKind},
+    },
     expand_macro::ExpandedMacro,
     file_structure::StructureNode,
     folding_ranges::{Fold, FoldKind},
@@ -82,7 +86,7 @@ pub use crate::{
 pub use assists::{Assist, AssistConfig, AssistId, AssistKind, ResolvedAssist};
 pub use completion::{
     CompletionConfig, CompletionItem, CompletionItemKind, CompletionResolveCapability,
-    CompletionScore, ImportEdit, InsertTextFormat,
+    CompletionScore, ImportEdit, InsertTextFormat, MatchKind,
 };
 pub use hir::{Documentation, Semantics};
 pub use ide_db::base_db::{
@@ -95,7 +99,7 @@ pub use ide_db::{
 };
 pub use ide_db::{
     label::Label,
-    line_index::{LineCol, LineIndex},
+    line_index::{LineCol, LineColUtf8, LineIndex},
     search::SearchScope,
     source_change::{FileSystemEdit, SourceChange, SourceFileEdit},
     symbol_index::Query,
@@ -240,6 +244,12 @@ impl Analysis {
         self.with_db(|db| db.parse(file_id).tree())
     }
 
+    /// Gets the syntax tree the file would have after applying `indel`, reusing
+    /// the current tree and reparsing only the edited token or block if possible.
+    pub fn reparse(&self, file_id: FileId, indel: &Indel) -> Cancelable<SourceFile> {
+        self.with_db(|db| db.parse(file_id).reparse(indel).tree())
+    }
+
     /// Gets the file's `LineIndex`: data structure to convert between absolute
     /// offsets and line/column representation.
     pub fn file_line_index(&self, file_id: FileId) -> Cancelable<Arc<LineIndex>> {
@@ -381,10 +391,9 @@ impl Analysis {
     pub fn hover(
         &self,
         position: FilePosition,
-        links_in_hover: bool,
-        markdown: bool,
+        config: &HoverConfig,
     ) -> Cancelable<Option<RangeInfo<HoverResult>>> {
-        self.with_db(|db| hover::hover(db, position, links_in_hover, markdown))
+        self.with_db(|db| hover::hover(db, position, config))
     }
 
     /// Return URL(s) for the documentation of the symbol under the cursor.
@@ -423,6 +432,14 @@ impl Analysis {
         self.with_db(|db| parent_module::parent_module(db, position))
     }
 
+    /// Returns the function containing the given position, if any.
+    pub fn enclosing_function(
+        &self,
+        position: FilePosition,
+    ) -> Cancelable<Option<NavigationTarget>> {
+        self.with_db(|db| enclosing_function::enclosing_function(db, position))
+    }
+
     /// Returns crates this file belongs too.
     pub fn crate_for(&self, file_id: FileId) -> Cancelable<Vec<CrateId>> {
         self.with_db(|db| parent_module::crate_for(db, file_id))
@@ -518,6 +535,25 @@ impl Analysis {
         self.with_db(|db| diagnostics::diagnostics(db, config, file_id))
     }
 
+    /// Computes the set of diagnostics for every file of the given crate.
+    pub fn crate_diagnostics(
+        &self,
+        config: &DiagnosticsConfig,
+        crate_id: CrateId,
+    ) -> Cancelable<Vec<(FileId, Diagnostic)>> {
+        self.with_db(|db| diagnostics::crate_diagnostics(db, config, crate_id))
+    }
+
+    /// Computes a fix which silences the lint reported by `diagnostic` on the
+    /// enclosing item.
+    pub fn allow_lint_fix(
+        &self,
+        file_id: FileId,
+        diagnostic: &Diagnostic,
+    ) -> Cancelable<Option<Fix>> {
+        self.with_db(|db| diagnostics::allow_lint_fix(db, file_id, diagnostic))
+    }
+
     /// Returns the edit required to rename reference at the position to the new
     /// name.
     pub fn rename(
@@ -1,9 +1,10 @@
 //! A module with ide helpers for high-level ide features.
 use crate::RootDatabase;
-use hir::{Crate, Enum, Module, ScopeDef, Semantics, Trait};
+use hir::{Crate, Enum, Module, ScopeDef, Semantics, Struct, Trait};
 use syntax::ast::{self, make};
 
 pub mod insert_use;
+pub mod match_arms;
 
 /// Converts the mod path struct into its ast representation.
 pub fn mod_path_to_ast(path: &hir::ModPath) -> ast::Path {
@@ -76,6 +77,8 @@ pub mod iter {
         pub(crate) mod collect {
             pub trait IntoIterator {
                 type Item;
+                type IntoIter: crate::iter::Iterator<Item = Self::Item>;
+                fn into_iter(self) -> Self::IntoIter;
             }
         }
     }
@@ -119,8 +122,12 @@ pub mod option {
     pub enum Option<T> { None, Some(T)}
 }
 
+pub mod result {
+    pub enum Result<T, E> { Ok(T), Err(E) }
+}
+
 pub mod prelude {
-    pub use crate::{convert::From, iter::{IntoIterator, Iterator}, option::Option::{self, *}, default::Default};
+    pub use crate::{convert::From, iter::{IntoIterator, Iterator}, option::Option::{self, *}, result::Result::{self, *}, default::Default};
 }
 #[prelude_import]
 pub use prelude::*;
@@ -138,6 +145,10 @@ pub use prelude::*;
         self.find_enum("core:option:Option")
     }
 
+    pub fn core_result_Result(&self) -> Option<Enum> {
+        self.find_enum("core:result:Result")
+    }
+
     pub fn core_default_Default(&self) -> Option<Trait> {
         self.find_trait("core:default:Default")
     }
@@ -146,6 +157,23 @@ pub use prelude::*;
         self.find_trait("core:iter:traits:iterator:Iterator")
     }
 
+    pub fn core_iter_IntoIterator(&self) -> Option<Trait> {
+        self.find_trait("core:iter:traits:collect:IntoIterator")
+    }
+
+    pub fn core_marker_Send(&self) -> Option<Trait> {
+        self.find_trait("core:marker:Send")
+    }
+
+    pub fn core_marker_Sync(&self) -> Option<Trait> {
+        self.find_trait("core:marker:Sync")
+    }
+
+    pub fn alloc_string_String(&self) -> Option<Struct> {
+        // `std` only re-exports `alloc`, which isn't a direct dependency of most crates.
+        self.find_struct("alloc:string:String").or_else(|| self.find_struct("std:string:String"))
+    }
+
     pub fn core_iter(&self) -> Option<Module> {
         self.find_module("core:iter")
     }
@@ -164,6 +192,13 @@ pub use prelude::*;
         }
     }
 
+    fn find_struct(&self, path: &str) -> Option<Struct> {
+        match self.find_def(path)? {
+            hir::ScopeDef::ModuleDef(hir::ModuleDef::Adt(hir::Adt::Struct(it))) => Some(it),
+            _ => None,
+        }
+    }
+
     fn find_module(&self, path: &str) -> Option<Module> {
         match self.find_def(path)? {
             hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it)) => Some(it),
@@ -187,13 +222,14 @@ pub use prelude::*;
         let std_crate = self.find_crate(std_crate)?;
         let mut module = std_crate.root_module(db);
         for segment in path {
-            module = module.children(db).find_map(|child| {
-                let name = child.name(db)?;
-                if name.to_string() == segment {
-                    Some(child)
-                } else {
-                    None
+            // Look through the whole scope, as modules like `std::string` are re-exports.
+            module = module.scope(db, None).into_iter().find_map(|(name, def)| match def {
+                hir::ScopeDef::ModuleDef(hir::ModuleDef::Module(it))
+                    if name.to_string() == segment =>
+                {
+                    Some(it)
                 }
+                _ => None,
             })?;
         }
         let def =
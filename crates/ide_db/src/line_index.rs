@@ -22,6 +22,15 @@ pub struct LineCol {
     pub col_utf16: u32,
 }
 
+/// Like `LineCol`, but with the column counted in UTF-8 code units (bytes).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct LineColUtf8 {
+    /// Zero-based
+    pub line: u32,
+    /// Zero-based
+    pub col: u32,
+}
+
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub(crate) struct Utf16Char {
     /// Start offset of a character inside a line, zero-based
@@ -89,17 +98,34 @@ impl LineIndex {
     }
 
     pub fn line_col(&self, offset: TextSize) -> LineCol {
+        self.to_utf16(self.line_col_utf8(offset))
+    }
+
+    pub fn offset(&self, line_col: LineCol) -> TextSize {
+        //FIXME: return Result
+        self.offset_utf8(self.to_utf8(line_col))
+    }
+
+    pub fn line_col_utf8(&self, offset: TextSize) -> LineColUtf8 {
         let line = partition_point(&self.newlines, |&it| it <= offset) - 1;
         let line_start_offset = self.newlines[line];
         let col = offset - line_start_offset;
 
-        LineCol { line: line as u32, col_utf16: self.utf8_to_utf16_col(line as u32, col) as u32 }
+        LineColUtf8 { line: line as u32, col: col.into() }
     }
 
-    pub fn offset(&self, line_col: LineCol) -> TextSize {
-        //FIXME: return Result
+    pub fn offset_utf8(&self, line_col: LineColUtf8) -> TextSize {
+        self.newlines[line_col.line as usize] + TextSize::from(line_col.col)
+    }
+
+    pub fn to_utf16(&self, line_col: LineColUtf8) -> LineCol {
+        let col_utf16 = self.utf8_to_utf16_col(line_col.line, line_col.col.into()) as u32;
+        LineCol { line: line_col.line, col_utf16 }
+    }
+
+    pub fn to_utf8(&self, line_col: LineCol) -> LineColUtf8 {
         let col = self.utf16_to_utf8_col(line_col.line, line_col.col_utf16);
-        self.newlines[line_col.line as usize] + col
+        LineColUtf8 { line: line_col.line, col: col.into() }
     }
 
     pub fn lines(&self, range: TextRange) -> impl Iterator<Item = TextRange> + '_ {
@@ -23,6 +23,27 @@ fn test_line_index() {
     assert_eq!(index.line_col(7.into()), LineCol { line: 2, col_utf16: 0 });
 }
 
+#[test]
+fn test_utf8_and_utf16_columns() {
+    let text = "a😀b\r\nc😀";
+    let index = LineIndex::new(text);
+
+    assert_eq!(index.line_col_utf8(5.into()), LineColUtf8 { line: 0, col: 5 });
+    assert_eq!(index.line_col(5.into()), LineCol { line: 0, col_utf16: 3 });
+    // `\r` is an ordinary character at the end of its line.
+    assert_eq!(index.line_col_utf8(6.into()), LineColUtf8 { line: 0, col: 6 });
+    assert_eq!(index.line_col(6.into()), LineCol { line: 0, col_utf16: 4 });
+    assert_eq!(index.line_col_utf8(8.into()), LineColUtf8 { line: 1, col: 0 });
+    assert_eq!(index.line_col_utf8(13.into()), LineColUtf8 { line: 1, col: 5 });
+    assert_eq!(index.line_col(13.into()), LineCol { line: 1, col_utf16: 3 });
+
+    assert_eq!(index.offset_utf8(LineColUtf8 { line: 1, col: 1 }), TextSize::from(9));
+    assert_eq!(index.offset(LineCol { line: 1, col_utf16: 3 }), TextSize::from(13));
+
+    assert_eq!(index.to_utf8(LineCol { line: 0, col_utf16: 3 }), LineColUtf8 { line: 0, col: 5 });
+    assert_eq!(index.to_utf16(LineColUtf8 { line: 0, col: 5 }), LineCol { line: 0, col_utf16: 3 });
+}
+
 #[test]
 fn test_char_len() {
     assert_eq!('メ'.len_utf8(), 3);
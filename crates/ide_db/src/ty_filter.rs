@@ -8,7 +8,7 @@ use std::iter;
 use syntax::ast::{self, make};
 
 /// Enum types that implement `std::ops::Try` trait.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq, Eq)]
 pub enum TryEnum {
     Result,
     Option,
@@ -31,6 +31,26 @@ impl TryEnum {
         })
     }
 
+    /// Returns `Some(..)` if a value of type `ty` can be propagated with `?` out of a function
+    /// returning `ret_ty` without any conversion, i.e. both are the same enum and, for `Result`,
+    /// have the same error type.
+    pub fn from_propagation(
+        sema: &Semantics<RootDatabase>,
+        ty: &Type,
+        ret_ty: &Type,
+    ) -> Option<TryEnum> {
+        let try_enum = TryEnum::from_ty(sema, ty)?;
+        if TryEnum::from_ty(sema, ret_ty)? != try_enum {
+            return None;
+        }
+        if let TryEnum::Result = try_enum {
+            if ty.type_arguments().nth(1)? != ret_ty.type_arguments().nth(1)? {
+                return None;
+            }
+        }
+        Some(try_enum)
+    }
+
     pub fn happy_case(self) -> &'static str {
         match self {
             TryEnum::Result => "Ok",
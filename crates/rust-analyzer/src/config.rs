@@ -82,6 +82,11 @@ config_data! {
         /// Toggles the additional completions that automatically add imports when completed.
         /// Note that your client must specify the `additionalTextEdits` LSP client capability to truly have this feature enabled.
         completion_autoimport_enable: bool       = "true",
+        /// Whether to show items which are not visible from the current module
+        /// with a lower priority instead of hiding them.
+        completion_privateItems_demote: bool     = "false",
+        /// Whether to rank snippet and postfix completions below other items.
+        completion_snippets_demote: bool         = "true",
 
         /// Whether to show native rust-analyzer diagnostics.
         diagnostics_enable: bool                = "true",
@@ -102,6 +107,9 @@ config_data! {
         /// Controls file watching implementation.
         files_watcher: String = "\"client\"",
 
+        /// Whether to show which of `Copy`, `Clone`, `Send` and `Sync` a type implements in hover.
+        hover_implementedTraits: bool = "false",
+
         /// Whether to show `Debug` action. Only applies when
         /// `#rust-analyzer.hoverActions.enable#` is set.
         hoverActions_debug: bool           = "true",
@@ -461,6 +469,8 @@ impl Config {
         self.completion.enable_autoimport_completions = data.completion_autoimport_enable;
         self.completion.add_call_parenthesis = data.completion_addCallParenthesis;
         self.completion.add_call_argument_snippets = data.completion_addCallArgumentSnippets;
+        self.completion.demote_private_items = data.completion_privateItems_demote;
+        self.completion.demote_snippets = data.completion_snippets_demote;
         self.completion.merge = self.assist.insert_use.merge;
 
         self.call_info_full = data.callInfo_full;
@@ -501,6 +511,7 @@ impl Config {
             goto_type_def: data.hoverActions_enable && data.hoverActions_gotoTypeDef,
             links_in_hover: data.hoverActions_linksInHover,
             markdown: true,
+            traits: data.hover_implementedTraits,
         };
     }
 
@@ -749,11 +749,7 @@ pub(crate) fn handle_hover(
 ) -> Result<Option<lsp_ext::Hover>> {
     let _p = profile::span("handle_hover");
     let position = from_proto::file_position(&snap, params.text_document_position_params)?;
-    let info = match snap.analysis.hover(
-        position,
-        snap.config.hover.links_in_hover,
-        snap.config.hover.markdown,
-    )? {
+    let info = match snap.analysis.hover(position, &snap.config.hover)? {
         None => return Ok(None),
         Some(info) => info,
     };
@@ -967,21 +963,28 @@ fn add_quick_fixes(
 ) -> Result<()> {
     let diagnostics = snap.analysis.diagnostics(&snap.config.diagnostics, frange.file_id)?;
 
-    for fix in diagnostics
-        .into_iter()
-        .filter_map(|d| d.fix)
-        .filter(|fix| fix.fix_trigger_range.intersect(frange.range).is_some())
-    {
-        let edit = to_proto::snippet_workspace_edit(&snap, fix.source_change)?;
-        let action = lsp_ext::CodeAction {
-            title: fix.label.to_string(),
-            group: None,
-            kind: Some(CodeActionKind::QUICKFIX),
-            edit: Some(edit),
-            is_preferred: Some(false),
-            data: None,
+    for diagnostic in diagnostics {
+        let allow_fix = match diagnostic.range.intersect(frange.range) {
+            Some(_) => snap.analysis.allow_lint_fix(frange.file_id, &diagnostic)?,
+            None => None,
         };
-        acc.push(action);
+        for fix in diagnostic
+            .fix
+            .into_iter()
+            .chain(allow_fix)
+            .filter(|fix| fix.fix_trigger_range.intersect(frange.range).is_some())
+        {
+            let edit = to_proto::snippet_workspace_edit(&snap, fix.source_change)?;
+            let action = lsp_ext::CodeAction {
+                title: fix.label.to_string(),
+                group: None,
+                kind: Some(CodeActionKind::QUICKFIX),
+                edit: Some(edit),
+                is_preferred: Some(false),
+                data: None,
+            };
+            acc.push(action);
+        }
     }
 
     for fix in snap.check_fixes.get(&frange.file_id).into_iter().flatten() {
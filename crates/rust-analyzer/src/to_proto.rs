@@ -7,7 +7,7 @@ use std::{
 use ide::{
     Assist, AssistKind, CallInfo, CompletionItem, CompletionItemKind, Documentation, FileId,
     FileRange, FileSystemEdit, Fold, FoldKind, Highlight, HighlightModifier, HighlightTag,
-    HighlightedRange, Indel, InlayHint, InlayKind, InsertTextFormat, LineIndex, Markup,
+    HighlightedRange, Indel, InlayHint, InlayKind, InsertTextFormat, LineIndex, Markup, MatchKind,
     NavigationTarget, ReferenceAccess, ResolvedAssist, Runnable, Severity, SourceChange,
     SourceFileEdit, SymbolKind, TextEdit, TextRange, TextSize,
 };
@@ -63,6 +63,7 @@ pub(crate) fn document_highlight_kind(
 pub(crate) fn diagnostic_severity(severity: Severity) -> lsp_types::DiagnosticSeverity {
     match severity {
         Severity::Error => lsp_types::DiagnosticSeverity::Error,
+        Severity::Warning => lsp_types::DiagnosticSeverity::Warning,
         Severity::WeakWarning => lsp_types::DiagnosticSeverity::Hint,
     }
 }
@@ -104,6 +105,7 @@ pub(crate) fn completion_item_kind(
         CompletionItemKind::TypeParam => lsp_types::CompletionItemKind::TypeParameter,
         CompletionItemKind::Macro => lsp_types::CompletionItemKind::Method,
         CompletionItemKind::Attribute => lsp_types::CompletionItemKind::EnumMember,
+        CompletionItemKind::Label => lsp_types::CompletionItemKind::Variable,
         CompletionItemKind::UnresolvedReference => lsp_types::CompletionItemKind::Reference,
     }
 }
@@ -206,8 +208,27 @@ pub(crate) fn completion_item(
         ..Default::default()
     };
 
-    if completion_item.score().is_some() {
-        set_score(&mut res, completion_item.label());
+    // Clients sort by `sort_text`, falling back to the label. Preselected items are prefixed
+    // with a space to come first. Items ranked below the regular ones are prefixed with `~`,
+    // which sorts after any identifier, followed by their rank: items matching the typed
+    // identifier only fuzzily come first, ordered by match quality, then demoted items, then
+    // private ones.
+    let rank = if completion_item.is_private() {
+        Some(5)
+    } else if completion_item.is_demoted() {
+        Some(4)
+    } else {
+        match completion_item.match_kind() {
+            Some(MatchKind::Substring) => Some(1),
+            Some(MatchKind::WordStarts) => Some(2),
+            Some(MatchKind::Subsequence) => Some(3),
+            Some(MatchKind::Prefix) | None => None,
+        }
+    };
+    match rank {
+        Some(rank) => res.sort_text = Some(format!("~{}{}", rank, completion_item.label())),
+        None if completion_item.score().is_some() => set_score(&mut res, completion_item.label()),
+        None => {}
     }
 
     if completion_item.deprecated() {
@@ -894,6 +915,73 @@ mod tests {
         .assert_debug_eq(&completions);
     }
 
+    #[test]
+    fn test_completion_snippets_sorted_after_identifiers() {
+        let fixture = r#"
+        fn main() {
+            let pdx = 92;
+            p<|>
+        }"#;
+
+        let (offset, text) = test_utils::extract_offset(fixture);
+        let line_index = LineIndex::new(&text);
+        let (analysis, file_id) = Analysis::from_single_file(text);
+        let sort_texts = |config: ide::CompletionConfig| -> Vec<(String, String)> {
+            analysis
+                .completions(&config, ide_db::base_db::FilePosition { file_id, offset })
+                .unwrap()
+                .unwrap()
+                .into_iter()
+                .filter(|c| c.label().starts_with("pd"))
+                .map(|c| completion_item(&line_index, LineEndings::Unix, c))
+                .flat_map(|comps| {
+                    comps.into_iter().map(|c| (c.label.clone(), c.sort_text.unwrap_or(c.label)))
+                })
+                .collect()
+        };
+
+        let sort_key = |completions: &[(String, String)], label: &str| {
+            completions.iter().find(|(it, _)| it == label).unwrap().1.clone()
+        };
+        let demoted = sort_texts(ide::CompletionConfig::default());
+        assert!(sort_key(&demoted, "pdx") < sort_key(&demoted, "pd"));
+
+        let config = ide::CompletionConfig { demote_snippets: false, ..Default::default() };
+        let plain = sort_texts(config);
+        assert!(sort_key(&plain, "pd") < sort_key(&plain, "pdx"));
+    }
+
+    #[test]
+    fn test_completion_fuzzy_matches_sorted_after_prefix_matches() {
+        let fixture = r#"
+        fn find_db() {}
+        fn fdb_cache() {}
+        fn main() {
+            fdb<|>
+        }"#;
+
+        let (offset, text) = test_utils::extract_offset(fixture);
+        let line_index = LineIndex::new(&text);
+        let (analysis, file_id) = Analysis::from_single_file(text);
+        let completions: Vec<(String, String)> = analysis
+            .completions(
+                &ide::CompletionConfig::default(),
+                ide_db::base_db::FilePosition { file_id, offset },
+            )
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|c| completion_item(&line_index, LineEndings::Unix, c))
+            .flat_map(|comps| {
+                comps.into_iter().map(|c| (c.label.clone(), c.sort_text.unwrap_or(c.label)))
+            })
+            .collect();
+
+        let sort_key =
+            |label: &str| completions.iter().find(|(it, _)| it == label).unwrap().1.clone();
+        assert!(sort_key("fdb_cache()") < sort_key("find_db()"));
+    }
+
     #[test]
     fn conv_fold_line_folding_only_fixup() {
         let text = r#"mod a;
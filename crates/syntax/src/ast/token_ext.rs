@@ -173,7 +173,8 @@ impl ast::String {
             buf.capacity() == 0,
         ) {
             (Ok(c), false) => buf.push(c),
-            (Ok(c), true) if Some(c) == text_iter.next() => (),
+            // An escape sequence is longer than the character it produces.
+            (Ok(c), true) if char_range.len() == c.len_utf8() && Some(c) == text_iter.next() => (),
             (Ok(c), true) => {
                 buf.reserve_exact(text.len());
                 buf.push_str(&text[..char_range.start]);
@@ -659,7 +660,9 @@ impl Radix {
 
 #[cfg(test)]
 mod tests {
-    use crate::ast::{make, FloatNumber, IntNumber};
+    use std::borrow::Cow;
+
+    use crate::ast::{self, make, FloatNumber, IntNumber};
 
     fn check_float_suffix<'a>(lit: &str, expected: impl Into<Option<&'a str>>) {
         assert_eq!(FloatNumber { syntax: make::tokens::literal(lit) }.suffix(), expected.into());
@@ -692,4 +695,24 @@ mod tests {
         check_int_suffix("0o11u32", "u32");
         check_int_suffix("0xffu32", "u32");
     }
+
+    fn check_string_value<'a>(lit: &str, expected: impl Into<Option<&'a str>>) {
+        let string = ast::String { syntax: make::tokens::literal(lit) };
+        assert_eq!(string.value().as_deref(), expected.into());
+    }
+
+    #[test]
+    fn test_string_escape() {
+        check_string_value(r#""foobar""#, "foobar");
+        check_string_value(r#""\\foobar""#, "\\foobar");
+        check_string_value(r#""foo\\bar""#, "foo\\bar");
+        check_string_value(r#""a\nb""#, "a\nb");
+        check_string_value(r#""\u{00e9}""#, "é");
+    }
+
+    #[test]
+    fn test_string_value_is_borrowed_without_escapes() {
+        let string = ast::String { syntax: make::tokens::literal(r#""héllo""#) };
+        assert!(matches!(string.value(), Some(Cow::Borrowed("héllo"))));
+    }
 }
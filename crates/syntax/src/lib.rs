@@ -129,6 +129,9 @@ impl Parse<SourceFile> {
         buf
     }
 
+    /// Applies `indel` to the file, reparsing only the smallest token or block
+    /// containing the edit. Falls back to parsing the whole file when the edit
+    /// crosses those boundaries.
     pub fn reparse(&self, indel: &Indel) -> Parse<SourceFile> {
         self.incremental_reparse(indel).unwrap_or_else(|| self.full_reparse(indel))
     }
@@ -452,4 +452,23 @@ enum Foo {
             105,
         )
     }
+
+    #[test]
+    fn reparse_matches_full_parse() {
+        fn check(before: &str, replace_with: &str) {
+            let (range, before) = extract_range(before);
+            let edit = Indel::replace(range, replace_with.to_owned());
+            let mut after = before.clone();
+            edit.apply(&mut after);
+
+            let reparsed = SourceFile::parse(&before).reparse(&edit);
+            assert_eq!(reparsed.debug_dump(), SourceFile::parse(&after).debug_dump());
+        }
+
+        check("fn <|>foo<|>() {}", "bar");
+        check("fn foo() { \"Hello<|><|>\" }", ", world");
+        check("fn foo() { 9<|><|>2 }", "1");
+        // The edit unbalances the braces, so the whole file is reparsed.
+        check("fn foo() { <|><|> }\nfn bar() {}", "}");
+    }
 }